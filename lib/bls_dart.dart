@@ -6,6 +6,26 @@
 /// - [bls12381MinPkAggregate] — aggregate multiple signatures into one
 /// - [bls12381MinPkVerifyAggregate] — verify an aggregate against multiple keys
 ///
+/// Around them, one library per area is exported:
+///
+/// - `bls` — checked, fast, AUG/PoP-scheme and multi-message verification
+/// - `aggregator` — incremental aggregation of signatures as they arrive
+/// - `batch` — batch and parallel verification, with cancellation and a
+///   per-item event stream
+/// - `bundle` — self-contained certificate bundles
+/// - `cache` — an on-disk record of verified certificates
+/// - `commitment` — Merkle vector commitments over committee keys
+/// - `committee` — signer bitmaps, quorums and committee handles
+/// - `diagnostics` — instrumented verification and signing statistics
+/// - `encoding` — conversions between point encodings
+/// - `keys` — key generation, derivation and password-protected export
+/// - `min_sig` — the min_sig scheme (G2 keys, G1 signatures)
+/// - `prehash` — hash-then-sign variants
+/// - `rlp` — signatures over RLP-encoded messages
+/// - `sui` — Sui intent messages, object references and committees
+/// - `threshold` — recombining Shamir-shared partial signatures
+/// - `verifier` — a decoded key reused across many verifications
+///
 /// Uses the [blst](https://github.com/supranational/blst) C library via
 /// [flutter_rust_bridge](https://pub.dev/packages/flutter_rust_bridge).
 ///
@@ -23,9 +43,20 @@
 /// ```
 library;
 
-export 'src/rust/api/bls.dart'
-    show
-        bls12381MinPkVerify,
-        bls12381MinPkAggregate,
-        bls12381MinPkVerifyAggregate;
+export 'src/rust/api/aggregator.dart';
+export 'src/rust/api/batch.dart';
+export 'src/rust/api/bls.dart';
+export 'src/rust/api/bundle.dart';
+export 'src/rust/api/cache.dart';
+export 'src/rust/api/commitment.dart';
+export 'src/rust/api/committee.dart';
+export 'src/rust/api/diagnostics.dart';
+export 'src/rust/api/encoding.dart';
+export 'src/rust/api/keys.dart';
+export 'src/rust/api/min_sig.dart';
+export 'src/rust/api/prehash.dart';
+export 'src/rust/api/rlp.dart';
+export 'src/rust/api/sui.dart';
+export 'src/rust/api/threshold.dart';
+export 'src/rust/api/verifier.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
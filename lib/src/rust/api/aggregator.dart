@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// Fold a newly arrived signer into a same-message aggregate and check
+/// that the quorum so far still verifies.
+///
+/// * `agg_sig_bytes` – 96-byte compressed aggregate of the signers so far,
+///   or empty before the first signer
+/// * `existing_pks`  – 48-byte compressed G1 keys of the signers so far,
+///   empty exactly when `agg_sig_bytes` is
+/// * `new_sig_bytes` – 96-byte compressed G2 signature of the new signer
+/// * `new_pk_bytes`  – 48-byte compressed G1 key of the new signer
+/// * `msg`           – the message every signer signed
+///
+/// Returns the 96-byte updated aggregate and whether it verifies against
+/// all keys including the new one, or `(empty, false)` when an input is
+/// malformed.
+(Uint8List,bool)  bls12381MinPkAddAndVerify({required List<int> aggSigBytes , required List<Uint8List> existingPks , required List<int> newSigBytes , required List<int> newPkBytes , required List<int> msg }) => RustLib.instance.api.crateApiAggregatorBls12381MinPkAddAndVerify(aggSigBytes: aggSigBytes, existingPks: existingPks, newSigBytes: newSigBytes, newPkBytes: newPkBytes, msg: msg);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SignatureAggregator>>
+                abstract class SignatureAggregator implements RustOpaqueInterface {
+                    /// Fold one signature into the aggregate.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// The signature is group-checked first and the identity is refused, so
+/// one bad response can be dropped without spoiling the rest.
+///
+/// Returns `true` when the signature was added, `false` (leaving the
+/// aggregate unchanged) when it is malformed.
+ bool  add({required List<int> sigBytes });
+
+
+/// Number of signatures added so far.
+ int  count();
+
+
+static Future<SignatureAggregator>  default_()=>RustLib.instance.api.crateApiAggregatorSignatureAggregatorDefault();
+
+
+/// The aggregate of every signature added so far.
+///
+/// The aggregator stays usable, so this can be called again after more
+/// signatures arrive.
+///
+/// Returns the 96-byte compressed aggregate, or an empty `Vec<u8>` when
+/// no signature has been added.
+ Uint8List  finalize();
+
+
+/// Restore an aggregator from [`SignatureAggregator::snapshot`].
+///
+/// The stored aggregate is group-checked like any added signature.
+///
+/// Returns `None` when the snapshot is truncated, malformed, or its
+/// count disagrees with whether an aggregate is present.
+static SignatureAggregator?  fromSnapshot({required List<int> state })=>RustLib.instance.api.crateApiAggregatorSignatureAggregatorFromSnapshot(state: state);
+
+
+/// Create an aggregator holding no signatures.
+factory SignatureAggregator()=>RustLib.instance.api.crateApiAggregatorSignatureAggregatorNew();
+
+
+/// Serialize the aggregator's progress, e.g. to survive an app restart
+/// or move it to another isolate.
+///
+/// The layout is `u32_le(count) || aggregate`, where the aggregate is
+/// the 96-byte compressed signature and is absent while `count` is 0.
+///
+/// Returns the snapshot for [`SignatureAggregator::from_snapshot`].
+ Uint8List  snapshot();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
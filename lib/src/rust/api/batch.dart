@@ -0,0 +1,134 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `batch_coefficients`, `reduce_default_threads`, `verify_pool`, `verify_threads`, `verify_with_events`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`
+
+
+            /// Set how many threads parallel batch verification may use.
+///
+/// * `n` – maximum number of threads, or `0` to restore the default of one
+///   thread per physical core (half that on a portable blst build, once
+///   [`crate::api::diagnostics::bls12381_min_pk_verify_adaptive`] has run)
+///
+/// Low-end phones may want to cap this to avoid thermal throttling. The
+/// setting is global: the thread pool is rebuilt at the new size, and
+/// batches already running finish on the old one.
+void  bls12381SetVerifyThreads({required int n }) => RustLib.instance.api.crateApiBatchBls12381SetVerifyThreads(n: n);
+
+/// Verify many independent signatures, spreading the work across threads.
+///
+/// * `pks_bytes`  – 48-byte compressed G1 public keys
+/// * `msgs`       – messages, one per key
+/// * `sigs_bytes` – 96-byte compressed G2 signatures, one per key
+///
+/// Each triple is verified on its own on the rayon pool, which has
+/// [`bls12381_set_verify_threads`] threads. When the pool can't be started
+/// the triples are verified on the calling thread instead.
+///
+/// Returns one result per triple, in input order, or an empty `Vec` when the
+/// three lists differ in length.
+Future<List<bool>>  bls12381MinPkBatchVerifyParallel({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<Uint8List> sigsBytes }) => RustLib.instance.api.crateApiBatchBls12381MinPkBatchVerifyParallel(pksBytes: pksBytes, msgs: msgs, sigsBytes: sigsBytes);
+
+/// Verify many independent signatures with a single final pairing check.
+///
+/// * `pks_bytes`  – 48-byte compressed G1 public keys
+/// * `msgs`       – messages, one per key
+/// * `sigs_bytes` – 96-byte compressed G2 signatures, one per key
+///
+/// Each triple is weighted by a random coefficient before the pairings are
+/// combined, so a bad signature cannot be cancelled out by another. This is
+/// much faster than verifying one by one, but only yields one verdict for
+/// the whole batch; use [`bls12381_min_pk_batch_verify_parallel`] to find
+/// the bad items.
+///
+/// Returns `true` only when every triple is valid. Returns `false` when the
+/// three lists differ in length or are empty, any input is malformed, or
+/// the OS entropy source fails.
+bool  bls12381MinPkBatchVerify({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<Uint8List> sigsBytes }) => RustLib.instance.api.crateApiBatchBls12381MinPkBatchVerify(pksBytes: pksBytes, msgs: msgs, sigsBytes: sigsBytes);
+
+/// Verify a batch of signatures, stopping early if `cancel` is set.
+///
+/// * `sigs`   – 96-byte compressed G2 signatures
+/// * `pks`    – 48-byte compressed G1 public keys
+/// * `msgs`   – messages, one per signature
+/// * `cancel` – token checked before each item
+///
+/// Runs asynchronously so the app can call [`CancelToken::cancel`] while it
+/// is in progress, e.g. when the user navigates away.
+///
+/// Returns one result per triple, in input order; items not processed
+/// before cancellation are `false`. Returns an empty `Vec` when the three
+/// lists differ in length.
+Future<List<bool>>  bls12381BatchVerifyCancellable({required List<Uint8List> sigs , required List<Uint8List> pks , required List<Uint8List> msgs , required CancelToken cancel }) => RustLib.instance.api.crateApiBatchBls12381BatchVerifyCancellable(sigs: sigs, pks: pks, msgs: msgs, cancel: cancel);
+
+/// Verify a batch while streaming one [`VerifyEvent`] per item to Dart.
+///
+/// * `sigs` – 96-byte compressed G2 signatures
+/// * `pks`  – 48-byte compressed G1 public keys, one per signature
+/// * `msgs` – messages, one per signature
+/// * `sink` – receives the events, in input order, as items complete
+///
+/// Items are verified one at a time on the calling worker. The stream
+/// closes when the batch is done; no events are sent when the three lists
+/// differ in length.
+Stream<VerifyEvent>  bls12381VerifyWithEvents({required List<Uint8List> sigs , required List<Uint8List> pks , required List<Uint8List> msgs }) => RustLib.instance.api.crateApiBatchBls12381VerifyWithEvents(sigs: sigs, pks: pks, msgs: msgs);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancelToken>>
+                abstract class CancelToken implements RustOpaqueInterface {
+                    /// Request cancellation of every batch observing this token.
+ void  cancel();
+
+
+static Future<CancelToken>  default_()=>RustLib.instance.api.crateApiBatchCancelTokenDefault();
+
+
+/// Whether [`CancelToken::cancel`] has been called.
+ bool  isCancelled();
+
+
+/// Create a token that is not yet cancelled.
+factory CancelToken()=>RustLib.instance.api.crateApiBatchCancelTokenNew();
+
+
+
+                    
+                }
+                
+
+/// Progress report for one item of a monitored batch.
+class VerifyEvent  {
+                /// Position of the item in the batch.
+final int index;
+/// Whether its signature verified.
+final bool valid;
+/// Time spent verifying it, in microseconds.
+final BigInt elapsedUs;
+
+                const VerifyEvent({required this.index ,required this.valid ,required this.elapsedUs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^valid.hashCode^elapsedUs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VerifyEvent &&
+                runtimeType == other.runtimeType
+                && index == other.index&& valid == other.valid&& elapsedUs == other.elapsedUs;
+        
+            }
+            
\ No newline at end of file
@@ -6,23 +6,72 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Verify a single BLS12-381 min_pk signature.
+
+            // These functions are ignored because they are not marked as `pub`: `aggregate_pks`, `aggregate_verify_with_dst`, `check_paired_lens`, `fast_aggregate_verify`, `is_identity_sig`, `messages_distinct`, `parse_pks`, `parse_valid_pks`, `parse_valid_sig`, `sign_with_dst`, `verify_aggregate_checked_with_dst`, `verify_checked_with_dst`, `verify_checked_with_flags`, `verify_with_dst`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Verify a single BLS12-381 min_pk signature.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including malformed inputs).
+bool  bls12381MinPkVerify({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerify(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Verify a single BLS12-381 min_pk signature under a caller-supplied DST.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+/// * `dst`       – domain separation tag the signer used
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including malformed inputs and an empty `dst`).
+bool  bls12381MinPkVerifyWithDst({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required List<int> dst }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyWithDst(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg, dst: dst);
+
+/// Verify a single BLS12-381 min_pk signature, reporting why it failed.
 ///
 /// * `sig_bytes` – 96-byte compressed G2 signature
 /// * `pk_bytes`  – 48-byte compressed G1 public key
 /// * `msg`       – arbitrary-length message
 ///
+/// Returns `Ok(())` when the signature is valid,
+/// [`BlsError::InvalidPublicKey`] or [`BlsError::InvalidSignature`] for a
+/// bad point, and [`BlsError::VerificationFailed`] otherwise.
+void  bls12381MinPkVerifyChecked({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyChecked(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Check whether a signature is the identity (point at infinity) of G2.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// The identity never comes from an honest signer, and pairs to one with
+/// any key, which naive verifiers can be tricked by. The verify functions
+/// here reject it before pairing.
+///
+/// Returns `true` only for a well-formed encoding of the identity.
+bool  bls12381MinPkIsIdentitySignature({required List<int> sigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkIsIdentitySignature(sigBytes: sigBytes);
+
+/// Verify a single BLS12-381 min_pk signature, optionally skipping the
+/// subgroup checks.
+///
+/// * `sig_bytes`       – 96-byte compressed G2 signature
+/// * `pk_bytes`        – 48-byte compressed G1 public key
+/// * `msg`             – arbitrary-length message
+/// * `check_sig_group` – check that the signature lies in G2
+/// * `validate_pk`     – check that the key lies in G1 and isn't the identity
+///
+/// With both flags set this is [`bls12381_min_pk_verify`]. Turning a check
+/// off saves a subgroup check per call, but is only safe when that input
+/// was already validated, e.g. with [`bls12381_min_pk_validate_signature`]
+/// or [`bls12381_min_pk_validate_pubkey`]. On untrusted inputs it opens
+/// the door to small-subgroup and identity-key attacks.
+///
 /// Returns `true` when the signature is valid, `false` otherwise
 /// (including malformed inputs).
-bool bls12381MinPkVerify({
-  required List<int> sigBytes,
-  required List<int> pkBytes,
-  required List<int> msg,
-}) => RustLib.instance.api.crateApiBlsBls12381MinPkVerify(
-  sigBytes: sigBytes,
-  pkBytes: pkBytes,
-  msg: msg,
-);
+bool  bls12381MinPkVerifyFast({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required bool checkSigGroup , required bool validatePk }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyFast(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg, checkSigGroup: checkSigGroup, validatePk: validatePk);
 
 /// Aggregate multiple BLS12-381 min_pk signatures into one.
 ///
@@ -30,10 +79,58 @@ bool bls12381MinPkVerify({
 ///
 /// Returns the 96-byte aggregate signature, or an empty `Vec<u8>` on error
 /// (e.g. empty list, malformed signature).
-Uint8List bls12381MinPkAggregate({required List<Uint8List> sigsBytes}) =>
-    RustLib.instance.api.crateApiBlsBls12381MinPkAggregate(
-      sigsBytes: sigsBytes,
-    );
+Uint8List  bls12381MinPkAggregate({required List<Uint8List> sigsBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregate(sigsBytes: sigsBytes);
+
+/// Aggregate signatures like [`bls12381_min_pk_aggregate`], reporting why
+/// it failed.
+///
+/// * `sigs_bytes` – list of 96-byte compressed G2 signatures
+///
+/// Returns the 96-byte aggregate signature, [`BlsError::EmptyInput`] for an
+/// empty list, or [`BlsError::InvalidSignature`] for a bad signature.
+Uint8List  bls12381MinPkAggregateChecked({required List<Uint8List> sigsBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregateChecked(sigsBytes: sigsBytes);
+
+/// Merge aggregate signatures, e.g. partial aggregates from several shards,
+/// into one.
+///
+/// * `agg_sigs` – list of 96-byte compressed G2 aggregate signatures
+///
+/// An aggregate is itself a G2 point and aggregation is associative, so
+/// merging `[s1]` with `[s2, s3]` gives the same bytes as aggregating
+/// `[s1, s2, s3]` at once.
+///
+/// Returns the 96-byte merged aggregate, or an empty `Vec<u8>` on error
+/// (e.g. empty list, malformed aggregate).
+Uint8List  bls12381MinPkAggregateAggregates({required List<Uint8List> aggSigs }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregateAggregates(aggSigs: aggSigs);
+
+/// Remove one signature from an aggregate, e.g. a signer later found
+/// invalid or slashed.
+///
+/// * `agg_sig_bytes` – 96-byte compressed G2 aggregate signature
+/// * `sig_to_remove` – 96-byte compressed G2 signature included in it
+///
+/// Subtracts the signature's point from the aggregate, so removing `s2`
+/// from the aggregate of `[s1, s2, s3]` gives the aggregate of `[s1, s3]`.
+/// Nothing checks that `sig_to_remove` was actually part of the aggregate;
+/// verify the result against the remaining keys. Removing the only
+/// signature leaves the identity, which never verifies.
+///
+/// Returns the 96-byte new aggregate, or an empty `Vec<u8>` when either
+/// input is malformed or outside G2.
+Uint8List  bls12381MinPkDeaggregate({required List<int> aggSigBytes , required List<int> sigToRemove }) => RustLib.instance.api.crateApiBlsBls12381MinPkDeaggregate(aggSigBytes: aggSigBytes, sigToRemove: sigToRemove);
+
+/// Aggregate BLS12-381 min_pk public keys into one.
+///
+/// * `pks_bytes` – list of 48-byte compressed G1 public keys
+///
+/// A same-message aggregate signature from these keys verifies against the
+/// result with [`bls12381_min_pk_verify`], so a fixed quorum's key can be
+/// computed once. Aggregation offers no rogue-key protection by itself;
+/// only combine keys whose possession was proven.
+///
+/// Returns the 48-byte aggregate public key, or an empty `Vec<u8>` on error
+/// (e.g. empty list, malformed or non-subgroup key).
+Uint8List  bls12381MinPkAggregatePubkeys({required List<Uint8List> pksBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregatePubkeys(pksBytes: pksBytes);
 
 /// Verify an aggregate BLS12-381 min_pk signature where all signers signed
 /// the same message.
@@ -43,12 +140,490 @@ Uint8List bls12381MinPkAggregate({required List<Uint8List> sigsBytes}) =>
 /// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
 ///
 /// Returns `true` when the aggregate signature is valid, `false` otherwise.
-bool bls12381MinPkVerifyAggregate({
-  required List<Uint8List> pksBytes,
-  required List<int> msg,
-  required List<int> aggSigBytes,
-}) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyAggregate(
-  pksBytes: pksBytes,
-  msg: msg,
-  aggSigBytes: aggSigBytes,
-);
+bool  bls12381MinPkVerifyAggregate({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyAggregate(pksBytes: pksBytes, msg: msg, aggSigBytes: aggSigBytes);
+
+/// Verify a same-message aggregate like
+/// [`bls12381_min_pk_verify_aggregate`], reporting why it failed.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Returns `Ok(())` when the aggregate is valid, [`BlsError::EmptyInput`]
+/// for no keys, [`BlsError::InvalidPublicKey`] or
+/// [`BlsError::InvalidSignature`] for a malformed point, and
+/// [`BlsError::VerificationFailed`] otherwise.
+void  bls12381MinPkVerifyAggregateChecked({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyAggregateChecked(pksBytes: pksBytes, msg: msg, aggSigBytes: aggSigBytes);
+
+/// Verify a same-message aggregate under a caller-supplied DST.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+/// * `dst`           – domain separation tag the signers used
+///
+/// Returns `true` when the aggregate signature is valid, `false` otherwise
+/// (including an empty `dst`).
+bool  bls12381MinPkVerifyAggregateWithDst({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes , required List<int> dst }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyAggregateWithDst(pksBytes: pksBytes, msg: msg, aggSigBytes: aggSigBytes, dst: dst);
+
+/// Sign a message with a BLS12-381 min_pk secret key.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+/// * `msg`      – arbitrary-length message
+///
+/// Signs under the same DST the verify functions use, with no
+/// augmentation.
+///
+/// Returns the 96-byte compressed G2 signature, or an empty `Vec<u8>` when
+/// the secret key is malformed.
+Uint8List  bls12381MinPkSign({required List<int> skBytes , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381MinPkSign(skBytes: skBytes, msg: msg);
+
+/// Sign a message under a caller-supplied DST.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+/// * `msg`      – arbitrary-length message
+/// * `dst`      – domain separation tag of the target deployment, e.g.
+///   Ethereum consensus' `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`
+///
+/// Returns the 96-byte compressed G2 signature, or an empty `Vec<u8>` when
+/// the secret key is malformed or `dst` is empty.
+Uint8List  bls12381MinPkSignWithDst({required List<int> skBytes , required List<int> msg , required List<int> dst }) => RustLib.instance.api.crateApiBlsBls12381MinPkSignWithDst(skBytes: skBytes, msg: msg, dst: dst);
+
+/// Check a public key once, e.g. when importing it into a key cache.
+///
+/// * `pk_bytes` – 48-byte compressed G1 public key
+///
+/// Runs the same checks verification does: the point must decode, lie on
+/// the curve and in G1, and not be the identity.
+///
+/// Returns `true` when the key is valid, `false` otherwise.
+bool  bls12381MinPkValidatePubkey({required List<int> pkBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkValidatePubkey(pkBytes: pkBytes);
+
+/// Check a signature before aggregating it.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// The point must decode, lie on the curve and in G2, and not be the
+/// identity, so a garbage response can be dropped on its own instead of
+/// spoiling an aggregate.
+///
+/// Returns `true` when the signature is a valid group element, `false`
+/// otherwise. Whether it verifies for any message is not checked.
+bool  bls12381MinPkValidateSignature({required List<int> sigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkValidateSignature(sigBytes: sigBytes);
+
+/// Derive the chain-specific DST for `chain_id`.
+///
+/// The scheme is `DST || u64_be(chain_id)`: the standard NUL-scheme tag with
+/// the big-endian chain ID appended. Signatures made for one chain therefore
+/// never verify on another, which prevents cross-chain replay.
+///
+/// Returns the 51-byte DST.
+Uint8List  bls12381ChainDst({required BigInt chainId }) => RustLib.instance.api.crateApiBlsBls12381ChainDst(chainId: chainId);
+
+/// Verify a single BLS12-381 min_pk signature under the DST of `chain_id`.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+/// * `chain_id`  – chain the signature was produced for
+///
+/// See [`bls12381_chain_dst`] for how the DST is derived.
+///
+/// Returns `true` when the signature is valid for that chain, `false`
+/// otherwise (including malformed inputs).
+bool  bls12381MinPkVerifyChain({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required BigInt chainId }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyChain(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg, chainId: chainId);
+
+/// Convert a signature produced by another BLS library into blst's canonical
+/// 96-byte compressed encoding.
+///
+/// * `sig_bytes` – the foreign signature bytes
+/// * `library`   – `0` = py_ecc, `1` = zcash, `2` = Ethereum consensus
+///
+/// All three build on the ZCash BLS12-381 serialization, but differ in what
+/// they emit: py_ecc and zcash tooling commonly hand out the 192-byte
+/// uncompressed point, while Ethereum mandates the 96-byte compressed form.
+/// The input is parsed in any form its library produces, subgroup-checked,
+/// then re-serialized compressed.
+///
+/// Returns the 96-byte canonical signature, or an empty `Vec<u8>` for an
+/// unsupported `library` or an invalid point.
+Uint8List  bls12381NormalizeForeignSignature({required List<int> sigBytes , required int library_ }) => RustLib.instance.api.crateApiBlsBls12381NormalizeForeignSignature(sigBytes: sigBytes, library_: library_);
+
+/// Verify a single BLS12-381 min_pk signature, rejecting the zero message.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Some protocols reserve the all-zero message as a sentinel, so a message
+/// made entirely of zero bytes (including the empty message) is rejected
+/// before any verification is attempted.
+///
+/// Returns `true` when the message is non-zero and the signature is valid.
+bool  bls12381MinPkVerifyNonzero({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyNonzero(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Sign a message under the AUG scheme with an explicit augmentation.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+/// * `msg`      – arbitrary-length message
+/// * `aug`      – bytes prepended to `msg` before hashing; the standard
+///   AUG scheme uses the signer's 48-byte compressed public key
+///
+/// With `aug` set to the signer's key, the result aggregates and verifies
+/// with [`bls12381_min_pk_verify_aggregate_aug`].
+///
+/// Returns the 96-byte compressed G2 signature, or an empty `Vec<u8>` when
+/// the secret key is malformed or `aug` is empty.
+Uint8List  bls12381MinPkSignAug({required List<int> skBytes , required List<int> msg , required List<int> aug }) => RustLib.instance.api.crateApiBlsBls12381MinPkSignAug(skBytes: skBytes, msg: msg, aug: aug);
+
+/// Verify an AUG-scheme signature made with an explicit augmentation.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+/// * `aug`       – the augmentation the signer used
+///
+/// Returns `true` when the signature is valid over `aug || msg`, `false`
+/// otherwise (including malformed inputs, the identity signature and an
+/// empty `aug`).
+bool  bls12381MinPkVerifyAug({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required List<int> aug }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyAug(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg, aug: aug);
+
+/// Aggregate BLS12-381 min_pk signatures produced with message augmentation.
+///
+/// * `sigs` – list of 96-byte compressed G2 AUG-scheme signatures
+///
+/// Aggregation is the same point addition as for the basic scheme; this
+/// exists so callers pair it with [`bls12381_min_pk_verify_aggregate_aug`].
+///
+/// Returns the 96-byte aggregate signature, or an empty `Vec<u8>` on error.
+Uint8List  bls12381MinPkAggregateAug({required List<Uint8List> sigs }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregateAug(sigs: sigs);
+
+/// Verify an aggregate of AUG-scheme signatures where every signer signed
+/// the same message.
+///
+/// * `pks`     – list of 48-byte compressed G1 public keys
+/// * `msg`     – the message all signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+///
+/// Each signer actually signed `pk_i || msg` under the AUG DST, so the
+/// messages differ per signer and distinct-message aggregate verification
+/// is used instead of the same-message fast path.
+///
+/// Returns `true` when the aggregate signature is valid, `false` otherwise.
+bool  bls12381MinPkVerifyAggregateAug({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyAggregateAug(pks: pks, msg: msg, aggSig: aggSig);
+
+/// Verify an aggregate BLS12-381 min_pk signature where each signer signed
+/// its own message.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msgs`          – one message per key, in the same order
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// As the basic scheme requires, the messages must be pairwise distinct;
+/// for a shared message use [`bls12381_min_pk_verify_aggregate`].
+///
+/// Returns `false` on a length mismatch, empty input, repeated messages, a
+/// malformed input, or an invalid aggregate.
+bool  bls12381MinPkAggregateVerify({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregateVerify(pksBytes: pksBytes, msgs: msgs, aggSigBytes: aggSigBytes);
+
+/// Verify a distinct-message aggregate like
+/// [`bls12381_min_pk_aggregate_verify`], reporting why it failed.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msgs`          – one message per key, in the same order
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// There is no limit on the number of signers beyond memory; each adds
+/// one Miller loop to the final pairing check.
+///
+/// Returns `Ok(())` when the aggregate is valid, [`BlsError::EmptyInput`]
+/// for no keys, [`BlsError::InvalidLength`] when the lists differ in
+/// length, [`BlsError::RepeatedMessage`] for a message used twice,
+/// [`BlsError::InvalidPublicKey`] or [`BlsError::InvalidSignature`] for a
+/// bad point, and [`BlsError::VerificationFailed`] otherwise.
+void  bls12381MinPkAggregateVerifyChecked({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregateVerifyChecked(pksBytes: pksBytes, msgs: msgs, aggSigBytes: aggSigBytes);
+
+/// Verify an aggregate that mixes a shared-message group with signers of
+/// their own messages, in a single pairing check.
+///
+/// * `shared_pks`    – 48-byte compressed G1 public keys that signed
+///   `shared_msg`
+/// * `shared_msg`    – the message the shared group signed
+/// * `distinct_pks`  – 48-byte compressed G1 public keys of the other signers
+/// * `distinct_msgs` – one message per distinct key, in the same order
+/// * `agg_sig_bytes` – 96-byte compressed aggregate of every signature
+///
+/// The shared keys are summed into one term as in
+/// [`bls12381_min_pk_verify_aggregate`], so the whole group costs one
+/// Miller loop; each distinct signer adds one more, as in
+/// [`bls12381_min_pk_aggregate_verify`]. The distinct messages must be
+/// pairwise distinct and differ from `shared_msg`. With no shared keys this
+/// is [`bls12381_min_pk_aggregate_verify`].
+///
+/// Returns `true` when the aggregate is valid, `false` otherwise (including
+/// mismatched distinct list lengths, no signers, a repeated message, or a
+/// malformed input).
+bool  bls12381MinPkVerifyMixed({required List<Uint8List> sharedPks , required List<int> sharedMsg , required List<Uint8List> distinctPks , required List<Uint8List> distinctMsgs , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyMixed(sharedPks: sharedPks, sharedMsg: sharedMsg, distinctPks: distinctPks, distinctMsgs: distinctMsgs, aggSigBytes: aggSigBytes);
+
+/// Salvage a partially corrupt signature set into a valid aggregate.
+///
+/// * `sigs` – 96-byte compressed G2 signatures
+/// * `pks`  – 48-byte compressed G1 public keys, one per signature
+/// * `msg`  – the message every signer signed
+///
+/// Each signature is verified individually against its key; invalid ones
+/// are dropped and the rest re-aggregated.
+///
+/// Returns the repaired aggregate. When `sigs` and `pks` differ in length
+/// nothing is verified: the aggregate is empty and every index of `sigs` is
+/// dropped.
+RepairedAggregate  bls12381RepairAggregate({required List<Uint8List> sigs , required List<Uint8List> pks , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381RepairAggregate(sigs: sigs, pks: pks, msg: msg);
+
+/// Verify an outer committee's attestation to an inner aggregate signature.
+///
+/// * `outer_pks`     – 48-byte compressed G1 public keys of the outer signers
+/// * `inner_agg_sig` – 96-byte compressed inner aggregate, the signed message
+/// * `outer_agg_sig` – 96-byte compressed outer aggregate G2 signature
+///
+/// The outer signers sign the compressed bytes of the inner aggregate, so
+/// chaining this check layer by layer certifies a multi-level attestation.
+/// The inner signature itself is not verified here.
+///
+/// Returns `false` when `inner_agg_sig` is not a compressed G2 point, or
+/// the outer aggregate does not verify over it.
+bool  bls12381VerifyNested({required List<Uint8List> outerPks , required List<int> innerAggSig , required List<int> outerAggSig }) => RustLib.instance.api.crateApiBlsBls12381VerifyNested(outerPks: outerPks, innerAggSig: innerAggSig, outerAggSig: outerAggSig);
+
+/// Verify a single signature and classify any failure for retry logic.
+///
+/// * `sig` – 96-byte compressed G2 signature
+/// * `pk`  – 48-byte compressed G1 public key
+/// * `msg` – arbitrary-length message
+///
+/// Returns [`VerifyCategory::RecoverableBadInput`] when either point fails
+/// to decode, is off the curve or outside its subgroup, or the key is the
+/// identity, and [`VerifyCategory::FatalInvalidSignature`] when the
+/// signature is the identity or both points are valid but the pairing
+/// check fails.
+VerifyCategory  bls12381MinPkVerifyCategorized({required List<int> sig , required List<int> pk , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyCategorized(sig: sig, pk: pk, msg: msg);
+
+/// Verify a single signature and return blst's raw error code.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Meant for debugging failed verifications. Apart from `-1`, the codes are
+/// `BLST_ERROR` discriminants:
+///
+/// * `-1` – an input has the wrong length; nothing was parsed
+/// * `0`  – `BLST_SUCCESS`, the signature is valid
+/// * `1`  – `BLST_BAD_ENCODING`
+/// * `2`  – `BLST_POINT_NOT_ON_CURVE`
+/// * `3`  – `BLST_POINT_NOT_IN_GROUP`
+/// * `5`  – `BLST_VERIFY_FAIL`, the signature is the identity or the
+///   pairing check failed
+/// * `6`  – `BLST_PK_IS_INFINITY`
+///
+/// Decoding errors are reported for the public key first, then the
+/// signature; subgroup checks run as in [`bls12381_min_pk_verify`].
+///
+/// Returns the code.
+int  bls12381MinPkVerifyWithError({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyWithError(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Aggregate only the signatures that verify individually.
+///
+/// * `sigs` – 96-byte compressed G2 signatures
+/// * `pks`  – 48-byte compressed G1 public keys, one per signature
+/// * `msg`  – the message every signer signed
+///
+/// Like [`bls12381_repair_aggregate`], but returns just the aggregate.
+///
+/// Returns the 96-byte aggregate of the valid signatures, or an empty
+/// `Vec<u8>` when none is valid or `sigs` and `pks` differ in length.
+Uint8List  bls12381MinPkAggregateVerified({required List<Uint8List> sigs , required List<Uint8List> pks , required List<int> msg }) => RustLib.instance.api.crateApiBlsBls12381MinPkAggregateVerified(sigs: sigs, pks: pks, msg: msg);
+
+/// Verify a signature over a nonce-suffixed message, rejecting reused
+/// nonces.
+///
+/// * `sig`         – 96-byte compressed G2 signature
+/// * `pk`          – 48-byte compressed G1 public key
+/// * `msg`         – the message, without the nonce
+/// * `nonce`       – nonce the signer appended to `msg`
+/// * `seen_nonces` – nonces already accepted in this epoch
+///
+/// The signed message is `msg || u64_be(nonce)`. Tracking `seen_nonces`
+/// is up to the caller.
+///
+/// Returns [`VerifyOutcome::ReplayDetected`] when `nonce` is in
+/// `seen_nonces` (checked before any cryptography),
+/// [`VerifyOutcome::MalformedInput`] when a point fails to decode, and
+/// [`VerifyOutcome::InvalidSignature`] when the signature does not verify.
+VerifyOutcome  bls12381MinPkVerifyWithNonce({required List<int> sig , required List<int> pk , required List<int> msg , required BigInt nonce , required Uint64List seenNonces }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyWithNonce(sig: sig, pk: pk, msg: msg, nonce: nonce, seenNonces: seenNonces);
+
+/// Verify an aggregate signature, falling back to per-signer checks to
+/// pinpoint the culprits when it fails.
+///
+/// * `pks`     – 48-byte compressed G1 public keys of the signers
+/// * `sigs`    – the individual 96-byte signatures, one per key, or empty
+/// * `msg`     – the shared message all signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+///
+/// The fast aggregate check runs first; the individual signatures are only
+/// verified if it fails and `sigs` has one entry per key.
+FallbackResult  bls12381VerifyAggregateWithFallback({required List<Uint8List> pks , required List<Uint8List> sigs , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiBlsBls12381VerifyAggregateWithFallback(pks: pks, sigs: sigs, msg: msg, aggSig: aggSig);
+
+/// Prove possession of a BLS12-381 min_pk secret key.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+///
+/// Signs the compressed public key under the PoP DST. Registering keys only
+/// together with a valid proof rules out rogue-key attacks on same-message
+/// aggregation.
+///
+/// Returns the 96-byte proof, or an empty `Vec<u8>` when the secret key is
+/// malformed.
+Uint8List  bls12381MinPkPopProve({required List<int> skBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkPopProve(skBytes: skBytes);
+
+/// Verify a proof of possession from [`bls12381_min_pk_pop_prove`].
+///
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `pop_bytes` – 96-byte compressed G2 proof
+///
+/// Returns `true` when the proof is a signature over `pk_bytes` by the
+/// matching secret key, `false` otherwise (including malformed inputs).
+bool  bls12381MinPkPopVerify({required List<int> pkBytes , required List<int> popBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkPopVerify(pkBytes: pkBytes, popBytes: popBytes);
+
+/// Verify a same-message aggregate from signers who published proofs of
+/// possession.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `pops_bytes`    – proof of possession of each key, in the same order
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Every proof is checked with [`bls12381_min_pk_pop_verify`] before the
+/// aggregate is verified like [`bls12381_min_pk_verify_aggregate`], so a
+/// rogue key without a valid proof can't forge the aggregate.
+///
+/// Returns `true` when every proof and the aggregate signature are valid,
+/// `false` otherwise (including mismatched list lengths and no keys).
+bool  bls12381MinPkVerifyAggregatePop({required List<Uint8List> pksBytes , required List<Uint8List> popsBytes , required List<int> msg , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiBlsBls12381MinPkVerifyAggregatePop(pksBytes: pksBytes, popsBytes: popsBytes, msg: msg, aggSigBytes: aggSigBytes);
+
+/// Look up the DST this library uses for a signature scheme.
+///
+/// * `scheme` – one of `"min_pk_nul"`, `"min_pk_aug"`, `"min_pk_pop"` or
+///   `"min_sig_nul"`
+///
+/// `"min_pk_pop"` gives the tag proofs of possession are signed under by
+/// [`bls12381_min_pk_pop_prove`]; messages in a PoP setup are still signed
+/// under `"min_pk_nul"`.
+///
+/// Returns the DST bytes, or an empty `Vec<u8>` for an unknown scheme.
+Uint8List  bls12381DstForScheme({required String scheme }) => RustLib.instance.api.crateApiBlsBls12381DstForScheme(scheme: scheme);
+
+            /// Why a checked operation failed.
+enum BlsError {
+                    /// A public key is malformed, off the curve, outside G1 or the identity.
+invalidPublicKey,
+/// A signature is malformed, off the curve, outside G2 or the identity.
+invalidSignature,
+/// Input lists that must pair up differ in length.
+invalidLength,
+/// The inputs are well-formed but the signature does not verify.
+verificationFailed,
+/// A list that needs at least one element is empty.
+emptyInput,
+/// Messages that must be pairwise distinct contain a repeat.
+repeatedMessage,
+                    ;
+                    
+                }
+
+/// Aggregate verdict plus the signers to blame when it fails.
+class FallbackResult  {
+                /// Whether the aggregate signature verified.
+final bool aggregateValid;
+/// Indices of the signatures that failed individual verification; empty
+/// when the aggregate verified or no individual signatures were given.
+final Uint32List failing;
+
+                const FallbackResult({required this.aggregateValid ,required this.failing ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => aggregateValid.hashCode^failing.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FallbackResult &&
+                runtimeType == other.runtimeType
+                && aggregateValid == other.aggregateValid&& failing == other.failing;
+        
+            }
+
+/// An aggregate rebuilt from only the signatures that verified.
+class RepairedAggregate  {
+                /// 96-byte aggregate of the valid signatures, empty if none were valid.
+final Uint8List aggregate;
+/// Input indices of the signatures that failed and were left out.
+final Uint32List dropped;
+
+                const RepairedAggregate({required this.aggregate ,required this.dropped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => aggregate.hashCode^dropped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RepairedAggregate &&
+                runtimeType == other.runtimeType
+                && aggregate == other.aggregate&& dropped == other.dropped;
+        
+            }
+
+/// Whether retrying a failed verification can help.
+enum VerifyCategory {
+                    /// The signature is valid.
+valid,
+/// An input is malformed (wrong length, bad encoding, not a valid group
+/// element); retrying with corrected input may succeed.
+recoverableBadInput,
+/// The inputs are well-formed but the signature does not verify;
+/// retrying with the same data will not help.
+fatalInvalidSignature,
+                    ;
+                    
+                }
+
+/// Outcome of a verification that can fail for more than one reason.
+enum VerifyOutcome {
+                    /// The signature is valid and all policy checks passed.
+valid,
+/// An input could not be parsed (wrong length, bad encoding, bit out of
+/// range).
+malformedInput,
+/// The inputs parsed but the signature does not verify.
+invalidSignature,
+/// Too few committee members took part to form a quorum.
+quorumNotMet,
+/// A committee member is marked as both signing and abstaining.
+overlappingBitmaps,
+/// The message's nonce has already been used.
+replayDetected,
+                    ;
+                    
+                }
+            
\ No newline at end of file
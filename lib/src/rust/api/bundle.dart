@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode`, `verify`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Bundle`
+
+
+            /// Pack a committee certificate into a self-contained bundle.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `epoch`         – epoch the certificate belongs to
+/// * `bitmap`        – signer bitmap; shorter bitmaps are zero-padded
+/// * `msg`           – the certified message
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+///
+/// Returns the encoded bundle, or an empty `Vec<u8>` when a key or the
+/// signature has the wrong length, or the bitmap has bits beyond the
+/// committee.
+Uint8List  bls12381BundleEncode({required List<Uint8List> committeePks , required BigInt epoch , required List<int> bitmap , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiBundleBls12381BundleEncode(committeePks: committeePks, epoch: epoch, bitmap: bitmap, msg: msg, aggSig: aggSig);
+
+/// Verify a certificate bundle.
+///
+/// * `bundle` – bytes produced by [`bls12381_bundle_encode`]
+///
+/// Returns `true` when the bundle decodes, its signers are more than 2/3 of
+/// the committee, and the aggregate signature verifies over the message.
+bool  bls12381BundleVerify({required List<int> bundle }) => RustLib.instance.api.crateApiBundleBls12381BundleVerify(bundle: bundle);
+
+/// Decode a certificate bundle and report its metadata and verdict.
+///
+/// * `bundle` – bytes produced by [`bls12381_bundle_encode`]
+///
+/// Returns `None` when the bundle does not decode, including when the bitmap
+/// has bits set past the committee size.
+BundleInfo?  bls12381BundleInspect({required List<int> bundle }) => RustLib.instance.api.crateApiBundleBls12381BundleInspect(bundle: bundle);
+
+            /// What a certificate bundle contains, plus whether it verifies.
+class BundleInfo  {
+                /// Number of committee members.
+final int committeeSize;
+/// Number of signers set in the bitmap.
+final int signerCount;
+/// Epoch the certificate belongs to.
+final BigInt epoch;
+/// Same verdict as [`bls12381_bundle_verify`].
+final bool valid;
+
+                const BundleInfo({required this.committeeSize ,required this.signerCount ,required this.epoch ,required this.valid ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => committeeSize.hashCode^signerCount.hashCode^epoch.hashCode^valid.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BundleInfo &&
+                runtimeType == other.runtimeType
+                && committeeSize == other.committeeSize&& signerCount == other.signerCount&& epoch == other.epoch&& valid == other.valid;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `append`, `record`, `verify_and_persist`
+
+
+            /// Verify an aggregate signature and, on success, append a record of it to
+/// a local cache file.
+///
+/// * `pks`        – 48-byte compressed G1 public keys of the signers
+/// * `msg`        – the shared message all signers signed
+/// * `agg_sig`    – 96-byte compressed aggregate G2 signature
+/// * `cache_path` – file to append to; created if missing
+///
+/// Each record is `committee_digest || SHA-256(msg) || u64_le(unix_secs)`,
+/// 72 bytes. Nothing is written for a failed verification.
+///
+/// Returns the verification verdict, whether or not the record could be
+/// written. Whether this call's write failed is reported through
+/// [`bls12381_last_persist_error`].
+bool  bls12381VerifyAndPersist({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig , required String cachePath }) => RustLib.instance.api.crateApiCacheBls12381VerifyAndPersist(pks: pks, msg: msg, aggSig: aggSig, cachePath: cachePath);
+
+/// The cache write failure of the latest [`bls12381_verify_and_persist`]
+/// call.
+///
+/// Returns an empty `String` when that call wrote its record or had nothing
+/// to write.
+String  bls12381LastPersistError() => RustLib.instance.api.crateApiCacheBls12381LastPersistError();
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `leaf_hash`, `node_hash`, `proof_root`, `tree_levels`
+
+
+            /// Commit to a committee's public keys.
+///
+/// * `pks` – 48-byte compressed G1 public keys, in committee order
+///
+/// Returns the 32-byte Merkle root, or an empty `Vec<u8>` if the committee
+/// is empty or a key is not 48 bytes.
+Uint8List  bls12381VcCommit({required List<Uint8List> pks }) => RustLib.instance.api.crateApiCommitmentBls12381VcCommit(pks: pks);
+
+/// Build the inclusion proof for the committee member at `index`.
+///
+/// * `pks`   – 48-byte compressed G1 public keys, in committee order
+/// * `index` – position of the member whose proof is wanted
+///
+/// Returns the encoded proof, or an empty `Vec<u8>` if `index` is out of
+/// range or a key is not 48 bytes.
+Uint8List  bls12381VcProve({required List<Uint8List> pks , required int index }) => RustLib.instance.api.crateApiCommitmentBls12381VcProve(pks: pks, index: index);
+
+/// Verify an aggregate signature from signers whose keys are proven members
+/// of a committed committee.
+///
+/// * `commitment` – 32-byte root from [`bls12381_vc_commit`]
+/// * `pks`        – 48-byte compressed G1 public keys of the signers
+/// * `vc_proofs`  – one proof from [`bls12381_vc_prove`] per key, same order
+/// * `msg`        – the message the signers signed
+/// * `agg_sig`    – 96-byte compressed aggregate G2 signature
+///
+/// Returns `true` only when every key's proof leads to `commitment`, no
+/// committee position is proven twice, and the aggregate signature
+/// verifies. Returns `false` on a length mismatch, no signers, a repeated
+/// signer, or any malformed input.
+bool  bls12381VerifyAggregateVc({required List<int> commitment , required List<Uint8List> pks , required List<Uint8List> vcProofs , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitmentBls12381VerifyAggregateVc(commitment: commitment, pks: pks, vcProofs: vcProofs, msg: msg, aggSig: aggSig);
+
+            
+            
\ No newline at end of file
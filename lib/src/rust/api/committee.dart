@@ -0,0 +1,501 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'bls.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_delta`, `bitmap_indices`, `bitmap_len`, `committee_change_bytes`, `digest_of`, `from_parts`, `is_bft_quorum`, `is_bft_weight_quorum`, `verify_certificate`, `verify_signers`
+
+
+            /// Compute the on-wire size of a set of signatures before transmission.
+///
+/// * `num_sigs`   – number of signatures collected
+/// * `aggregated` – whether they are sent as one aggregate plus a bitmap
+///
+/// Returns `num_sigs * 96` for individual signatures, or `96` plus a
+/// `ceil(num_sigs / 8)`-byte signer bitmap for an aggregate.
+BigInt  bls12381TransmissionSize({required int numSigs , required bool aggregated }) => RustLib.instance.api.crateApiCommitteeBls12381TransmissionSize(numSigs: numSigs, aggregated: aggregated);
+
+/// Verify an aggregate signature from a committee where members may have
+/// explicitly abstained.
+///
+/// * `committee_pks`  – 48-byte compressed G1 public keys, in committee order
+/// * `signed_bitmap`  – bitmap of members whose signatures are in `agg_sig`
+/// * `abstain_bitmap` – bitmap of members that explicitly abstained
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// The two bitmaps must be disjoint, and signers plus abstainers together
+/// must form a quorum (more than 2/3 of the committee) of participation.
+///
+/// Returns [`VerifyOutcome::OverlappingBitmaps`] when a member is in both
+/// bitmaps, [`VerifyOutcome::QuorumNotMet`] when participation is too low
+/// or nobody signed, and [`VerifyOutcome::MalformedInput`] on unparseable
+/// keys or out-of-range bits.
+VerifyOutcome  bls12381VerifyWithAbstentions({required List<Uint8List> committeePks , required List<int> signedBitmap , required List<int> abstainBitmap , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyWithAbstentions(committeePks: committeePks, signedBitmap: signedBitmap, abstainBitmap: abstainBitmap, msg: msg, aggSig: aggSig);
+
+/// Check that a validator took part in a certificate.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `validator_pk`  – the validator's 48-byte compressed public key
+/// * `bitmap`        – the certificate's signer bitmap
+///
+/// Returns `true` when `validator_pk` is a committee member and the bit at
+/// its committee position is set. Returns `false` when the validator is not
+/// in the committee or the bitmap has bits beyond the committee size.
+bool  bls12381ParticipationProof({required List<Uint8List> committeePks , required List<int> validatorPk , required List<int> bitmap }) => RustLib.instance.api.crateApiCommitteeBls12381ParticipationProof(committeePks: committeePks, validatorPk: validatorPk, bitmap: bitmap);
+
+/// Verify a stream of certificates from the same committee.
+///
+/// * `committee` – prepared committee, see [`CommitteeHandle::new`]
+/// * `certs`     – `(bitmap, msg, agg_sig)` per certificate
+///
+/// Only the signatures are checked; quorum policy is up to the caller.
+///
+/// Returns one result per certificate, in input order. A certificate with
+/// no signers, an out-of-range bit, or a malformed signature is `false`.
+List<bool>  bls12381VerifyManyCertificates({required CommitteeHandle committee , required List<(Uint8List,Uint8List,Uint8List)> certs }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyManyCertificates(committee: committee, certs: certs);
+
+/// Verify several independent aggregate signatures, e.g. one per epoch,
+/// in one call.
+///
+/// * `committees` – per aggregate, the 48-byte compressed G1 public keys of
+///   its signers
+/// * `msgs`       – per aggregate, the message its signers signed
+/// * `agg_sigs`   – the 96-byte compressed aggregate G2 signatures
+///
+/// Each committee's keys are summed, then all aggregates are checked
+/// together with [`bls12381_min_pk_batch_verify`], so the cost is one
+/// random-coefficient batch rather than one pairing check per aggregate.
+///
+/// Returns `true` only when every aggregate verifies. Returns `false` when
+/// the three lists differ in length or are empty, a committee is empty, or
+/// any input is malformed.
+bool  bls12381MinPkVerifyAggregates({required List<List<Uint8List>> committees , required List<Uint8List> msgs , required List<Uint8List> aggSigs }) => RustLib.instance.api.crateApiCommitteeBls12381MinPkVerifyAggregates(committees: committees, msgs: msgs, aggSigs: aggSigs);
+
+/// Compute the 32-byte digest that identifies a committee.
+///
+/// * `pks` – 48-byte compressed G1 public keys, in committee order
+///
+/// The digest is `SHA-256(pk_0 || pk_1 || ... || pk_{n-1})` over the
+/// canonical compressed encodings, so it changes if any key or the order
+/// changes.
+///
+/// Returns the 32-byte digest, or an empty `Vec<u8>` for an empty committee
+/// or a malformed key.
+Uint8List  bls12381CommitteeDigest({required List<Uint8List> pks }) => RustLib.instance.api.crateApiCommitteeBls12381CommitteeDigest(pks: pks);
+
+/// Compute a committee's aggregate public key and digest together.
+///
+/// * `pks` – 48-byte compressed G1 public keys, in committee order
+///
+/// Returns `None` for an empty committee or a malformed key.
+CommitteeSetup?  bls12381CommitteeSetup({required List<Uint8List> pks }) => RustLib.instance.api.crateApiCommitteeBls12381CommitteeSetup(pks: pks);
+
+/// Aggregate the public keys of the committee members selected by `bitmap`.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`        – signer bitmap selecting the subset
+///
+/// The result can be reused with [`crate::api::bls::bls12381_min_pk_verify`]
+/// to check several aggregate signatures, over different messages, from the
+/// same signer subset.
+///
+/// Returns the 48-byte aggregate key, or an empty `Vec<u8>` when no bit is
+/// set, a bit is out of range, or a key is malformed.
+Uint8List  bls12381SubsetAggregatePk({required List<Uint8List> committeePks , required List<int> bitmap }) => RustLib.instance.api.crateApiCommitteeBls12381SubsetAggregatePk(committeePks: committeePks, bitmap: bitmap);
+
+/// Verify a same-message aggregate from the committee members selected by a
+/// signer bitmap.
+///
+/// * `all_pks`       – 48-byte compressed G1 public keys, in committee order
+/// * `signer_bitmap` – little-endian bitmap, bit `i` set when member `i`
+///   signed
+/// * `msg`           – the message every signer signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Bits are numbered from the least significant bit of the first byte, the
+/// same indexing as the rest of this module.
+///
+/// Returns `true` when the aggregate verifies against the selected keys,
+/// `false` when no bit is set, a bit is out of range, an input is
+/// malformed, or the aggregate is invalid.
+bool  bls12381MinPkVerifyAggregateBitmap({required List<Uint8List> allPks , required List<int> signerBitmap , required List<int> msg , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiCommitteeBls12381MinPkVerifyAggregateBitmap(allPks: allPks, signerBitmap: signerBitmap, msg: msg, aggSigBytes: aggSigBytes);
+
+/// Check that the members selected by a signer bitmap carry enough weight.
+///
+/// * `signer_bitmap` – signer bitmap, indexed like
+///   [`bls12381_min_pk_verify_aggregate_bitmap`]
+/// * `weights`       – voting weight of each member, in committee order
+/// * `threshold`     – minimum total weight for a quorum
+///
+/// Returns `true` when the set members' weights sum to at least
+/// `threshold`, `false` otherwise or when a set bit is out of range.
+bool  bls12381QuorumMet({required List<int> signerBitmap , required Uint64List weights , required BigInt threshold }) => RustLib.instance.api.crateApiCommitteeBls12381QuorumMet(signerBitmap: signerBitmap, weights: weights, threshold: threshold);
+
+/// Verify a certificate and check it was signed by exactly the expected
+/// validators.
+///
+/// * `committee_pks`  – 48-byte compressed G1 public keys, in committee order
+/// * `expected_order` – committee indices of the validators expected to sign
+/// * `bitmap`         – the certificate's signer bitmap
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// Aggregation is commutative, so `expected_order` is compared as a set:
+/// its order and any repeated entries are ignored.
+///
+/// Returns `false` when the bitmap's signers differ from `expected_order`
+/// in either direction, or the aggregate signature does not verify.
+bool  bls12381VerifySignerOrder({required List<Uint8List> committeePks , required List<int> expectedOrder , required List<int> bitmap , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifySignerOrder(committeePks: committeePks, expectedOrder: expectedOrder, bitmap: bitmap, msg: msg, aggSig: aggSig);
+
+/// Verify a certificate under a selectable fault-tolerance model.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`        – the certificate's signer bitmap
+/// * `msg`           – the message the signers signed
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+/// * `fault_model`   – `0` = crash faults, needs more than 1/2 of the
+///   committee; `1` = Byzantine faults, needs more than 2/3
+///
+/// Returns `false` for an unknown `fault_model`, too few signers for the
+/// selected model, or an aggregate signature that does not verify.
+bool  bls12381VerifyBft({required List<Uint8List> committeePks , required List<int> bitmap , required List<int> msg , required List<int> aggSig , required int faultModel }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyBft(committeePks: committeePks, bitmap: bitmap, msg: msg, aggSig: aggSig, faultModel: faultModel);
+
+/// Verify an aggregate signature and derive a receipt id for it.
+///
+/// * `pks`     – 48-byte compressed G1 public keys of the signers, in order
+/// * `msg`     – the message the signers signed
+/// * `agg_sig` – aggregate G2 signature, 96-byte compressed or 192-byte
+///   uncompressed
+///
+/// The receipt id is the first 16 bytes of
+/// `SHA-256(committee_digest || agg_sig || msg)`, where `committee_digest`
+/// is [`bls12381_committee_digest`] of `pks` and `agg_sig` is re-encoded in
+/// its 96-byte compressed form. The same certificate always yields the same
+/// id, whichever encoding the signature arrives in, so it can serve as a
+/// cache or dedup key.
+///
+/// Returns the 16-byte receipt id, or an empty `Vec<u8>` when the
+/// signature does not verify or an input is malformed.
+Uint8List  bls12381VerifyAggregateReceipt({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyAggregateReceipt(pks: pks, msg: msg, aggSig: aggSig);
+
+/// Encode a signer set as the shortest bitmap that represents it.
+///
+/// * `signer_indices` – committee indices of the signers, in any order
+/// * `committee_size` – number of committee members
+///
+/// Bits follow the module's little-endian layout and trailing zero bytes
+/// are trimmed, so equal signer sets always encode to the same bytes.
+/// Repeated indices are set once.
+///
+/// Returns the bitmap, or an empty `Vec<u8>` when an index is at or beyond
+/// `committee_size`. An empty signer set also encodes as an empty bitmap.
+Uint8List  bls12381EncodeBitmap({required List<int> signerIndices , required int committeeSize }) => RustLib.instance.api.crateApiCommitteeBls12381EncodeBitmap(signerIndices: signerIndices, committeeSize: committeeSize);
+
+/// Verify a certificate from the committee formed by applying an epoch
+/// delta to the previous committee.
+///
+/// * `prev_committee` – the previous epoch's committee
+/// * `added`          – 48-byte compressed G1 keys of new members
+/// * `removed`        – 48-byte compressed G1 keys of departing members
+/// * `bitmap`         – signer bitmap over the current committee
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// The current committee is the previous one, in order, without the
+/// removed members, followed by the added members in the order given.
+/// Added members get a voting weight of 1. The signers must hold more than
+/// 2/3 of the current committee's weight, as in
+/// [`CommitteeHandle::verify_quorum`].
+///
+/// Returns `false` when a removed key is not in the previous committee, an
+/// added key already is, a key is malformed or repeated, the delta leaves
+/// the committee empty, or the certificate does not verify.
+bool  bls12381VerifyWithCommitteeDelta({required CommitteeHandle prevCommittee , required List<Uint8List> added , required List<Uint8List> removed , required List<int> bitmap , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyWithCommitteeDelta(prevCommittee: prevCommittee, added: added, removed: removed, bitmap: bitmap, msg: msg, aggSig: aggSig);
+
+/// Put a committee's public keys in canonical order.
+///
+/// * `pks` – 48-byte compressed or 96-byte uncompressed G1 public keys
+///
+/// Every key is re-encoded in 48-byte compressed form and the list is
+/// sorted ascending by those bytes, so mixed encodings of the same
+/// committee always sort the same way. Duplicates are kept. Keys are
+/// decoded but not subgroup-checked. The result is the order
+/// [`bls12381_committee_contains`] expects.
+///
+/// Returns the sorted compressed keys, or an empty `Vec` when any key is
+/// malformed. A bad key is never silently dropped, as that would shift
+/// every later committee index.
+List<Uint8List>  bls12381MinPkSortPubkeys({required List<Uint8List> pks }) => RustLib.instance.api.crateApiCommitteeBls12381MinPkSortPubkeys(pks: pks);
+
+/// Check committee membership by binary search.
+///
+/// * `sorted_committee_pks` – 48-byte compressed G1 public keys, sorted
+///   ascending by their bytes
+/// * `pk`                   – 48-byte compressed public key to look up
+///
+/// Runs in O(log n) byte comparisons and never decompresses a key. The
+/// result is unspecified if the committee is not sorted.
+///
+/// Returns `false` when `pk` is not 48 bytes or is not in the committee.
+bool  bls12381CommitteeContains({required List<Uint8List> sortedCommitteePks , required List<int> pk }) => RustLib.instance.api.crateApiCommitteeBls12381CommitteeContains(sortedCommitteePks: sortedCommitteePks, pk: pk);
+
+/// Assemble individual committee signatures into a canonical certificate.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `signer_sigs`   – `(committee_index, 96-byte signature)` per signer, in
+///   any order
+///
+/// Aggregation is commutative and the bitmap is minimal, so any ordering of
+/// the same signatures yields byte-identical certificates. The individual
+/// signatures are not verified.
+///
+/// Returns `None` when there are no signers, an index is out of range or
+/// repeated, or a signature is malformed.
+CanonicalCertificate?  bls12381CanonicalAggregate({required List<Uint8List> committeePks , required List<(int,Uint8List)> signerSigs }) => RustLib.instance.api.crateApiCommitteeBls12381CanonicalAggregate(committeePks: committeePks, signerSigs: signerSigs);
+
+/// The message an outgoing committee signs to approve a new committee.
+///
+/// * `proposed_pks`     – 48-byte compressed G1 keys of the new committee
+/// * `proposed_weights` – voting weight per proposed key
+///
+/// The layout is `"BLS_COMMITTEE_CHANGE_V1" || u32_le(n)` followed by
+/// `pk || u64_le(weight)` for each member, sorted by compressed key, so
+/// the input order does not matter.
+///
+/// Returns the message, or an empty `Vec<u8>` for an empty proposal,
+/// mismatched lengths, or a malformed or repeated key.
+Uint8List  bls12381CommitteeChangeMessage({required List<Uint8List> proposedPks , required Uint64List proposedWeights }) => RustLib.instance.api.crateApiCommitteeBls12381CommitteeChangeMessage(proposedPks: proposedPks, proposedWeights: proposedWeights);
+
+/// Verify that the outgoing committee approved a new committee.
+///
+/// * `old_committee`    – the current committee
+/// * `proposed_pks`     – 48-byte compressed G1 keys of the new committee
+/// * `proposed_weights` – voting weight per proposed key
+/// * `bitmap`           – signer bitmap over `old_committee`
+/// * `agg_sig`          – 96-byte compressed aggregate G2 signature over
+///   [`bls12381_committee_change_message`]
+///
+/// The signers must hold more than 2/3 of the old committee's weight, as in
+/// [`CommitteeHandle::verify_quorum`].
+///
+/// Returns `false` when the proposal is invalid, the quorum is not met, or
+/// the aggregate signature does not verify.
+bool  bls12381VerifyCommitteeChange({required CommitteeHandle oldCommittee , required List<Uint8List> proposedPks , required Uint64List proposedWeights , required List<int> bitmap , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyCommitteeChange(oldCommittee: oldCommittee, proposedPks: proposedPks, proposedWeights: proposedWeights, bitmap: bitmap, aggSig: aggSig);
+
+/// Verify a certificate against a committee referenced by its digest.
+///
+/// * `committee_hash` – expected 32-byte [`bls12381_committee_digest`]
+/// * `provided_pks`   – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`         – signer bitmap over `provided_pks`
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// The provided keys are only trusted when their digest matches
+/// `committee_hash`, so a substituted or reordered key is rejected before
+/// any signature check. Quorum policy is up to the caller.
+///
+/// Returns `false` on a digest mismatch, a malformed key, an out-of-range
+/// bit, no signers, or an aggregate signature that does not verify.
+bool  bls12381VerifyByCommitteeHash({required List<int> committeeHash , required List<Uint8List> providedPks , required List<int> bitmap , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyByCommitteeHash(committeeHash: committeeHash, providedPks: providedPks, bitmap: bitmap, msg: msg, aggSig: aggSig);
+
+/// Verify a certificate whose signers are given as a Roaring bitmap.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `roaring_bytes` – signer set in the portable Roaring serialization
+/// * `msg`           – the message the signers signed
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+///
+/// Roaring compresses sparse or clustered signer sets in very large
+/// committees far below the `ceil(n / 8)` bytes of a plain bitmap. Quorum
+/// policy is up to the caller.
+///
+/// Returns `false` when the bitmap does not decode, a signer index is out
+/// of range, there are no signers, or the aggregate does not verify.
+bool  bls12381VerifyRoaring({required List<Uint8List> committeePks , required List<int> roaringBytes , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyRoaring(committeePks: committeePks, roaringBytes: roaringBytes, msg: msg, aggSig: aggSig);
+
+/// Map a certificate's bitmap back to committee indices.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`        – the certificate's signer bitmap
+///
+/// Only the committee size is used; the keys are not parsed.
+///
+/// Returns the signers' committee indices in ascending order, or an empty
+/// `Vec` when a bit is out of range (or no bit is set).
+Uint32List  bls12381ResolveSigners({required List<Uint8List> committeePks , required List<int> bitmap }) => RustLib.instance.api.crateApiCommitteeBls12381ResolveSigners(committeePks: committeePks, bitmap: bitmap);
+
+/// Verify a certificate against a stake threshold over a flat key array.
+///
+/// * `pks_concat`    – committee keys as consecutive 48-byte compressed G1
+///   points, in committee order
+/// * `stakes`        – stake per committee position
+/// * `signer_bitmap` – signer bitmap, in committee order
+/// * `threshold_bps` – required share of total stake in basis points, e.g.
+///   `6667` for two thirds
+/// * `msg`           – the message the signers signed
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+///
+/// The signers' stake must satisfy
+/// `signed * 10_000 >= total * threshold_bps`, computed in `u128`.
+///
+/// Returns `false` when `pks_concat` is not a whole number of keys or does
+/// not match `stakes`, `threshold_bps` exceeds `10_000`, the total stake is
+/// zero, the threshold is not met, or the aggregate does not verify.
+bool  bls12381VerifyStakeQuorumFlat({required List<int> pksConcat , required Uint64List stakes , required List<int> signerBitmap , required int thresholdBps , required List<int> msg , required List<int> aggSig }) => RustLib.instance.api.crateApiCommitteeBls12381VerifyStakeQuorumFlat(pksConcat: pksConcat, stakes: stakes, signerBitmap: signerBitmap, thresholdBps: thresholdBps, msg: msg, aggSig: aggSig);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CommitteeHandle>>
+                abstract class CommitteeHandle implements RustOpaqueInterface {
+                    /// Prepare a committee from its 48-byte compressed G1 public keys, in
+/// committee order. Every member gets a voting weight of 1.
+///
+/// Returns `None` for an empty committee or a malformed key.
+factory CommitteeHandle({required List<Uint8List> pks })=>RustLib.instance.api.crateApiCommitteeCommitteeHandleNew(pks: pks);
+
+
+/// Number of committee members.
+ int  size();
+
+
+/// Sum of all members' voting weights.
+ BigInt  totalWeight();
+
+
+/// Verify a certificate and require its signers to hold more than 2/3 of
+/// the committee's total voting weight.
+///
+/// * `bitmap`  – signer bitmap, in committee order
+/// * `msg`     – the message the signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+///
+/// Returns `false` when the quorum is not met, a bit is out of range, or
+/// the aggregate signature does not verify.
+ bool  verifyQuorum({required List<int> bitmap , required List<int> msg , required List<int> aggSig });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CommitteeKey>>
+                abstract class CommitteeKey implements RustOpaqueInterface {
+                    /// Aggregate a committee's 48-byte compressed G1 public keys once.
+///
+/// Every key is validated here, so [`CommitteeKey::verify`] needs no
+/// per-call key checks.
+///
+/// Returns `None` for an empty committee, a malformed or invalid key, or
+/// keys that sum to the identity.
+factory CommitteeKey({required List<Uint8List> pks })=>RustLib.instance.api.crateApiCommitteeCommitteeKeyNew(pks: pks);
+
+
+/// The 48-byte compressed aggregate public key.
+ Uint8List  publicKey();
+
+
+/// Verify an aggregate signature by the whole committee.
+///
+/// * `msg`           – the message every member signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Returns `true` when the aggregate signature is valid, `false`
+/// otherwise (including a malformed signature).
+ bool  verify({required List<int> msg , required List<int> aggSigBytes });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TimedCommittee>>
+                abstract class TimedCommittee implements RustOpaqueInterface {
+                    /// Prepare a committee from `(pk, valid_from, valid_until)` triples, in
+/// committee order. A key is valid at times `t` with
+/// `valid_from <= t < valid_until`.
+///
+/// Returns `None` for an empty committee, a malformed key, or a term
+/// that ends before it starts.
+factory TimedCommittee({required List<(Uint8List,BigInt,BigInt)> members })=>RustLib.instance.api.crateApiCommitteeTimedCommitteeNew(members: members);
+
+
+/// Verify a certificate whose signers must all hold a valid key at `now`.
+///
+/// * `bitmap`  – signer bitmap, in committee order
+/// * `msg`     – the message the signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+/// * `now`     – the time to check terms against, in the caller's units
+///
+/// Returns `false` when any signer's key is outside its term at `now`,
+/// a bit is out of range, or the aggregate signature does not verify.
+ bool  verify({required List<int> bitmap , required List<int> msg , required List<int> aggSig , required BigInt now });
+
+
+
+                    
+                }
+                
+
+/// A certificate in canonical byte form.
+class CanonicalCertificate  {
+                /// Minimal signer bitmap, see [`bls12381_encode_bitmap`].
+final Uint8List bitmap;
+/// 96-byte compressed aggregate G2 signature.
+final Uint8List aggregate;
+
+                const CanonicalCertificate({required this.bitmap ,required this.aggregate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bitmap.hashCode^aggregate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CanonicalCertificate &&
+                runtimeType == other.runtimeType
+                && bitmap == other.bitmap&& aggregate == other.aggregate;
+        
+            }
+
+/// The one-time setup a verifier does per committee.
+class CommitteeSetup  {
+                /// 48-byte compressed aggregate of all committee keys.
+final Uint8List aggregatePk;
+/// 32-byte committee digest, see [`bls12381_committee_digest`].
+final Uint8List digest;
+
+                const CommitteeSetup({required this.aggregatePk ,required this.digest ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => aggregatePk.hashCode^digest.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CommitteeSetup &&
+                runtimeType == other.runtimeType
+                && aggregatePk == other.aggregatePk&& digest == other.digest;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,204 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `adapt_to_build`, `bench_signature`, `read_cycle_counter`, `record`, `set_last_warning`, `yes_no`
+
+
+            /// Verify a single BLS12-381 min_pk signature and measure the CPU cycles
+/// spent.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Cycles are read with `rdtsc` on x86_64, which counts at a constant
+/// reference rate on modern CPUs, so treat the value as approximate. On
+/// other architectures `supported` is `false` and `cycles` is `0`.
+CycleReport  bls12381MinPkVerifyCycles({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiDiagnosticsBls12381MinPkVerifyCycles(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Verify a single BLS12-381 min_pk signature and report each check
+/// separately.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// `signature_valid` agrees with [`bls12381_min_pk_verify`]; the other
+/// fields say which input was at fault when it is `false`.
+FullVerifyResult  bls12381MinPkVerifyFull({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiDiagnosticsBls12381MinPkVerifyFull(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Verify a signature given as hex and return a human-readable report.
+///
+/// * `sig_hex` – hex of the 96-byte compressed G2 signature
+/// * `pk_hex`  – hex of the 48-byte compressed G1 public key
+/// * `msg_hex` – hex of the message
+///
+/// Each hex string may carry a `0x` prefix. The report has one
+/// `key: value` line each for `sig`, `pk`, `msg`, `dst` and `verdict`, in
+/// that order; the format is stable so tooling can grep it. The verdict
+/// line is `verdict: VALID` or `verdict: INVALID`.
+String  bls12381DebugVerify({required String sigHex , required String pkHex , required String msgHex }) => RustLib.instance.api.crateApiDiagnosticsBls12381DebugVerify(sigHex: sigHex, pkHex: pkHex, msgHex: msgHex);
+
+/// Time the aggregation of `num_sigs` signatures.
+///
+/// * `num_sigs` – how many signatures to aggregate
+///
+/// The timed part matches [`crate::api::bls::bls12381_min_pk_aggregate`]:
+/// decompressing and group-checking every signature, then summing them.
+/// The signatures are generated once and cached, so only the first call for
+/// a given size pays for signing.
+///
+/// Returns the elapsed time in microseconds, `0` for `num_sigs == 0`.
+Future<BigInt>  bls12381BenchAggregate({required int numSigs }) => RustLib.instance.api.crateApiDiagnosticsBls12381BenchAggregate(numSigs: numSigs);
+
+/// Report the build configuration of the native library.
+BuildFeatures  bls12381BuildFeatures() => RustLib.instance.api.crateApiDiagnosticsBls12381BuildFeatures();
+
+/// Verify a single signature, adapting defaults to a slow build.
+///
+/// * `sig` – 96-byte compressed G2 signature
+/// * `pk`  – 48-byte compressed G1 public key
+/// * `msg` – arbitrary-length message
+///
+/// The result is always the same as [`bls12381_min_pk_verify`]. When
+/// [`bls12381_build_features`] reports a portable build, the first call
+/// also halves the default thread count of parallel batch verification and
+/// leaves a warning for [`bls12381_last_warning`].
+bool  bls12381MinPkVerifyAdaptive({required List<int> sig , required List<int> pk , required List<int> msg }) => RustLib.instance.api.crateApiDiagnosticsBls12381MinPkVerifyAdaptive(sig: sig, pk: pk, msg: msg);
+
+/// The most recent warning raised by an adaptive function.
+///
+/// Returns an empty `String` when nothing has been reported.
+String  bls12381LastWarning() => RustLib.instance.api.crateApiDiagnosticsBls12381LastWarning();
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SigningStats>>
+                abstract class SigningStats implements RustOpaqueInterface {
+                    /// Reset every bucket to zero.
+ void  clear();
+
+
+static Future<SigningStats>  default_()=>RustLib.instance.api.crateApiDiagnosticsSigningStatsDefault();
+
+
+/// Bucketed signing latency counts.
+///
+/// Bucket `i` counts signings under `[250, 500, 1000, 2000, 4000, 8000,
+/// 16000][i]` microseconds (and at least the previous bound); the last
+/// of the 8 buckets counts everything from 16 ms up.
+ Uint64List  histogram();
+
+
+/// Create an empty histogram.
+factory SigningStats()=>RustLib.instance.api.crateApiDiagnosticsSigningStatsNew();
+
+
+/// Sign `msg` and record how long it took.
+///
+/// * `sk`  – 32-byte secret key
+/// * `msg` – arbitrary-length message
+///
+/// Returns the 96-byte signature, or an empty `Vec<u8>` for a malformed
+/// key; failed signings are not recorded.
+ Uint8List  signTracked({required List<int> sk , required List<int> msg });
+
+
+
+                    
+                }
+                
+
+/// How the native library was built.
+class BuildFeatures  {
+                /// Whether blst uses its portable code instead of CPU-specific assembly
+/// (ADX on x86_64), which makes every pairing noticeably slower.
+final bool portable;
+/// Target architecture, e.g. `"x86_64"` or `"aarch64"`.
+final String targetArch;
+
+                const BuildFeatures({required this.portable ,required this.targetArch ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => portable.hashCode^targetArch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BuildFeatures &&
+                runtimeType == other.runtimeType
+                && portable == other.portable&& targetArch == other.targetArch;
+        
+            }
+
+/// Verification result plus the CPU cycles it took.
+class CycleReport  {
+                /// Same result as [`bls12381_min_pk_verify`].
+final bool valid;
+/// Approximate cycle count, `0` when `supported` is `false`.
+final BigInt cycles;
+/// Whether this platform has a cycle counter (x86_64 `rdtsc`).
+final bool supported;
+
+                const CycleReport({required this.valid ,required this.cycles ,required this.supported ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => valid.hashCode^cycles.hashCode^supported.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CycleReport &&
+                runtimeType == other.runtimeType
+                && valid == other.valid&& cycles == other.cycles&& supported == other.supported;
+        
+            }
+
+/// Every check behind a verification, computed in one pass.
+class FullVerifyResult  {
+                /// The signature decodes to a point in the G2 subgroup.
+final bool signatureValidPoint;
+/// The public key decodes to a point in the G1 subgroup.
+final bool pubkeyValidPoint;
+/// The public key is not the identity (point at infinity).
+final bool pubkeyNotIdentity;
+/// All of the above hold and the signature verifies over the message.
+final bool signatureValid;
+
+                const FullVerifyResult({required this.signatureValidPoint ,required this.pubkeyValidPoint ,required this.pubkeyNotIdentity ,required this.signatureValid ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => signatureValidPoint.hashCode^pubkeyValidPoint.hashCode^pubkeyNotIdentity.hashCode^signatureValid.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FullVerifyResult &&
+                runtimeType == other.runtimeType
+                && signatureValidPoint == other.signatureValidPoint&& pubkeyValidPoint == other.pubkeyValidPoint&& pubkeyNotIdentity == other.pubkeyNotIdentity&& signatureValid == other.signatureValid;
+        
+            }
+            
\ No newline at end of file
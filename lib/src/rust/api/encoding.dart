@@ -0,0 +1,128 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode_hex`, `pk_from_any`, `pk_from_uncompressed`, `sig_from_any`, `sig_from_uncompressed`
+
+
+            /// Encode bytes as lowercase hex.
+///
+/// * `bytes` – any bytes, e.g. a key or signature
+///
+/// Returns the hex string, without a `0x` prefix.
+String  bls12381BytesToHex({required List<int> bytes }) => RustLib.instance.api.crateApiEncodingBls12381BytesToHex(bytes: bytes);
+
+/// Decode a hex string.
+///
+/// * `hex` – hex digits in either case, optionally prefixed with `0x`
+///
+/// Surrounding whitespace is ignored.
+///
+/// Returns the decoded bytes, or an empty `Vec<u8>` when `hex` has an odd
+/// number of digits or a non-hex character.
+Uint8List  bls12381HexToBytes({required String hex }) => RustLib.instance.api.crateApiEncodingBls12381HexToBytes(hex: hex);
+
+/// Convert a public key to uncompressed form.
+///
+/// * `pk_bytes` – 48-byte compressed G1 public key
+///
+/// Returns the 96-byte uncompressed key, or an empty `Vec<u8>` when
+/// `pk_bytes` is not a valid compressed point.
+Uint8List  bls12381MinPkPubkeyUncompress({required List<int> pkBytes }) => RustLib.instance.api.crateApiEncodingBls12381MinPkPubkeyUncompress(pkBytes: pkBytes);
+
+/// Decompress a public key; the same as [`bls12381_min_pk_pubkey_uncompress`],
+/// under the name some tooling expects.
+///
+/// * `compressed_48` – 48-byte compressed G1 public key
+///
+/// Returns the 96-byte uncompressed key, or an empty `Vec<u8>` when the
+/// input has the wrong length or is not a point on the curve.
+Uint8List  bls12381MinPkPubkeyDecompress({required List<int> compressed48 }) => RustLib.instance.api.crateApiEncodingBls12381MinPkPubkeyDecompress(compressed48: compressed48);
+
+/// Convert an uncompressed public key back to compressed form.
+///
+/// * `pk_bytes` – 96-byte uncompressed G1 public key
+///
+/// Returns the 48-byte compressed key, or an empty `Vec<u8>` when
+/// `pk_bytes` is not a valid uncompressed point.
+Uint8List  bls12381MinPkPubkeyCompress({required List<int> pkBytes }) => RustLib.instance.api.crateApiEncodingBls12381MinPkPubkeyCompress(pkBytes: pkBytes);
+
+/// Convert a signature to uncompressed form.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// Returns the 192-byte uncompressed signature, or an empty `Vec<u8>` when
+/// `sig_bytes` is not a valid compressed point.
+Uint8List  bls12381MinPkSigUncompress({required List<int> sigBytes }) => RustLib.instance.api.crateApiEncodingBls12381MinPkSigUncompress(sigBytes: sigBytes);
+
+/// Convert an uncompressed signature back to compressed form.
+///
+/// * `sig_bytes` – 192-byte uncompressed G2 signature
+///
+/// Returns the 96-byte compressed signature, or an empty `Vec<u8>` when
+/// `sig_bytes` is not a valid uncompressed point.
+Uint8List  bls12381MinPkSigCompress({required List<int> sigBytes }) => RustLib.instance.api.crateApiEncodingBls12381MinPkSigCompress(sigBytes: sigBytes);
+
+/// Verify a single signature given in uncompressed form.
+///
+/// * `sig_bytes` – 192-byte uncompressed G2 signature
+/// * `pk_bytes`  – 96-byte uncompressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Same checks as [`crate::api::bls::bls12381_min_pk_verify`], minus the
+/// decompression.
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including compressed or malformed inputs).
+bool  bls12381MinPkVerifyUncompressed({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiEncodingBls12381MinPkVerifyUncompressed(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Verify a single signature given in either encoding.
+///
+/// * `sig_bytes` – 96-byte compressed or 192-byte uncompressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed or 96-byte uncompressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// The encoding of each input is picked from its length, so data from
+/// sources using different encodings can be mixed freely. Same checks as
+/// [`crate::api::bls::bls12381_min_pk_verify`] otherwise.
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including inputs of any other length and malformed inputs).
+bool  bls12381MinPkVerifyAny({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg }) => RustLib.instance.api.crateApiEncodingBls12381MinPkVerifyAny(sigBytes: sigBytes, pkBytes: pkBytes, msg: msg);
+
+/// Compare two public keys as points, whatever their encodings.
+///
+/// * `a` – 48-byte compressed or 96-byte uncompressed G1 public key
+/// * `b` – 48-byte compressed or 96-byte uncompressed G1 public key
+///
+/// Returns `true` when both decode to the same point, `false` otherwise
+/// (including when either is malformed).
+bool  bls12381MinPkPubkeysEqual({required List<int> a , required List<int> b }) => RustLib.instance.api.crateApiEncodingBls12381MinPkPubkeysEqual(a: a, b: b);
+
+/// Compare two signatures as points, whatever their encodings.
+///
+/// * `a` – 96-byte compressed or 192-byte uncompressed G2 signature
+/// * `b` – 96-byte compressed or 192-byte uncompressed G2 signature
+///
+/// Returns `true` when both decode to the same point, `false` otherwise
+/// (including when either is malformed).
+bool  bls12381MinPkSigsEqual({required List<int> a , required List<int> b }) => RustLib.instance.api.crateApiEncodingBls12381MinPkSigsEqual(a: a, b: b);
+
+/// Verify a same-message aggregate against uncompressed public keys.
+///
+/// * `pks_bytes`     – 96-byte uncompressed G1 public keys
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed or 192-byte uncompressed
+///   aggregate G2 signature
+///
+/// Returns `true` when the aggregate signature is valid, `false` otherwise
+/// (including no keys or a malformed input).
+bool  bls12381MinPkVerifyAggregateUncompressed({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes }) => RustLib.instance.api.crateApiEncodingBls12381MinPkVerifyAggregateUncompressed(pksBytes: pksBytes, msg: msg, aggSigBytes: aggSigBytes);
+
+            
+            
\ No newline at end of file
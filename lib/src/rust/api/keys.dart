@@ -0,0 +1,245 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `as_slice`, `export_identity`, `identity_cipher`, `new`, `pk_checksum`, `scalar_in_range_ct`, `secret_key`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SecretBytes`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`, `zeroize`
+
+
+            /// Generate a key pair deterministically from a seed.
+///
+/// * `seed` – input keying material, at least 32 bytes
+///
+/// Uses the IETF `KeyGen` procedure with an empty `key_info`, so the same
+/// seed always yields the same key pair. The seed buffer is wiped.
+///
+/// Returns `(secret_key_32, public_key_48)`, or two empty vectors when the
+/// seed is shorter than 32 bytes.
+(Uint8List,Uint8List)  bls12381MinPkKeygenFromSeed({required List<int> seed }) => RustLib.instance.api.crateApiKeysBls12381MinPkKeygenFromSeed(seed: seed);
+
+/// Generate one key pair per seed, like [`bls12381_min_pk_keygen_from_seed`]
+/// in a single call.
+///
+/// * `seeds` – input keying material for each key pair, at least 32 bytes
+///
+/// A short seed doesn't abort the batch; it just leaves empty entries at
+/// its index. Every seed buffer is wiped.
+///
+/// Returns `(secret_keys_32, public_keys_48)`, index-aligned with `seeds`.
+(List<Uint8List>,List<Uint8List>)  bls12381MinPkBatchKeygen({required List<Uint8List> seeds }) => RustLib.instance.api.crateApiKeysBls12381MinPkBatchKeygen(seeds: seeds);
+
+/// Generate a fresh random key pair.
+///
+/// The 32 bytes of input keying material come from the operating system's
+/// entropy source (via `getrandom`, which also covers Windows and the
+/// browser's `crypto.getRandomValues` on web), so nothing depends on the
+/// quality of a Dart RNG. They go through the same `KeyGen` as
+/// [`bls12381_min_pk_keygen_from_seed`] and are wiped afterwards.
+///
+/// Returns `(secret_key_32, public_key_48)`, or two empty vectors when the
+/// entropy source can't be read.
+(Uint8List,Uint8List)  bls12381MinPkGenerateKeypair() => RustLib.instance.api.crateApiKeysBls12381MinPkGenerateKeypair();
+
+/// Derive the public key belonging to a secret key.
+///
+/// * `sk_bytes` – 32-byte secret key
+///
+/// The input buffer is wiped.
+///
+/// Returns the 48-byte compressed G1 public key, or an empty `Vec<u8>`
+/// when `sk_bytes` is malformed.
+Uint8List  bls12381MinPkSkToPk({required List<int> skBytes }) => RustLib.instance.api.crateApiKeysBls12381MinPkSkToPk(skBytes: skBytes);
+
+/// Import a secret key from an untrusted source without leaking, through
+/// timing, why it was rejected.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+///
+/// The range check against the group order runs in constant time, and the
+/// key is only parsed once it passed. The input buffer is wiped.
+///
+/// Returns the canonical 32-byte secret key, or an empty `Vec<u8>` when
+/// `sk_bytes` is not 32 bytes or not a nonzero scalar below the group
+/// order.
+Uint8List  bls12381MinPkImportSkCt({required List<int> skBytes }) => RustLib.instance.api.crateApiKeysBls12381MinPkImportSkCt(skBytes: skBytes);
+
+/// Check whether raw bytes form a valid secret key.
+///
+/// * `sk_bytes` – candidate 32-byte big-endian secret key
+///
+/// A valid key is a nonzero scalar below the group order. The input buffer
+/// is wiped.
+///
+/// Returns `true` when `sk_bytes` can be used to sign, `false` otherwise.
+bool  bls12381MinPkValidateSecretKey({required List<int> skBytes }) => RustLib.instance.api.crateApiKeysBls12381MinPkValidateSecretKey(skBytes: skBytes);
+
+/// Turn a BIP39 mnemonic phrase into its 64-byte wallet seed.
+///
+/// * `mnemonic`   – 12 to 24 lowercase words from the BIP39 English list
+/// * `passphrase` – optional extra passphrase, empty for none
+///
+/// The word list and checksum are validated before the seed is derived
+/// with PBKDF2-HMAC-SHA-512. Passing the seed to
+/// [`bls12381_derive_master_sk`] recovers the key tree backed up by the
+/// phrase. A non-ASCII passphrase must already be NFKD-normalized. Both
+/// strings are wiped.
+///
+/// Returns the 64-byte seed, or an empty `Vec<u8>` when the phrase has an
+/// unknown word, an unsupported length or a bad checksum.
+Uint8List  bls12381MnemonicToSeed({required String mnemonic , required String passphrase }) => RustLib.instance.api.crateApiKeysBls12381MnemonicToSeed(mnemonic: mnemonic, passphrase: passphrase);
+
+/// Derive the EIP-2333 master secret key from a seed.
+///
+/// * `seed` – input keying material, at least 32 bytes
+///
+/// The root of an EIP-2333 key tree, compatible with Ethereum consensus
+/// wallets; children come from [`bls12381_derive_child_sk`]. The seed
+/// buffer is wiped.
+///
+/// Returns the 32-byte master secret key, or an empty `Vec<u8>` when the
+/// seed is shorter than 32 bytes.
+Uint8List  bls12381DeriveMasterSk({required List<int> seed }) => RustLib.instance.api.crateApiKeysBls12381DeriveMasterSk(seed: seed);
+
+/// Derive an EIP-2333 child secret key.
+///
+/// * `parent_sk` – 32-byte parent secret key
+/// * `index`     – child index
+///
+/// Applying this along a path such as `m/12381/3600/0/0` from
+/// [`bls12381_derive_master_sk`] gives the same keys as other EIP-2333
+/// implementations. The parent buffer is wiped.
+///
+/// Returns the 32-byte child secret key, or an empty `Vec<u8>` when
+/// `parent_sk` is malformed.
+Uint8List  bls12381DeriveChildSk({required List<int> parentSk , required int index }) => RustLib.instance.api.crateApiKeysBls12381DeriveChildSk(parentSk: parentSk, index: index);
+
+/// Derive the secret key for one storage shard from a node's master key.
+///
+/// * `master_sk`   – 32-byte master secret key
+/// * `shard_index` – index of the shard the key is bound to
+///
+/// Uses EIP-2333 child derivation with `shard_index` as the child index, so
+/// the same master key and index always yield the same shard key, and shard
+/// keys can't be used to recover the master key.
+///
+/// Returns the 32-byte shard secret key, or an empty `Vec<u8>` when
+/// `master_sk` is malformed.
+Uint8List  bls12381DeriveShardKey({required List<int> masterSk , required int shardIndex }) => RustLib.instance.api.crateApiKeysBls12381DeriveShardKey(masterSk: masterSk, shardIndex: shardIndex);
+
+/// Derive a session key pair from a master secret key.
+///
+/// * `master_sk`  – 32-byte master secret key
+/// * `session_id` – identifier of the session the key is bound to
+///
+/// The session secret key is the IETF `KeyGen` (HKDF-SHA-256) output with
+/// the master key as input keying material and `session_id` as `key_info`,
+/// so each session id yields an unrelated key.
+///
+/// Returns `None` when `master_sk` is malformed.
+SessionKeyPair?  bls12381SessionKeypair({required List<int> masterSk , required List<int> sessionId }) => RustLib.instance.api.crateApiKeysBls12381SessionKeypair(masterSk: masterSk, sessionId: sessionId);
+
+/// Export a signing identity as a password-encrypted blob.
+///
+/// * `sk_bytes` – 32-byte secret key
+/// * `password` – password protecting the export
+///
+/// Layout (version 2):
+///
+/// ```text
+/// version: u8 = 2 || iterations: u32_le || salt: 16 bytes || nonce: 12 bytes
+///   || ciphertext: 36 bytes || tag: 16 bytes
+/// ```
+///
+/// The password is stretched with PBKDF2-HMAC-SHA-256 over a random salt
+/// into a ChaCha20-Poly1305 key, which seals `sk || SHA-256(pk)[..4]` under
+/// a random nonce with the header as associated data. Salt and nonce come
+/// from the OS RNG, so every export of the same key is different and no
+/// password guesses can be precomputed for it.
+///
+/// Returns the 85-byte blob, or an empty `Vec<u8>` when `sk_bytes` is
+/// malformed or the OS entropy source fails.
+Uint8List  bls12381ExportIdentity({required List<int> skBytes , required String password }) => RustLib.instance.api.crateApiKeysBls12381ExportIdentity(skBytes: skBytes, password: password);
+
+/// Import a signing identity from [`bls12381_export_identity`].
+///
+/// * `blob`     – the exported bytes
+/// * `password` – the password used for the export
+///
+/// Returns `None` when the blob is malformed or has an unknown version, the
+/// password is wrong, the blob was tampered with, or the decrypted key
+/// does not match its public key checksum.
+IdentityImport?  bls12381ImportIdentity({required List<int> blob , required String password }) => RustLib.instance.api.crateApiKeysBls12381ImportIdentity(blob: blob, password: password);
+
+/// Wrap a secret key in a PKCS#8-style DER structure.
+///
+/// * `sk_bytes` – 32-byte secret key
+///
+/// The layout is fixed (see [`bls12381_min_pk_import_sk_der`]), so the
+/// result is always 57 bytes. The input buffer is wiped.
+///
+/// Returns the DER-encoded key, or an empty `Vec<u8>` when `sk_bytes` is
+/// malformed.
+Uint8List  bls12381MinPkExportSkDer({required List<int> skBytes }) => RustLib.instance.api.crateApiKeysBls12381MinPkExportSkDer(skBytes: skBytes);
+
+/// Unwrap a secret key from [`bls12381_min_pk_export_sk_der`].
+///
+/// * `der` – DER-encoded key
+///
+/// Only the exact structure written on export is accepted: a different
+/// algorithm OID, optional PKCS#8 attributes or a public key field are all
+/// rejected. The input buffer is wiped.
+///
+/// Returns the 32-byte secret key, or an empty `Vec<u8>` when `der` is
+/// malformed or holds an invalid scalar.
+Uint8List  bls12381MinPkImportSkDer({required List<int> der }) => RustLib.instance.api.crateApiKeysBls12381MinPkImportSkDer(der: der);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SessionKeyPair>>
+                abstract class SessionKeyPair implements RustOpaqueInterface {
+                    /// The 48-byte compressed G1 session public key.
+ Uint8List  publicKey();
+
+
+/// Sign `msg` with the session key, returning a 96-byte compressed G2
+/// signature that verifies under [`SessionKeyPair::public_key`].
+ Uint8List  sign({required List<int> msg });
+
+
+
+                    
+                }
+                
+
+/// A signing identity recovered from an export.
+class IdentityImport  {
+                /// 32-byte secret key.
+final Uint8List secretKey;
+/// 48-byte compressed G1 public key, checked against the export.
+final Uint8List publicKey;
+
+                const IdentityImport({required this.secretKey ,required this.publicKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => secretKey.hashCode^publicKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IdentityImport &&
+                runtimeType == other.runtimeType
+                && secretKey == other.secretKey&& publicKey == other.publicKey;
+        
+            }
+            
\ No newline at end of file
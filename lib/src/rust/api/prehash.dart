@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `digest`
+
+
+            /// Sign the BLAKE2b-256 digest of `content`.
+///
+/// * `sk_bytes` – 32-byte secret key
+/// * `content`  – arbitrary-length content to hash and sign
+///
+/// Returns the 96-byte signature over the 32-byte digest, or an empty
+/// `Vec<u8>` for a malformed secret key.
+Uint8List  bls12381MinPkSignBlake2B({required List<int> skBytes , required List<int> content }) => RustLib.instance.api.crateApiPrehashBls12381MinPkSignBlake2B(skBytes: skBytes, content: content);
+
+/// Verify a signature over the BLAKE2b-256 digest of `content`.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `content`   – arbitrary-length content whose digest was signed
+///
+/// Returns `true` when the signature over the digest is valid, `false`
+/// otherwise (including malformed inputs).
+bool  bls12381MinPkVerifyBlake2B({required List<int> sigBytes , required List<int> pkBytes , required List<int> content }) => RustLib.instance.api.crateApiPrehashBls12381MinPkVerifyBlake2B(sigBytes: sigBytes, pkBytes: pkBytes, content: content);
+
+/// Sign a 32-byte digest the application already computed.
+///
+/// * `sk_bytes` – 32-byte secret key
+/// * `digest32` – 32-byte digest, signed as the message under the standard
+///   DST
+///
+/// Interoperates with signers that hash content themselves and sign the
+/// digest, with hash-to-curve applied to the digest bytes.
+///
+/// Returns the 96-byte signature, or an empty `Vec<u8>` when `digest32` is
+/// not 32 bytes or the secret key is malformed.
+Uint8List  bls12381MinPkSignPrehashed({required List<int> skBytes , required List<int> digest32 }) => RustLib.instance.api.crateApiPrehashBls12381MinPkSignPrehashed(skBytes: skBytes, digest32: digest32);
+
+/// Verify a signature over a 32-byte digest from
+/// [`bls12381_min_pk_sign_prehashed`].
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `digest32`  – 32-byte digest that was signed
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including a digest that isn't 32 bytes and malformed inputs).
+bool  bls12381MinPkVerifyPrehashed({required List<int> sigBytes , required List<int> pkBytes , required List<int> digest32 }) => RustLib.instance.api.crateApiPrehashBls12381MinPkVerifyPrehashed(sigBytes: sigBytes, pkBytes: pkBytes, digest32: digest32);
+
+/// Verify a signature over the digest of `content`, with the hash chosen at
+/// runtime.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `content`   – arbitrary-length content whose digest was signed
+/// * `hash_id`   – hash applied to `content` before verifying:
+///   - `0` – SHA-256 (32-byte digest)
+///   - `1` – SHA-512 (64-byte digest)
+///   - `2` – BLAKE2b-256 (32-byte digest), as Sui and Walrus use
+///   - `3` – Keccak-256 (32-byte digest), as Ethereum uses
+///
+/// The signature must be over the full digest bytes under the standard DST.
+///
+/// Returns `false` for an unknown `hash_id`, malformed inputs, or an
+/// invalid signature.
+bool  bls12381MinPkVerifyWithHash({required List<int> sigBytes , required List<int> pkBytes , required List<int> content , required int hashId }) => RustLib.instance.api.crateApiPrehashBls12381MinPkVerifyWithHash(sigBytes: sigBytes, pkBytes: pkBytes, content: content, hashId: hashId);
+
+            
+            
\ No newline at end of file
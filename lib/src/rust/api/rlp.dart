@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `encode_bytes`, `header`, `is_canonical_item`, `item_len`, `push_prefix`
+
+
+            /// RLP-encode a list of byte-string fields, ready for signing.
+///
+/// * `fields` – the list's fields, each encoded as an RLP byte string
+///
+/// Integers are expected already in RLP form: big-endian with no leading
+/// zero bytes, and zero as the empty string.
+///
+/// Returns the encoded list.
+Uint8List  bls12381RlpEncodeList({required List<Uint8List> fields }) => RustLib.instance.api.crateApiRlpBls12381RlpEncodeList(fields: fields);
+
+/// Verify a BLS12-381 min_pk signature over an RLP-encoded message.
+///
+/// * `sig`       – 96-byte compressed G2 signature
+/// * `pk`        – 48-byte compressed G1 public key
+/// * `rlp_bytes` – the signed message, one RLP item
+///
+/// The signature is checked over `rlp_bytes` as given.
+///
+/// Returns `false` when `rlp_bytes` is not exactly one canonical RLP item,
+/// or the signature does not verify.
+bool  bls12381MinPkVerifyRlp({required List<int> sig , required List<int> pk , required List<int> rlpBytes }) => RustLib.instance.api.crateApiRlpBls12381MinPkVerifyRlp(sig: sig, pk: pk, rlpBytes: rlpBytes);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'committee.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `bcs_byte_vec`, `byte_vec`, `bytes`, `intent_message`, `is_empty`, `new`, `object_ref_message`, `u64`, `uleb128`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BcsReader`
+
+
+            /// Build the intent message Sui validators sign for a payload.
+///
+/// * `intent_scope` – Sui `IntentScope`, e.g. `3` for a personal message
+/// * `version`      – Sui `IntentVersion`, `0` today
+/// * `app_id`       – Sui `AppId`, e.g. `0` for Sui itself
+/// * `payload`      – the BCS-encoded value being signed
+///
+/// This is the BCS encoding of Sui's `IntentMessage`: the 3 intent bytes
+/// followed by `payload` as is. Sui's BLS12-381 authority signatures are
+/// over these bytes directly, so nothing is hashed here; the result goes
+/// straight to the verify functions. `payload` must already be BCS, e.g.
+/// with a length prefix for a byte vector.
+///
+/// Returns the `3 + payload.len()`-byte intent message.
+Uint8List  suiIntentMessage({required int intentScope , required int version , required int appId , required List<int> payload }) => RustLib.instance.api.crateApiSuiSuiIntentMessage(intentScope: intentScope, version: version, appId: appId, payload: payload);
+
+/// Verify an aggregate signature over a Sui object reference.
+///
+/// * `pks`       – list of 48-byte compressed G1 public keys of the signers
+/// * `object_id` – 32-byte object ID
+/// * `version`   – object version (sequence number)
+/// * `digest`    – 32-byte object digest
+/// * `agg_sig`   – 96-byte compressed aggregate G2 signature
+///
+/// The reference is BCS-encoded as Sui's `ObjectRef` tuple
+/// `(ObjectID, SequenceNumber, ObjectDigest)`, where the digest is a byte
+/// vector with its own length prefix. Sui has no intent for signing an
+/// object reference, so this crate's own choice is to sign those bytes the
+/// way Sui frames a personal message, without the Blake2b pre-hash:
+///
+/// ```text
+/// [3, 0, 0] || uleb128(73) || object_id || u64_le(version) || uleb128(32) || digest
+/// ```
+///
+/// Returns `false` when `object_id` or `digest` is not 32 bytes, or the
+/// aggregate signature does not verify.
+bool  bls12381VerifyObjectRef({required List<Uint8List> pks , required List<int> objectId , required BigInt version , required List<int> digest , required List<int> aggSig }) => RustLib.instance.api.crateApiSuiBls12381VerifyObjectRef(pks: pks, objectId: objectId, version: version, digest: digest, aggSig: aggSig);
+
+/// Decode an on-chain committee from its BCS encoding into a weighted
+/// [`CommitteeHandle`].
+///
+/// * `bcs_bytes` – BCS-encoded committee
+///
+/// The expected layout is the Move struct
+///
+/// ```text
+/// struct Committee {
+///     epoch: u64,
+///     members: vector<Member>,
+/// }
+/// struct Member {
+///     public_key: vector<u8>,  // 48-byte compressed G1 key
+///     voting_power: u64,
+/// }
+/// ```
+///
+/// i.e. `u64_le(epoch) || uleb128(n) || n × (uleb128(48) || pk || u64_le(power))`.
+/// The epoch is read but not kept on the handle.
+///
+/// Returns `None` on truncated or trailing bytes, a key that is not 48
+/// bytes or does not parse, an empty committee, or a total voting power
+/// that overflows `u64`.
+CommitteeHandle?  bls12381CommitteeFromSuiBcs({required List<int> bcsBytes }) => RustLib.instance.api.crateApiSuiBls12381CommitteeFromSuiBcs(bcsBytes: bcsBytes);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `fr_from_u64`, `fr_inverse`, `fr_mul`, `fr_sub`, `lagrange_at_zero`, `p2_mult`, `scalar_from_fr`
+
+
+            /// Recombine partial signatures into the group signature.
+///
+/// * `indices`      – nonzero share index of each signer, i.e. the point
+///   its key share was evaluated at
+/// * `partial_sigs` – 96-byte compressed G2 signatures by those shares over
+///   the same message, in the same order
+///
+/// With at least the threshold number of shares, the result verifies under
+/// the group public key like an ordinary signature. With fewer, it is just
+/// some other signature, so verify the result rather than trusting it.
+///
+/// Returns the 96-byte reconstructed signature, or an empty `Vec<u8>` when
+/// the lists differ in length or are empty, an index is zero or repeated,
+/// or a partial signature is malformed.
+Uint8List  bls12381MinPkThresholdCombine({required List<int> indices , required List<Uint8List> partialSigs }) => RustLib.instance.api.crateApiThresholdBls12381MinPkThresholdCombine(indices: indices, partialSigs: partialSigs);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VerifierKey>>
+                abstract class VerifierKey implements RustOpaqueInterface {
+                    /// Decode and validate a 48-byte compressed G1 public key once.
+///
+/// Returns `None` when the key is malformed, outside G1 or the identity.
+factory VerifierKey({required List<int> pkBytes })=>RustLib.instance.api.crateApiVerifierVerifierKeyNew(pkBytes: pkBytes);
+
+
+/// The 48-byte compressed public key.
+ Uint8List  publicKey();
+
+
+/// Verify a signature by this key.
+///
+/// * `msg`       – arbitrary-length message
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including a malformed signature).
+ bool  verify({required List<int> msg , required List<int> sigBytes });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -3,412 +3,5977 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/aggregator.dart';
+import 'api/batch.dart';
 import 'api/bls.dart';
+import 'api/bundle.dart';
+import 'api/cache.dart';
+import 'api/commitment.dart';
+import 'api/committee.dart';
+import 'api/diagnostics.dart';
+import 'api/encoding.dart';
+import 'api/keys.dart';
+import 'api/min_sig.dart';
+import 'api/prehash.dart';
+import 'api/rlp.dart';
 import 'api/simple.dart';
+import 'api/sui.dart';
+import 'api/threshold.dart';
+import 'api/verifier.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 601136085;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_bls_dart',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Uint8List crateApiBlsBls12381MinPkAggregate({
-    required List<Uint8List> sigsBytes,
-  });
-
-  bool crateApiBlsBls12381MinPkVerify({
-    required List<int> sigBytes,
-    required List<int> pkBytes,
-    required List<int> msg,
-  });
-
-  bool crateApiBlsBls12381MinPkVerifyAggregate({
-    required List<Uint8List> pksBytes,
-    required List<int> msg,
-    required List<int> aggSigBytes,
-  });
-
-  String crateApiSimpleGreet({required String name});
-
-  Future<void> crateApiSimpleInitApp();
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Uint8List crateApiBlsBls12381MinPkAggregate({
-    required List<Uint8List> sigsBytes,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_list_prim_u_8_strict(sigsBytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBlsBls12381MinPkAggregateConstMeta,
-        argValues: [sigsBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateConstMeta =>
-      const TaskConstMeta(
-        debugName: "bls12381_min_pk_aggregate",
-        argNames: ["sigsBytes"],
-      );
-
-  @override
-  bool crateApiBlsBls12381MinPkVerify({
-    required List<int> sigBytes,
-    required List<int> pkBytes,
-    required List<int> msg,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(sigBytes, serializer);
-          sse_encode_list_prim_u_8_loose(pkBytes, serializer);
-          sse_encode_list_prim_u_8_loose(msg, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBlsBls12381MinPkVerifyConstMeta,
-        argValues: [sigBytes, pkBytes, msg],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyConstMeta =>
-      const TaskConstMeta(
-        debugName: "bls12381_min_pk_verify",
-        argNames: ["sigBytes", "pkBytes", "msg"],
-      );
-
-  @override
-  bool crateApiBlsBls12381MinPkVerifyAggregate({
-    required List<Uint8List> pksBytes,
-    required List<int> msg,
-    required List<int> aggSigBytes,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
-          sse_encode_list_prim_u_8_loose(msg, serializer);
-          sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiBlsBls12381MinPkVerifyAggregateConstMeta,
-        argValues: [pksBytes, msg, aggSigBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyAggregateConstMeta =>
-      const TaskConstMeta(
-        debugName: "bls12381_min_pk_verify_aggregate",
-        argNames: ["pksBytes", "msg", "aggSigBytes"],
-      );
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -1903567149;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_bls_dart',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  void crateApiBatchCancelTokenCancel({required CancelToken that });
+
+Future<CancelToken> crateApiBatchCancelTokenDefault();
+
+bool crateApiBatchCancelTokenIsCancelled({required CancelToken that });
+
+CancelToken crateApiBatchCancelTokenNew();
+
+CommitteeHandle? crateApiCommitteeCommitteeHandleNew({required List<Uint8List> pks });
+
+int crateApiCommitteeCommitteeHandleSize({required CommitteeHandle that });
+
+BigInt crateApiCommitteeCommitteeHandleTotalWeight({required CommitteeHandle that });
+
+bool crateApiCommitteeCommitteeHandleVerifyQuorum({required CommitteeHandle that , required List<int> bitmap , required List<int> msg , required List<int> aggSig });
+
+CommitteeKey? crateApiCommitteeCommitteeKeyNew({required List<Uint8List> pks });
+
+Uint8List crateApiCommitteeCommitteeKeyPublicKey({required CommitteeKey that });
+
+bool crateApiCommitteeCommitteeKeyVerify({required CommitteeKey that , required List<int> msg , required List<int> aggSigBytes });
+
+Uint8List crateApiKeysSessionKeyPairPublicKey({required SessionKeyPair that });
+
+Uint8List crateApiKeysSessionKeyPairSign({required SessionKeyPair that , required List<int> msg });
+
+bool crateApiAggregatorSignatureAggregatorAdd({required SignatureAggregator that , required List<int> sigBytes });
+
+int crateApiAggregatorSignatureAggregatorCount({required SignatureAggregator that });
+
+Future<SignatureAggregator> crateApiAggregatorSignatureAggregatorDefault();
+
+Uint8List crateApiAggregatorSignatureAggregatorFinalize({required SignatureAggregator that });
+
+SignatureAggregator? crateApiAggregatorSignatureAggregatorFromSnapshot({required List<int> state });
+
+SignatureAggregator crateApiAggregatorSignatureAggregatorNew();
+
+Uint8List crateApiAggregatorSignatureAggregatorSnapshot({required SignatureAggregator that });
+
+void crateApiDiagnosticsSigningStatsClear({required SigningStats that });
+
+Future<SigningStats> crateApiDiagnosticsSigningStatsDefault();
+
+Uint64List crateApiDiagnosticsSigningStatsHistogram({required SigningStats that });
+
+SigningStats crateApiDiagnosticsSigningStatsNew();
+
+Uint8List crateApiDiagnosticsSigningStatsSignTracked({required SigningStats that , required List<int> sk , required List<int> msg });
+
+TimedCommittee? crateApiCommitteeTimedCommitteeNew({required List<(Uint8List,BigInt,BigInt)> members });
+
+bool crateApiCommitteeTimedCommitteeVerify({required TimedCommittee that , required List<int> bitmap , required List<int> msg , required List<int> aggSig , required BigInt now });
+
+VerifierKey? crateApiVerifierVerifierKeyNew({required List<int> pkBytes });
+
+Uint8List crateApiVerifierVerifierKeyPublicKey({required VerifierKey that });
+
+bool crateApiVerifierVerifierKeyVerify({required VerifierKey that , required List<int> msg , required List<int> sigBytes });
+
+Future<List<bool>> crateApiBatchBls12381BatchVerifyCancellable({required List<Uint8List> sigs , required List<Uint8List> pks , required List<Uint8List> msgs , required CancelToken cancel });
+
+Future<BigInt> crateApiDiagnosticsBls12381BenchAggregate({required int numSigs });
+
+BuildFeatures crateApiDiagnosticsBls12381BuildFeatures();
+
+Uint8List crateApiBundleBls12381BundleEncode({required List<Uint8List> committeePks , required BigInt epoch , required List<int> bitmap , required List<int> msg , required List<int> aggSig });
+
+BundleInfo? crateApiBundleBls12381BundleInspect({required List<int> bundle });
+
+bool crateApiBundleBls12381BundleVerify({required List<int> bundle });
+
+String crateApiEncodingBls12381BytesToHex({required List<int> bytes });
+
+CanonicalCertificate? crateApiCommitteeBls12381CanonicalAggregate({required List<Uint8List> committeePks , required List<(int,Uint8List)> signerSigs });
+
+Uint8List crateApiBlsBls12381ChainDst({required BigInt chainId });
+
+Uint8List crateApiCommitteeBls12381CommitteeChangeMessage({required List<Uint8List> proposedPks , required Uint64List proposedWeights });
+
+bool crateApiCommitteeBls12381CommitteeContains({required List<Uint8List> sortedCommitteePks , required List<int> pk });
+
+Uint8List crateApiCommitteeBls12381CommitteeDigest({required List<Uint8List> pks });
+
+CommitteeHandle? crateApiSuiBls12381CommitteeFromSuiBcs({required List<int> bcsBytes });
+
+CommitteeSetup? crateApiCommitteeBls12381CommitteeSetup({required List<Uint8List> pks });
+
+String crateApiDiagnosticsBls12381DebugVerify({required String sigHex , required String pkHex , required String msgHex });
+
+Uint8List crateApiKeysBls12381DeriveChildSk({required List<int> parentSk , required int index });
+
+Uint8List crateApiKeysBls12381DeriveMasterSk({required List<int> seed });
+
+Uint8List crateApiKeysBls12381DeriveShardKey({required List<int> masterSk , required int shardIndex });
+
+Uint8List crateApiBlsBls12381DstForScheme({required String scheme });
+
+Uint8List crateApiCommitteeBls12381EncodeBitmap({required List<int> signerIndices , required int committeeSize });
+
+Uint8List crateApiKeysBls12381ExportIdentity({required List<int> skBytes , required String password });
+
+Uint8List crateApiEncodingBls12381HexToBytes({required String hex });
+
+IdentityImport? crateApiKeysBls12381ImportIdentity({required List<int> blob , required String password });
+
+String crateApiCacheBls12381LastPersistError();
+
+String crateApiDiagnosticsBls12381LastWarning();
+
+(Uint8List,bool) crateApiAggregatorBls12381MinPkAddAndVerify({required List<int> aggSigBytes , required List<Uint8List> existingPks , required List<int> newSigBytes , required List<int> newPkBytes , required List<int> msg });
+
+Uint8List crateApiBlsBls12381MinPkAggregate({required List<Uint8List> sigsBytes });
+
+Uint8List crateApiBlsBls12381MinPkAggregateAggregates({required List<Uint8List> aggSigs });
+
+Uint8List crateApiBlsBls12381MinPkAggregateAug({required List<Uint8List> sigs });
+
+Uint8List crateApiBlsBls12381MinPkAggregateChecked({required List<Uint8List> sigsBytes });
+
+Uint8List crateApiBlsBls12381MinPkAggregatePubkeys({required List<Uint8List> pksBytes });
+
+Uint8List crateApiBlsBls12381MinPkAggregateVerified({required List<Uint8List> sigs , required List<Uint8List> pks , required List<int> msg });
+
+bool crateApiBlsBls12381MinPkAggregateVerify({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<int> aggSigBytes });
+
+void crateApiBlsBls12381MinPkAggregateVerifyChecked({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<int> aggSigBytes });
+
+(List<Uint8List>,List<Uint8List>) crateApiKeysBls12381MinPkBatchKeygen({required List<Uint8List> seeds });
+
+bool crateApiBatchBls12381MinPkBatchVerify({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<Uint8List> sigsBytes });
+
+Future<List<bool>> crateApiBatchBls12381MinPkBatchVerifyParallel({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<Uint8List> sigsBytes });
+
+Uint8List crateApiBlsBls12381MinPkDeaggregate({required List<int> aggSigBytes , required List<int> sigToRemove });
+
+Uint8List crateApiKeysBls12381MinPkExportSkDer({required List<int> skBytes });
+
+(Uint8List,Uint8List) crateApiKeysBls12381MinPkGenerateKeypair();
+
+Uint8List crateApiKeysBls12381MinPkImportSkCt({required List<int> skBytes });
+
+Uint8List crateApiKeysBls12381MinPkImportSkDer({required List<int> der });
+
+bool crateApiBlsBls12381MinPkIsIdentitySignature({required List<int> sigBytes });
+
+(Uint8List,Uint8List) crateApiKeysBls12381MinPkKeygenFromSeed({required List<int> seed });
+
+Uint8List crateApiBlsBls12381MinPkPopProve({required List<int> skBytes });
+
+bool crateApiBlsBls12381MinPkPopVerify({required List<int> pkBytes , required List<int> popBytes });
+
+Uint8List crateApiEncodingBls12381MinPkPubkeyCompress({required List<int> pkBytes });
+
+Uint8List crateApiEncodingBls12381MinPkPubkeyDecompress({required List<int> compressed48 });
+
+Uint8List crateApiEncodingBls12381MinPkPubkeyUncompress({required List<int> pkBytes });
+
+bool crateApiEncodingBls12381MinPkPubkeysEqual({required List<int> a , required List<int> b });
+
+Uint8List crateApiEncodingBls12381MinPkSigCompress({required List<int> sigBytes });
+
+Uint8List crateApiEncodingBls12381MinPkSigUncompress({required List<int> sigBytes });
+
+Uint8List crateApiBlsBls12381MinPkSign({required List<int> skBytes , required List<int> msg });
+
+Uint8List crateApiBlsBls12381MinPkSignAug({required List<int> skBytes , required List<int> msg , required List<int> aug });
+
+Uint8List crateApiPrehashBls12381MinPkSignBlake2B({required List<int> skBytes , required List<int> content });
+
+Uint8List crateApiPrehashBls12381MinPkSignPrehashed({required List<int> skBytes , required List<int> digest32 });
+
+Uint8List crateApiBlsBls12381MinPkSignWithDst({required List<int> skBytes , required List<int> msg , required List<int> dst });
+
+bool crateApiEncodingBls12381MinPkSigsEqual({required List<int> a , required List<int> b });
+
+Uint8List crateApiKeysBls12381MinPkSkToPk({required List<int> skBytes });
+
+List<Uint8List> crateApiCommitteeBls12381MinPkSortPubkeys({required List<Uint8List> pks });
+
+Uint8List crateApiThresholdBls12381MinPkThresholdCombine({required List<int> indices , required List<Uint8List> partialSigs });
+
+bool crateApiBlsBls12381MinPkValidatePubkey({required List<int> pkBytes });
+
+bool crateApiKeysBls12381MinPkValidateSecretKey({required List<int> skBytes });
+
+bool crateApiBlsBls12381MinPkValidateSignature({required List<int> sigBytes });
+
+bool crateApiBlsBls12381MinPkVerify({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiDiagnosticsBls12381MinPkVerifyAdaptive({required List<int> sig , required List<int> pk , required List<int> msg });
+
+bool crateApiBlsBls12381MinPkVerifyAggregate({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes });
+
+bool crateApiBlsBls12381MinPkVerifyAggregateAug({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig });
+
+bool crateApiCommitteeBls12381MinPkVerifyAggregateBitmap({required List<Uint8List> allPks , required List<int> signerBitmap , required List<int> msg , required List<int> aggSigBytes });
+
+void crateApiBlsBls12381MinPkVerifyAggregateChecked({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes });
+
+bool crateApiBlsBls12381MinPkVerifyAggregatePop({required List<Uint8List> pksBytes , required List<Uint8List> popsBytes , required List<int> msg , required List<int> aggSigBytes });
+
+bool crateApiEncodingBls12381MinPkVerifyAggregateUncompressed({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes });
+
+bool crateApiBlsBls12381MinPkVerifyAggregateWithDst({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes , required List<int> dst });
+
+bool crateApiCommitteeBls12381MinPkVerifyAggregates({required List<List<Uint8List>> committees , required List<Uint8List> msgs , required List<Uint8List> aggSigs });
+
+bool crateApiEncodingBls12381MinPkVerifyAny({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiBlsBls12381MinPkVerifyAug({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required List<int> aug });
+
+bool crateApiPrehashBls12381MinPkVerifyBlake2B({required List<int> sigBytes , required List<int> pkBytes , required List<int> content });
+
+VerifyCategory crateApiBlsBls12381MinPkVerifyCategorized({required List<int> sig , required List<int> pk , required List<int> msg });
+
+bool crateApiBlsBls12381MinPkVerifyChain({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required BigInt chainId });
+
+void crateApiBlsBls12381MinPkVerifyChecked({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+CycleReport crateApiDiagnosticsBls12381MinPkVerifyCycles({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiBlsBls12381MinPkVerifyFast({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required bool checkSigGroup , required bool validatePk });
+
+FullVerifyResult crateApiDiagnosticsBls12381MinPkVerifyFull({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiBlsBls12381MinPkVerifyMixed({required List<Uint8List> sharedPks , required List<int> sharedMsg , required List<Uint8List> distinctPks , required List<Uint8List> distinctMsgs , required List<int> aggSigBytes });
+
+bool crateApiBlsBls12381MinPkVerifyNonzero({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiPrehashBls12381MinPkVerifyPrehashed({required List<int> sigBytes , required List<int> pkBytes , required List<int> digest32 });
+
+bool crateApiRlpBls12381MinPkVerifyRlp({required List<int> sig , required List<int> pk , required List<int> rlpBytes });
+
+bool crateApiEncodingBls12381MinPkVerifyUncompressed({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiBlsBls12381MinPkVerifyWithDst({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required List<int> dst });
+
+int crateApiBlsBls12381MinPkVerifyWithError({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiPrehashBls12381MinPkVerifyWithHash({required List<int> sigBytes , required List<int> pkBytes , required List<int> content , required int hashId });
+
+VerifyOutcome crateApiBlsBls12381MinPkVerifyWithNonce({required List<int> sig , required List<int> pk , required List<int> msg , required BigInt nonce , required Uint64List seenNonces });
+
+Uint8List crateApiMinSigBls12381MinSigAggregate({required List<Uint8List> sigsBytes });
+
+bool crateApiMinSigBls12381MinSigVerify({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg });
+
+bool crateApiMinSigBls12381MinSigVerifyAggregate({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes });
+
+Uint8List crateApiKeysBls12381MnemonicToSeed({required String mnemonic , required String passphrase });
+
+Uint8List crateApiBlsBls12381NormalizeForeignSignature({required List<int> sigBytes , required int library_ });
+
+bool crateApiCommitteeBls12381ParticipationProof({required List<Uint8List> committeePks , required List<int> validatorPk , required List<int> bitmap });
+
+bool crateApiCommitteeBls12381QuorumMet({required List<int> signerBitmap , required Uint64List weights , required BigInt threshold });
+
+RepairedAggregate crateApiBlsBls12381RepairAggregate({required List<Uint8List> sigs , required List<Uint8List> pks , required List<int> msg });
+
+Uint32List crateApiCommitteeBls12381ResolveSigners({required List<Uint8List> committeePks , required List<int> bitmap });
+
+Uint8List crateApiRlpBls12381RlpEncodeList({required List<Uint8List> fields });
+
+SessionKeyPair? crateApiKeysBls12381SessionKeypair({required List<int> masterSk , required List<int> sessionId });
+
+void crateApiBatchBls12381SetVerifyThreads({required int n });
+
+Uint8List crateApiCommitteeBls12381SubsetAggregatePk({required List<Uint8List> committeePks , required List<int> bitmap });
+
+BigInt crateApiCommitteeBls12381TransmissionSize({required int numSigs , required bool aggregated });
+
+Uint8List crateApiCommitmentBls12381VcCommit({required List<Uint8List> pks });
+
+Uint8List crateApiCommitmentBls12381VcProve({required List<Uint8List> pks , required int index });
+
+Uint8List crateApiCommitteeBls12381VerifyAggregateReceipt({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig });
+
+bool crateApiCommitmentBls12381VerifyAggregateVc({required List<int> commitment , required List<Uint8List> pks , required List<Uint8List> vcProofs , required List<int> msg , required List<int> aggSig });
+
+FallbackResult crateApiBlsBls12381VerifyAggregateWithFallback({required List<Uint8List> pks , required List<Uint8List> sigs , required List<int> msg , required List<int> aggSig });
+
+bool crateApiCacheBls12381VerifyAndPersist({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig , required String cachePath });
+
+bool crateApiCommitteeBls12381VerifyBft({required List<Uint8List> committeePks , required List<int> bitmap , required List<int> msg , required List<int> aggSig , required int faultModel });
+
+bool crateApiCommitteeBls12381VerifyByCommitteeHash({required List<int> committeeHash , required List<Uint8List> providedPks , required List<int> bitmap , required List<int> msg , required List<int> aggSig });
+
+bool crateApiCommitteeBls12381VerifyCommitteeChange({required CommitteeHandle oldCommittee , required List<Uint8List> proposedPks , required Uint64List proposedWeights , required List<int> bitmap , required List<int> aggSig });
+
+List<bool> crateApiCommitteeBls12381VerifyManyCertificates({required CommitteeHandle committee , required List<(Uint8List,Uint8List,Uint8List)> certs });
+
+bool crateApiBlsBls12381VerifyNested({required List<Uint8List> outerPks , required List<int> innerAggSig , required List<int> outerAggSig });
+
+bool crateApiSuiBls12381VerifyObjectRef({required List<Uint8List> pks , required List<int> objectId , required BigInt version , required List<int> digest , required List<int> aggSig });
+
+bool crateApiCommitteeBls12381VerifyRoaring({required List<Uint8List> committeePks , required List<int> roaringBytes , required List<int> msg , required List<int> aggSig });
+
+bool crateApiCommitteeBls12381VerifySignerOrder({required List<Uint8List> committeePks , required List<int> expectedOrder , required List<int> bitmap , required List<int> msg , required List<int> aggSig });
+
+bool crateApiCommitteeBls12381VerifyStakeQuorumFlat({required List<int> pksConcat , required Uint64List stakes , required List<int> signerBitmap , required int thresholdBps , required List<int> msg , required List<int> aggSig });
+
+VerifyOutcome crateApiCommitteeBls12381VerifyWithAbstentions({required List<Uint8List> committeePks , required List<int> signedBitmap , required List<int> abstainBitmap , required List<int> msg , required List<int> aggSig });
+
+bool crateApiCommitteeBls12381VerifyWithCommitteeDelta({required CommitteeHandle prevCommittee , required List<Uint8List> added , required List<Uint8List> removed , required List<int> bitmap , required List<int> msg , required List<int> aggSig });
+
+Stream<VerifyEvent> crateApiBatchBls12381VerifyWithEvents({required List<Uint8List> sigs , required List<Uint8List> pks , required List<Uint8List> msgs });
+
+String crateApiSimpleGreet({required String name });
+
+Future<void> crateApiSimpleInitApp();
+
+Uint8List crateApiSuiSuiIntentMessage({required int intentScope , required int version , required int appId , required List<int> payload });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CancelToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CancelToken;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancelTokenPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CommitteeHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CommitteeHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CommitteeHandlePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CommitteeKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CommitteeKey;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CommitteeKeyPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SessionKeyPair;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SessionKeyPair;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionKeyPairPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SignatureAggregator;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SignatureAggregator;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SignatureAggregatorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SigningStats;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SigningStats;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SigningStatsPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TimedCommittee;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TimedCommittee;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TimedCommitteePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_VerifierKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_VerifierKey;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VerifierKeyPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override void crateApiBatchCancelTokenCancel({required CancelToken that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Uint8List>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_list_list_prim_u_8_strict(
-    List<Uint8List> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_prim_u_8_strict(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiBatchCancelTokenCancelConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchCancelTokenCancelConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_cancel",
+            argNames: ["that"],
+        );
+        
+
+@override Future<CancelToken> crateApiBatchCancelTokenDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchCancelTokenDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchCancelTokenDefaultConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiBatchCancelTokenIsCancelled({required CancelToken that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchCancelTokenIsCancelledConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchCancelTokenIsCancelledConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_is_cancelled",
+            argNames: ["that"],
+        );
+        
+
+@override CancelToken crateApiBatchCancelTokenNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchCancelTokenNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchCancelTokenNewConstMeta => const TaskConstMeta(
+            debugName: "CancelToken_new",
+            argNames: [],
+        );
+        
+
+@override CommitteeHandle? crateApiCommitteeCommitteeHandleNew({required List<Uint8List> pks })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeCommitteeHandleNewConstMeta,
+            argValues: [pks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeCommitteeHandleNewConstMeta => const TaskConstMeta(
+            debugName: "CommitteeHandle_new",
+            argNames: ["pks"],
+        );
+        
+
+@override int crateApiCommitteeCommitteeHandleSize({required CommitteeHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeCommitteeHandleSizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeCommitteeHandleSizeConstMeta => const TaskConstMeta(
+            debugName: "CommitteeHandle_size",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateApiCommitteeCommitteeHandleTotalWeight({required CommitteeHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeCommitteeHandleTotalWeightConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeCommitteeHandleTotalWeightConstMeta => const TaskConstMeta(
+            debugName: "CommitteeHandle_total_weight",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiCommitteeCommitteeHandleVerifyQuorum({required CommitteeHandle that , required List<int> bitmap , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(that, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeCommitteeHandleVerifyQuorumConstMeta,
+            argValues: [that, bitmap, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeCommitteeHandleVerifyQuorumConstMeta => const TaskConstMeta(
+            debugName: "CommitteeHandle_verify_quorum",
+            argNames: ["that", "bitmap", "msg", "aggSig"],
+        );
+        
+
+@override CommitteeKey? crateApiCommitteeCommitteeKeyNew({required List<Uint8List> pks })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeCommitteeKeyNewConstMeta,
+            argValues: [pks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeCommitteeKeyNewConstMeta => const TaskConstMeta(
+            debugName: "CommitteeKey_new",
+            argNames: ["pks"],
+        );
+        
+
+@override Uint8List crateApiCommitteeCommitteeKeyPublicKey({required CommitteeKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeCommitteeKeyPublicKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeCommitteeKeyPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "CommitteeKey_public_key",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiCommitteeCommitteeKeyVerify({required CommitteeKey that , required List<int> msg , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(that, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeCommitteeKeyVerifyConstMeta,
+            argValues: [that, msg, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeCommitteeKeyVerifyConstMeta => const TaskConstMeta(
+            debugName: "CommitteeKey_verify",
+            argNames: ["that", "msg", "aggSigBytes"],
+        );
+        
+
+@override Uint8List crateApiKeysSessionKeyPairPublicKey({required SessionKeyPair that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysSessionKeyPairPublicKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysSessionKeyPairPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "SessionKeyPair_public_key",
+            argNames: ["that"],
+        );
+        
+
+@override Uint8List crateApiKeysSessionKeyPairSign({required SessionKeyPair that , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(that, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysSessionKeyPairSignConstMeta,
+            argValues: [that, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysSessionKeyPairSignConstMeta => const TaskConstMeta(
+            debugName: "SessionKeyPair_sign",
+            argNames: ["that", "msg"],
+        );
+        
+
+@override bool crateApiAggregatorSignatureAggregatorAdd({required SignatureAggregator that , required List<int> sigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(that, serializer);
+sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorSignatureAggregatorAddConstMeta,
+            argValues: [that, sigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorSignatureAggregatorAddConstMeta => const TaskConstMeta(
+            debugName: "SignatureAggregator_add",
+            argNames: ["that", "sigBytes"],
+        );
+        
+
+@override int crateApiAggregatorSignatureAggregatorCount({required SignatureAggregator that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorSignatureAggregatorCountConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorSignatureAggregatorCountConstMeta => const TaskConstMeta(
+            debugName: "SignatureAggregator_count",
+            argNames: ["that"],
+        );
+        
+
+@override Future<SignatureAggregator> crateApiAggregatorSignatureAggregatorDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorSignatureAggregatorDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorSignatureAggregatorDefaultConstMeta => const TaskConstMeta(
+            debugName: "SignatureAggregator_default",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateApiAggregatorSignatureAggregatorFinalize({required SignatureAggregator that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorSignatureAggregatorFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorSignatureAggregatorFinalizeConstMeta => const TaskConstMeta(
+            debugName: "SignatureAggregator_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override SignatureAggregator? crateApiAggregatorSignatureAggregatorFromSnapshot({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorSignatureAggregatorFromSnapshotConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorSignatureAggregatorFromSnapshotConstMeta => const TaskConstMeta(
+            debugName: "SignatureAggregator_from_snapshot",
+            argNames: ["state"],
+        );
+        
+
+@override SignatureAggregator crateApiAggregatorSignatureAggregatorNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorSignatureAggregatorNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorSignatureAggregatorNewConstMeta => const TaskConstMeta(
+            debugName: "SignatureAggregator_new",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateApiAggregatorSignatureAggregatorSnapshot({required SignatureAggregator that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorSignatureAggregatorSnapshotConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorSignatureAggregatorSnapshotConstMeta => const TaskConstMeta(
+            debugName: "SignatureAggregator_snapshot",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiDiagnosticsSigningStatsClear({required SigningStats that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsSigningStatsClearConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsSigningStatsClearConstMeta => const TaskConstMeta(
+            debugName: "SigningStats_clear",
+            argNames: ["that"],
+        );
+        
+
+@override Future<SigningStats> crateApiDiagnosticsSigningStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsSigningStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsSigningStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "SigningStats_default",
+            argNames: [],
+        );
+        
+
+@override Uint64List crateApiDiagnosticsSigningStatsHistogram({required SigningStats that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_64_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsSigningStatsHistogramConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsSigningStatsHistogramConstMeta => const TaskConstMeta(
+            debugName: "SigningStats_histogram",
+            argNames: ["that"],
+        );
+        
+
+@override SigningStats crateApiDiagnosticsSigningStatsNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsSigningStatsNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsSigningStatsNewConstMeta => const TaskConstMeta(
+            debugName: "SigningStats_new",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateApiDiagnosticsSigningStatsSignTracked({required SigningStats that , required List<int> sk , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(that, serializer);
+sse_encode_list_prim_u_8_loose(sk, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsSigningStatsSignTrackedConstMeta,
+            argValues: [that, sk, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsSigningStatsSignTrackedConstMeta => const TaskConstMeta(
+            debugName: "SigningStats_sign_tracked",
+            argNames: ["that", "sk", "msg"],
+        );
+        
+
+@override TimedCommittee? crateApiCommitteeTimedCommitteeNew({required List<(Uint8List,BigInt,BigInt)> members })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_record_list_prim_u_8_strict_u_64_u_64(members, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeTimedCommitteeNewConstMeta,
+            argValues: [members],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeTimedCommitteeNewConstMeta => const TaskConstMeta(
+            debugName: "TimedCommittee_new",
+            argNames: ["members"],
+        );
+        
+
+@override bool crateApiCommitteeTimedCommitteeVerify({required TimedCommittee that , required List<int> bitmap , required List<int> msg , required List<int> aggSig , required BigInt now })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(that, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+sse_encode_u_64(now, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeTimedCommitteeVerifyConstMeta,
+            argValues: [that, bitmap, msg, aggSig, now],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeTimedCommitteeVerifyConstMeta => const TaskConstMeta(
+            debugName: "TimedCommittee_verify",
+            argNames: ["that", "bitmap", "msg", "aggSig", "now"],
+        );
+        
+
+@override VerifierKey? crateApiVerifierVerifierKeyNew({required List<int> pkBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVerifierVerifierKeyNewConstMeta,
+            argValues: [pkBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifierVerifierKeyNewConstMeta => const TaskConstMeta(
+            debugName: "VerifierKey_new",
+            argNames: ["pkBytes"],
+        );
+        
+
+@override Uint8List crateApiVerifierVerifierKeyPublicKey({required VerifierKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVerifierVerifierKeyPublicKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifierVerifierKeyPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "VerifierKey_public_key",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiVerifierVerifierKeyVerify({required VerifierKey that , required List<int> msg , required List<int> sigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(that, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVerifierVerifierKeyVerifyConstMeta,
+            argValues: [that, msg, sigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifierVerifierKeyVerifyConstMeta => const TaskConstMeta(
+            debugName: "VerifierKey_verify",
+            argNames: ["that", "msg", "sigBytes"],
+        );
+        
+
+@override Future<List<bool>> crateApiBatchBls12381BatchVerifyCancellable({required List<Uint8List> sigs , required List<Uint8List> pks , required List<Uint8List> msgs , required CancelToken cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigs, serializer);
+sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_list_prim_u_8_strict(msgs, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchBls12381BatchVerifyCancellableConstMeta,
+            argValues: [sigs, pks, msgs, cancel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchBls12381BatchVerifyCancellableConstMeta => const TaskConstMeta(
+            debugName: "bls12381_batch_verify_cancellable",
+            argNames: ["sigs", "pks", "msgs", "cancel"],
+        );
+        
+
+@override Future<BigInt> crateApiDiagnosticsBls12381BenchAggregate({required int numSigs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(numSigs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsBls12381BenchAggregateConstMeta,
+            argValues: [numSigs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsBls12381BenchAggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_bench_aggregate",
+            argNames: ["numSigs"],
+        );
+        
+
+@override BuildFeatures crateApiDiagnosticsBls12381BuildFeatures()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_build_features,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsBls12381BuildFeaturesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsBls12381BuildFeaturesConstMeta => const TaskConstMeta(
+            debugName: "bls12381_build_features",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateApiBundleBls12381BundleEncode({required List<Uint8List> committeePks , required BigInt epoch , required List<int> bitmap , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_u_64(epoch, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBundleBls12381BundleEncodeConstMeta,
+            argValues: [committeePks, epoch, bitmap, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBundleBls12381BundleEncodeConstMeta => const TaskConstMeta(
+            debugName: "bls12381_bundle_encode",
+            argNames: ["committeePks", "epoch", "bitmap", "msg", "aggSig"],
+        );
+        
+
+@override BundleInfo? crateApiBundleBls12381BundleInspect({required List<int> bundle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bundle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_bundle_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBundleBls12381BundleInspectConstMeta,
+            argValues: [bundle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBundleBls12381BundleInspectConstMeta => const TaskConstMeta(
+            debugName: "bls12381_bundle_inspect",
+            argNames: ["bundle"],
+        );
+        
+
+@override bool crateApiBundleBls12381BundleVerify({required List<int> bundle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bundle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBundleBls12381BundleVerifyConstMeta,
+            argValues: [bundle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBundleBls12381BundleVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_bundle_verify",
+            argNames: ["bundle"],
+        );
+        
+
+@override String crateApiEncodingBls12381BytesToHex({required List<int> bytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381BytesToHexConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381BytesToHexConstMeta => const TaskConstMeta(
+            debugName: "bls12381_bytes_to_hex",
+            argNames: ["bytes"],
+        );
+        
+
+@override CanonicalCertificate? crateApiCommitteeBls12381CanonicalAggregate({required List<Uint8List> committeePks , required List<(int,Uint8List)> signerSigs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_record_u_32_list_prim_u_8_strict(signerSigs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_canonical_certificate,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381CanonicalAggregateConstMeta,
+            argValues: [committeePks, signerSigs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381CanonicalAggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_canonical_aggregate",
+            argNames: ["committeePks", "signerSigs"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381ChainDst({required BigInt chainId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(chainId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381ChainDstConstMeta,
+            argValues: [chainId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381ChainDstConstMeta => const TaskConstMeta(
+            debugName: "bls12381_chain_dst",
+            argNames: ["chainId"],
+        );
+        
+
+@override Uint8List crateApiCommitteeBls12381CommitteeChangeMessage({required List<Uint8List> proposedPks , required Uint64List proposedWeights })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(proposedPks, serializer);
+sse_encode_list_prim_u_64_strict(proposedWeights, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381CommitteeChangeMessageConstMeta,
+            argValues: [proposedPks, proposedWeights],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381CommitteeChangeMessageConstMeta => const TaskConstMeta(
+            debugName: "bls12381_committee_change_message",
+            argNames: ["proposedPks", "proposedWeights"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381CommitteeContains({required List<Uint8List> sortedCommitteePks , required List<int> pk })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sortedCommitteePks, serializer);
+sse_encode_list_prim_u_8_loose(pk, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381CommitteeContainsConstMeta,
+            argValues: [sortedCommitteePks, pk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381CommitteeContainsConstMeta => const TaskConstMeta(
+            debugName: "bls12381_committee_contains",
+            argNames: ["sortedCommitteePks", "pk"],
+        );
+        
+
+@override Uint8List crateApiCommitteeBls12381CommitteeDigest({required List<Uint8List> pks })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381CommitteeDigestConstMeta,
+            argValues: [pks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381CommitteeDigestConstMeta => const TaskConstMeta(
+            debugName: "bls12381_committee_digest",
+            argNames: ["pks"],
+        );
+        
+
+@override CommitteeHandle? crateApiSuiBls12381CommitteeFromSuiBcs({required List<int> bcsBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bcsBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSuiBls12381CommitteeFromSuiBcsConstMeta,
+            argValues: [bcsBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSuiBls12381CommitteeFromSuiBcsConstMeta => const TaskConstMeta(
+            debugName: "bls12381_committee_from_sui_bcs",
+            argNames: ["bcsBytes"],
+        );
+        
+
+@override CommitteeSetup? crateApiCommitteeBls12381CommitteeSetup({required List<Uint8List> pks })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_committee_setup,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381CommitteeSetupConstMeta,
+            argValues: [pks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381CommitteeSetupConstMeta => const TaskConstMeta(
+            debugName: "bls12381_committee_setup",
+            argNames: ["pks"],
+        );
+        
+
+@override String crateApiDiagnosticsBls12381DebugVerify({required String sigHex , required String pkHex , required String msgHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sigHex, serializer);
+sse_encode_String(pkHex, serializer);
+sse_encode_String(msgHex, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsBls12381DebugVerifyConstMeta,
+            argValues: [sigHex, pkHex, msgHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsBls12381DebugVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_debug_verify",
+            argNames: ["sigHex", "pkHex", "msgHex"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381DeriveChildSk({required List<int> parentSk , required int index })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(parentSk, serializer);
+sse_encode_u_32(index, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381DeriveChildSkConstMeta,
+            argValues: [parentSk, index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381DeriveChildSkConstMeta => const TaskConstMeta(
+            debugName: "bls12381_derive_child_sk",
+            argNames: ["parentSk", "index"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381DeriveMasterSk({required List<int> seed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(seed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381DeriveMasterSkConstMeta,
+            argValues: [seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381DeriveMasterSkConstMeta => const TaskConstMeta(
+            debugName: "bls12381_derive_master_sk",
+            argNames: ["seed"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381DeriveShardKey({required List<int> masterSk , required int shardIndex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(masterSk, serializer);
+sse_encode_u_32(shardIndex, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381DeriveShardKeyConstMeta,
+            argValues: [masterSk, shardIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381DeriveShardKeyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_derive_shard_key",
+            argNames: ["masterSk", "shardIndex"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381DstForScheme({required String scheme })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381DstForSchemeConstMeta,
+            argValues: [scheme],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381DstForSchemeConstMeta => const TaskConstMeta(
+            debugName: "bls12381_dst_for_scheme",
+            argNames: ["scheme"],
+        );
+        
+
+@override Uint8List crateApiCommitteeBls12381EncodeBitmap({required List<int> signerIndices , required int committeeSize })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_32_loose(signerIndices, serializer);
+sse_encode_u_32(committeeSize, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381EncodeBitmapConstMeta,
+            argValues: [signerIndices, committeeSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381EncodeBitmapConstMeta => const TaskConstMeta(
+            debugName: "bls12381_encode_bitmap",
+            argNames: ["signerIndices", "committeeSize"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381ExportIdentity({required List<int> skBytes , required String password })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+sse_encode_String(password, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381ExportIdentityConstMeta,
+            argValues: [skBytes, password],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381ExportIdentityConstMeta => const TaskConstMeta(
+            debugName: "bls12381_export_identity",
+            argNames: ["skBytes", "password"],
+        );
+        
+
+@override Uint8List crateApiEncodingBls12381HexToBytes({required String hex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(hex, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381HexToBytesConstMeta,
+            argValues: [hex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381HexToBytesConstMeta => const TaskConstMeta(
+            debugName: "bls12381_hex_to_bytes",
+            argNames: ["hex"],
+        );
+        
+
+@override IdentityImport? crateApiKeysBls12381ImportIdentity({required List<int> blob , required String password })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(blob, serializer);
+sse_encode_String(password, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_identity_import,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381ImportIdentityConstMeta,
+            argValues: [blob, password],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381ImportIdentityConstMeta => const TaskConstMeta(
+            debugName: "bls12381_import_identity",
+            argNames: ["blob", "password"],
+        );
+        
+
+@override String crateApiCacheBls12381LastPersistError()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCacheBls12381LastPersistErrorConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCacheBls12381LastPersistErrorConstMeta => const TaskConstMeta(
+            debugName: "bls12381_last_persist_error",
+            argNames: [],
+        );
+        
+
+@override String crateApiDiagnosticsBls12381LastWarning()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsBls12381LastWarningConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsBls12381LastWarningConstMeta => const TaskConstMeta(
+            debugName: "bls12381_last_warning",
+            argNames: [],
+        );
+        
+
+@override (Uint8List,bool) crateApiAggregatorBls12381MinPkAddAndVerify({required List<int> aggSigBytes , required List<Uint8List> existingPks , required List<int> newSigBytes , required List<int> newPkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+sse_encode_list_list_prim_u_8_strict(existingPks, serializer);
+sse_encode_list_prim_u_8_loose(newSigBytes, serializer);
+sse_encode_list_prim_u_8_loose(newPkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_8_strict_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAggregatorBls12381MinPkAddAndVerifyConstMeta,
+            argValues: [aggSigBytes, existingPks, newSigBytes, newPkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAggregatorBls12381MinPkAddAndVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_add_and_verify",
+            argNames: ["aggSigBytes", "existingPks", "newSigBytes", "newPkBytes", "msg"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkAggregate({required List<Uint8List> sigsBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigsBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregateConstMeta,
+            argValues: [sigsBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate",
+            argNames: ["sigsBytes"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkAggregateAggregates({required List<Uint8List> aggSigs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(aggSigs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregateAggregatesConstMeta,
+            argValues: [aggSigs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateAggregatesConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate_aggregates",
+            argNames: ["aggSigs"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkAggregateAug({required List<Uint8List> sigs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregateAugConstMeta,
+            argValues: [sigs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateAugConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate_aug",
+            argNames: ["sigs"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkAggregateChecked({required List<Uint8List> sigsBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigsBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_bls_error,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregateCheckedConstMeta,
+            argValues: [sigsBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateCheckedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate_checked",
+            argNames: ["sigsBytes"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkAggregatePubkeys({required List<Uint8List> pksBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregatePubkeysConstMeta,
+            argValues: [pksBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregatePubkeysConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate_pubkeys",
+            argNames: ["pksBytes"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkAggregateVerified({required List<Uint8List> sigs , required List<Uint8List> pks , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigs, serializer);
+sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregateVerifiedConstMeta,
+            argValues: [sigs, pks, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateVerifiedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate_verified",
+            argNames: ["sigs", "pks", "msg"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkAggregateVerify({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_list_prim_u_8_strict(msgs, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregateVerifyConstMeta,
+            argValues: [pksBytes, msgs, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate_verify",
+            argNames: ["pksBytes", "msgs", "aggSigBytes"],
+        );
+        
+
+@override void crateApiBlsBls12381MinPkAggregateVerifyChecked({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_list_prim_u_8_strict(msgs, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bls_error,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkAggregateVerifyCheckedConstMeta,
+            argValues: [pksBytes, msgs, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkAggregateVerifyCheckedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_aggregate_verify_checked",
+            argNames: ["pksBytes", "msgs", "aggSigBytes"],
+        );
+        
+
+@override (List<Uint8List>,List<Uint8List>) crateApiKeysBls12381MinPkBatchKeygen({required List<Uint8List> seeds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(seeds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_list_prim_u_8_strict_list_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkBatchKeygenConstMeta,
+            argValues: [seeds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkBatchKeygenConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_batch_keygen",
+            argNames: ["seeds"],
+        );
+        
+
+@override bool crateApiBatchBls12381MinPkBatchVerify({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<Uint8List> sigsBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_list_prim_u_8_strict(msgs, serializer);
+sse_encode_list_list_prim_u_8_strict(sigsBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchBls12381MinPkBatchVerifyConstMeta,
+            argValues: [pksBytes, msgs, sigsBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchBls12381MinPkBatchVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_batch_verify",
+            argNames: ["pksBytes", "msgs", "sigsBytes"],
+        );
+        
+
+@override Future<List<bool>> crateApiBatchBls12381MinPkBatchVerifyParallel({required List<Uint8List> pksBytes , required List<Uint8List> msgs , required List<Uint8List> sigsBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_list_prim_u_8_strict(msgs, serializer);
+sse_encode_list_list_prim_u_8_strict(sigsBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchBls12381MinPkBatchVerifyParallelConstMeta,
+            argValues: [pksBytes, msgs, sigsBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchBls12381MinPkBatchVerifyParallelConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_batch_verify_parallel",
+            argNames: ["pksBytes", "msgs", "sigsBytes"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkDeaggregate({required List<int> aggSigBytes , required List<int> sigToRemove })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+sse_encode_list_prim_u_8_loose(sigToRemove, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkDeaggregateConstMeta,
+            argValues: [aggSigBytes, sigToRemove],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkDeaggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_deaggregate",
+            argNames: ["aggSigBytes", "sigToRemove"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381MinPkExportSkDer({required List<int> skBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkExportSkDerConstMeta,
+            argValues: [skBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkExportSkDerConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_export_sk_der",
+            argNames: ["skBytes"],
+        );
+        
+
+@override (Uint8List,Uint8List) crateApiKeysBls12381MinPkGenerateKeypair()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkGenerateKeypairConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkGenerateKeypairConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_generate_keypair",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381MinPkImportSkCt({required List<int> skBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkImportSkCtConstMeta,
+            argValues: [skBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkImportSkCtConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_import_sk_ct",
+            argNames: ["skBytes"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381MinPkImportSkDer({required List<int> der })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(der, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkImportSkDerConstMeta,
+            argValues: [der],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkImportSkDerConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_import_sk_der",
+            argNames: ["der"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkIsIdentitySignature({required List<int> sigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkIsIdentitySignatureConstMeta,
+            argValues: [sigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkIsIdentitySignatureConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_is_identity_signature",
+            argNames: ["sigBytes"],
+        );
+        
+
+@override (Uint8List,Uint8List) crateApiKeysBls12381MinPkKeygenFromSeed({required List<int> seed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(seed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkKeygenFromSeedConstMeta,
+            argValues: [seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkKeygenFromSeedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_keygen_from_seed",
+            argNames: ["seed"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkPopProve({required List<int> skBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkPopProveConstMeta,
+            argValues: [skBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkPopProveConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_pop_prove",
+            argNames: ["skBytes"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkPopVerify({required List<int> pkBytes , required List<int> popBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(popBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkPopVerifyConstMeta,
+            argValues: [pkBytes, popBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkPopVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_pop_verify",
+            argNames: ["pkBytes", "popBytes"],
+        );
+        
+
+@override Uint8List crateApiEncodingBls12381MinPkPubkeyCompress({required List<int> pkBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkPubkeyCompressConstMeta,
+            argValues: [pkBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkPubkeyCompressConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_pubkey_compress",
+            argNames: ["pkBytes"],
+        );
+        
+
+@override Uint8List crateApiEncodingBls12381MinPkPubkeyDecompress({required List<int> compressed48 })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(compressed48, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkPubkeyDecompressConstMeta,
+            argValues: [compressed48],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkPubkeyDecompressConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_pubkey_decompress",
+            argNames: ["compressed48"],
+        );
+        
+
+@override Uint8List crateApiEncodingBls12381MinPkPubkeyUncompress({required List<int> pkBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkPubkeyUncompressConstMeta,
+            argValues: [pkBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkPubkeyUncompressConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_pubkey_uncompress",
+            argNames: ["pkBytes"],
+        );
+        
+
+@override bool crateApiEncodingBls12381MinPkPubkeysEqual({required List<int> a , required List<int> b })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(a, serializer);
+sse_encode_list_prim_u_8_loose(b, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkPubkeysEqualConstMeta,
+            argValues: [a, b],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkPubkeysEqualConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_pubkeys_equal",
+            argNames: ["a", "b"],
+        );
+        
+
+@override Uint8List crateApiEncodingBls12381MinPkSigCompress({required List<int> sigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkSigCompressConstMeta,
+            argValues: [sigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkSigCompressConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sig_compress",
+            argNames: ["sigBytes"],
+        );
+        
+
+@override Uint8List crateApiEncodingBls12381MinPkSigUncompress({required List<int> sigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkSigUncompressConstMeta,
+            argValues: [sigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkSigUncompressConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sig_uncompress",
+            argNames: ["sigBytes"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkSign({required List<int> skBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkSignConstMeta,
+            argValues: [skBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkSignConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sign",
+            argNames: ["skBytes", "msg"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkSignAug({required List<int> skBytes , required List<int> msg , required List<int> aug })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aug, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkSignAugConstMeta,
+            argValues: [skBytes, msg, aug],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkSignAugConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sign_aug",
+            argNames: ["skBytes", "msg", "aug"],
+        );
+        
+
+@override Uint8List crateApiPrehashBls12381MinPkSignBlake2B({required List<int> skBytes , required List<int> content })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+sse_encode_list_prim_u_8_loose(content, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPrehashBls12381MinPkSignBlake2BConstMeta,
+            argValues: [skBytes, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPrehashBls12381MinPkSignBlake2BConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sign_blake2b",
+            argNames: ["skBytes", "content"],
+        );
+        
+
+@override Uint8List crateApiPrehashBls12381MinPkSignPrehashed({required List<int> skBytes , required List<int> digest32 })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+sse_encode_list_prim_u_8_loose(digest32, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPrehashBls12381MinPkSignPrehashedConstMeta,
+            argValues: [skBytes, digest32],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPrehashBls12381MinPkSignPrehashedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sign_prehashed",
+            argNames: ["skBytes", "digest32"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381MinPkSignWithDst({required List<int> skBytes , required List<int> msg , required List<int> dst })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(dst, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkSignWithDstConstMeta,
+            argValues: [skBytes, msg, dst],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkSignWithDstConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sign_with_dst",
+            argNames: ["skBytes", "msg", "dst"],
+        );
+        
+
+@override bool crateApiEncodingBls12381MinPkSigsEqual({required List<int> a , required List<int> b })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(a, serializer);
+sse_encode_list_prim_u_8_loose(b, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkSigsEqualConstMeta,
+            argValues: [a, b],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkSigsEqualConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sigs_equal",
+            argNames: ["a", "b"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381MinPkSkToPk({required List<int> skBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkSkToPkConstMeta,
+            argValues: [skBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkSkToPkConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sk_to_pk",
+            argNames: ["skBytes"],
+        );
+        
+
+@override List<Uint8List> crateApiCommitteeBls12381MinPkSortPubkeys({required List<Uint8List> pks })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381MinPkSortPubkeysConstMeta,
+            argValues: [pks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381MinPkSortPubkeysConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_sort_pubkeys",
+            argNames: ["pks"],
+        );
+        
+
+@override Uint8List crateApiThresholdBls12381MinPkThresholdCombine({required List<int> indices , required List<Uint8List> partialSigs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_32_loose(indices, serializer);
+sse_encode_list_list_prim_u_8_strict(partialSigs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiThresholdBls12381MinPkThresholdCombineConstMeta,
+            argValues: [indices, partialSigs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiThresholdBls12381MinPkThresholdCombineConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_threshold_combine",
+            argNames: ["indices", "partialSigs"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkValidatePubkey({required List<int> pkBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkValidatePubkeyConstMeta,
+            argValues: [pkBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkValidatePubkeyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_validate_pubkey",
+            argNames: ["pkBytes"],
+        );
+        
+
+@override bool crateApiKeysBls12381MinPkValidateSecretKey({required List<int> skBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(skBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MinPkValidateSecretKeyConstMeta,
+            argValues: [skBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MinPkValidateSecretKeyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_validate_secret_key",
+            argNames: ["skBytes"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkValidateSignature({required List<int> sigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkValidateSignatureConstMeta,
+            argValues: [sigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkValidateSignatureConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_validate_signature",
+            argNames: ["sigBytes"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerify({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiDiagnosticsBls12381MinPkVerifyAdaptive({required List<int> sig , required List<int> pk , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sig, serializer);
+sse_encode_list_prim_u_8_loose(pk, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsBls12381MinPkVerifyAdaptiveConstMeta,
+            argValues: [sig, pk, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsBls12381MinPkVerifyAdaptiveConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_adaptive",
+            argNames: ["sig", "pk", "msg"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyAggregate({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyAggregateConstMeta,
+            argValues: [pksBytes, msg, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyAggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregate",
+            argNames: ["pksBytes", "msg", "aggSigBytes"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyAggregateAug({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyAggregateAugConstMeta,
+            argValues: [pks, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyAggregateAugConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregate_aug",
+            argNames: ["pks", "msg", "aggSig"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381MinPkVerifyAggregateBitmap({required List<Uint8List> allPks , required List<int> signerBitmap , required List<int> msg , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(allPks, serializer);
+sse_encode_list_prim_u_8_loose(signerBitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381MinPkVerifyAggregateBitmapConstMeta,
+            argValues: [allPks, signerBitmap, msg, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381MinPkVerifyAggregateBitmapConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregate_bitmap",
+            argNames: ["allPks", "signerBitmap", "msg", "aggSigBytes"],
+        );
+        
+
+@override void crateApiBlsBls12381MinPkVerifyAggregateChecked({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bls_error,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyAggregateCheckedConstMeta,
+            argValues: [pksBytes, msg, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyAggregateCheckedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregate_checked",
+            argNames: ["pksBytes", "msg", "aggSigBytes"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyAggregatePop({required List<Uint8List> pksBytes , required List<Uint8List> popsBytes , required List<int> msg , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_list_prim_u_8_strict(popsBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyAggregatePopConstMeta,
+            argValues: [pksBytes, popsBytes, msg, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyAggregatePopConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregate_pop",
+            argNames: ["pksBytes", "popsBytes", "msg", "aggSigBytes"],
+        );
+        
+
+@override bool crateApiEncodingBls12381MinPkVerifyAggregateUncompressed({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkVerifyAggregateUncompressedConstMeta,
+            argValues: [pksBytes, msg, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkVerifyAggregateUncompressedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregate_uncompressed",
+            argNames: ["pksBytes", "msg", "aggSigBytes"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyAggregateWithDst({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes , required List<int> dst })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+sse_encode_list_prim_u_8_loose(dst, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyAggregateWithDstConstMeta,
+            argValues: [pksBytes, msg, aggSigBytes, dst],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyAggregateWithDstConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregate_with_dst",
+            argNames: ["pksBytes", "msg", "aggSigBytes", "dst"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381MinPkVerifyAggregates({required List<List<Uint8List>> committees , required List<Uint8List> msgs , required List<Uint8List> aggSigs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_list_prim_u_8_strict(committees, serializer);
+sse_encode_list_list_prim_u_8_strict(msgs, serializer);
+sse_encode_list_list_prim_u_8_strict(aggSigs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381MinPkVerifyAggregatesConstMeta,
+            argValues: [committees, msgs, aggSigs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381MinPkVerifyAggregatesConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aggregates",
+            argNames: ["committees", "msgs", "aggSigs"],
+        );
+        
+
+@override bool crateApiEncodingBls12381MinPkVerifyAny({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkVerifyAnyConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkVerifyAnyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_any",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyAug({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required List<int> aug })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aug, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyAugConstMeta,
+            argValues: [sigBytes, pkBytes, msg, aug],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyAugConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_aug",
+            argNames: ["sigBytes", "pkBytes", "msg", "aug"],
+        );
+        
+
+@override bool crateApiPrehashBls12381MinPkVerifyBlake2B({required List<int> sigBytes , required List<int> pkBytes , required List<int> content })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(content, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPrehashBls12381MinPkVerifyBlake2BConstMeta,
+            argValues: [sigBytes, pkBytes, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPrehashBls12381MinPkVerifyBlake2BConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_blake2b",
+            argNames: ["sigBytes", "pkBytes", "content"],
+        );
+        
+
+@override VerifyCategory crateApiBlsBls12381MinPkVerifyCategorized({required List<int> sig , required List<int> pk , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sig, serializer);
+sse_encode_list_prim_u_8_loose(pk, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_verify_category,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyCategorizedConstMeta,
+            argValues: [sig, pk, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyCategorizedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_categorized",
+            argNames: ["sig", "pk", "msg"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyChain({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required BigInt chainId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_u_64(chainId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyChainConstMeta,
+            argValues: [sigBytes, pkBytes, msg, chainId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyChainConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_chain",
+            argNames: ["sigBytes", "pkBytes", "msg", "chainId"],
+        );
+        
+
+@override void crateApiBlsBls12381MinPkVerifyChecked({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_bls_error,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyCheckedConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyCheckedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_checked",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override CycleReport crateApiDiagnosticsBls12381MinPkVerifyCycles({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cycle_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsBls12381MinPkVerifyCyclesConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsBls12381MinPkVerifyCyclesConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_cycles",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyFast({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required bool checkSigGroup , required bool validatePk })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_bool(checkSigGroup, serializer);
+sse_encode_bool(validatePk, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyFastConstMeta,
+            argValues: [sigBytes, pkBytes, msg, checkSigGroup, validatePk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyFastConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_fast",
+            argNames: ["sigBytes", "pkBytes", "msg", "checkSigGroup", "validatePk"],
+        );
+        
+
+@override FullVerifyResult crateApiDiagnosticsBls12381MinPkVerifyFull({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_full_verify_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDiagnosticsBls12381MinPkVerifyFullConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiagnosticsBls12381MinPkVerifyFullConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_full",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyMixed({required List<Uint8List> sharedPks , required List<int> sharedMsg , required List<Uint8List> distinctPks , required List<Uint8List> distinctMsgs , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sharedPks, serializer);
+sse_encode_list_prim_u_8_loose(sharedMsg, serializer);
+sse_encode_list_list_prim_u_8_strict(distinctPks, serializer);
+sse_encode_list_list_prim_u_8_strict(distinctMsgs, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyMixedConstMeta,
+            argValues: [sharedPks, sharedMsg, distinctPks, distinctMsgs, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyMixedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_mixed",
+            argNames: ["sharedPks", "sharedMsg", "distinctPks", "distinctMsgs", "aggSigBytes"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyNonzero({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyNonzeroConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyNonzeroConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_nonzero",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiPrehashBls12381MinPkVerifyPrehashed({required List<int> sigBytes , required List<int> pkBytes , required List<int> digest32 })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(digest32, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPrehashBls12381MinPkVerifyPrehashedConstMeta,
+            argValues: [sigBytes, pkBytes, digest32],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPrehashBls12381MinPkVerifyPrehashedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_prehashed",
+            argNames: ["sigBytes", "pkBytes", "digest32"],
+        );
+        
+
+@override bool crateApiRlpBls12381MinPkVerifyRlp({required List<int> sig , required List<int> pk , required List<int> rlpBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sig, serializer);
+sse_encode_list_prim_u_8_loose(pk, serializer);
+sse_encode_list_prim_u_8_loose(rlpBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRlpBls12381MinPkVerifyRlpConstMeta,
+            argValues: [sig, pk, rlpBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRlpBls12381MinPkVerifyRlpConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_rlp",
+            argNames: ["sig", "pk", "rlpBytes"],
+        );
+        
+
+@override bool crateApiEncodingBls12381MinPkVerifyUncompressed({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncodingBls12381MinPkVerifyUncompressedConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncodingBls12381MinPkVerifyUncompressedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_uncompressed",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiBlsBls12381MinPkVerifyWithDst({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg , required List<int> dst })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(dst, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyWithDstConstMeta,
+            argValues: [sigBytes, pkBytes, msg, dst],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyWithDstConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_with_dst",
+            argNames: ["sigBytes", "pkBytes", "msg", "dst"],
+        );
+        
+
+@override int crateApiBlsBls12381MinPkVerifyWithError({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyWithErrorConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyWithErrorConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_with_error",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiPrehashBls12381MinPkVerifyWithHash({required List<int> sigBytes , required List<int> pkBytes , required List<int> content , required int hashId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(content, serializer);
+sse_encode_u_8(hashId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPrehashBls12381MinPkVerifyWithHashConstMeta,
+            argValues: [sigBytes, pkBytes, content, hashId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPrehashBls12381MinPkVerifyWithHashConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_with_hash",
+            argNames: ["sigBytes", "pkBytes", "content", "hashId"],
+        );
+        
+
+@override VerifyOutcome crateApiBlsBls12381MinPkVerifyWithNonce({required List<int> sig , required List<int> pk , required List<int> msg , required BigInt nonce , required Uint64List seenNonces })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sig, serializer);
+sse_encode_list_prim_u_8_loose(pk, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_u_64(nonce, serializer);
+sse_encode_list_prim_u_64_strict(seenNonces, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_verify_outcome,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381MinPkVerifyWithNonceConstMeta,
+            argValues: [sig, pk, msg, nonce, seenNonces],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381MinPkVerifyWithNonceConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_pk_verify_with_nonce",
+            argNames: ["sig", "pk", "msg", "nonce", "seenNonces"],
+        );
+        
+
+@override Uint8List crateApiMinSigBls12381MinSigAggregate({required List<Uint8List> sigsBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigsBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMinSigBls12381MinSigAggregateConstMeta,
+            argValues: [sigsBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMinSigBls12381MinSigAggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_sig_aggregate",
+            argNames: ["sigsBytes"],
+        );
+        
+
+@override bool crateApiMinSigBls12381MinSigVerify({required List<int> sigBytes , required List<int> pkBytes , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_list_prim_u_8_loose(pkBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMinSigBls12381MinSigVerifyConstMeta,
+            argValues: [sigBytes, pkBytes, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMinSigBls12381MinSigVerifyConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_sig_verify",
+            argNames: ["sigBytes", "pkBytes", "msg"],
+        );
+        
+
+@override bool crateApiMinSigBls12381MinSigVerifyAggregate({required List<Uint8List> pksBytes , required List<int> msg , required List<int> aggSigBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pksBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSigBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMinSigBls12381MinSigVerifyAggregateConstMeta,
+            argValues: [pksBytes, msg, aggSigBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMinSigBls12381MinSigVerifyAggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_min_sig_verify_aggregate",
+            argNames: ["pksBytes", "msg", "aggSigBytes"],
+        );
+        
+
+@override Uint8List crateApiKeysBls12381MnemonicToSeed({required String mnemonic , required String passphrase })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(mnemonic, serializer);
+sse_encode_String(passphrase, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381MnemonicToSeedConstMeta,
+            argValues: [mnemonic, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381MnemonicToSeedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_mnemonic_to_seed",
+            argNames: ["mnemonic", "passphrase"],
+        );
+        
+
+@override Uint8List crateApiBlsBls12381NormalizeForeignSignature({required List<int> sigBytes , required int library_ })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(sigBytes, serializer);
+sse_encode_u_8(library_, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381NormalizeForeignSignatureConstMeta,
+            argValues: [sigBytes, library_],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381NormalizeForeignSignatureConstMeta => const TaskConstMeta(
+            debugName: "bls12381_normalize_foreign_signature",
+            argNames: ["sigBytes", "library_"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381ParticipationProof({required List<Uint8List> committeePks , required List<int> validatorPk , required List<int> bitmap })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_prim_u_8_loose(validatorPk, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381ParticipationProofConstMeta,
+            argValues: [committeePks, validatorPk, bitmap],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381ParticipationProofConstMeta => const TaskConstMeta(
+            debugName: "bls12381_participation_proof",
+            argNames: ["committeePks", "validatorPk", "bitmap"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381QuorumMet({required List<int> signerBitmap , required Uint64List weights , required BigInt threshold })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(signerBitmap, serializer);
+sse_encode_list_prim_u_64_strict(weights, serializer);
+sse_encode_u_64(threshold, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381QuorumMetConstMeta,
+            argValues: [signerBitmap, weights, threshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381QuorumMetConstMeta => const TaskConstMeta(
+            debugName: "bls12381_quorum_met",
+            argNames: ["signerBitmap", "weights", "threshold"],
+        );
+        
+
+@override RepairedAggregate crateApiBlsBls12381RepairAggregate({required List<Uint8List> sigs , required List<Uint8List> pks , required List<int> msg })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigs, serializer);
+sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_repaired_aggregate,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381RepairAggregateConstMeta,
+            argValues: [sigs, pks, msg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381RepairAggregateConstMeta => const TaskConstMeta(
+            debugName: "bls12381_repair_aggregate",
+            argNames: ["sigs", "pks", "msg"],
+        );
+        
+
+@override Uint32List crateApiCommitteeBls12381ResolveSigners({required List<Uint8List> committeePks , required List<int> bitmap })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381ResolveSignersConstMeta,
+            argValues: [committeePks, bitmap],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381ResolveSignersConstMeta => const TaskConstMeta(
+            debugName: "bls12381_resolve_signers",
+            argNames: ["committeePks", "bitmap"],
+        );
+        
+
+@override Uint8List crateApiRlpBls12381RlpEncodeList({required List<Uint8List> fields })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(fields, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRlpBls12381RlpEncodeListConstMeta,
+            argValues: [fields],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRlpBls12381RlpEncodeListConstMeta => const TaskConstMeta(
+            debugName: "bls12381_rlp_encode_list",
+            argNames: ["fields"],
+        );
+        
+
+@override SessionKeyPair? crateApiKeysBls12381SessionKeypair({required List<int> masterSk , required List<int> sessionId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(masterSk, serializer);
+sse_encode_list_prim_u_8_loose(sessionId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiKeysBls12381SessionKeypairConstMeta,
+            argValues: [masterSk, sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKeysBls12381SessionKeypairConstMeta => const TaskConstMeta(
+            debugName: "bls12381_session_keypair",
+            argNames: ["masterSk", "sessionId"],
+        );
+        
+
+@override void crateApiBatchBls12381SetVerifyThreads({required int n })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(n, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchBls12381SetVerifyThreadsConstMeta,
+            argValues: [n],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBatchBls12381SetVerifyThreadsConstMeta => const TaskConstMeta(
+            debugName: "bls12381_set_verify_threads",
+            argNames: ["n"],
+        );
+        
+
+@override Uint8List crateApiCommitteeBls12381SubsetAggregatePk({required List<Uint8List> committeePks , required List<int> bitmap })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381SubsetAggregatePkConstMeta,
+            argValues: [committeePks, bitmap],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381SubsetAggregatePkConstMeta => const TaskConstMeta(
+            debugName: "bls12381_subset_aggregate_pk",
+            argNames: ["committeePks", "bitmap"],
+        );
+        
+
+@override BigInt crateApiCommitteeBls12381TransmissionSize({required int numSigs , required bool aggregated })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(numSigs, serializer);
+sse_encode_bool(aggregated, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381TransmissionSizeConstMeta,
+            argValues: [numSigs, aggregated],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381TransmissionSizeConstMeta => const TaskConstMeta(
+            debugName: "bls12381_transmission_size",
+            argNames: ["numSigs", "aggregated"],
+        );
+        
+
+@override Uint8List crateApiCommitmentBls12381VcCommit({required List<Uint8List> pks })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitmentBls12381VcCommitConstMeta,
+            argValues: [pks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitmentBls12381VcCommitConstMeta => const TaskConstMeta(
+            debugName: "bls12381_vc_commit",
+            argNames: ["pks"],
+        );
+        
+
+@override Uint8List crateApiCommitmentBls12381VcProve({required List<Uint8List> pks , required int index })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_u_32(index, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitmentBls12381VcProveConstMeta,
+            argValues: [pks, index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitmentBls12381VcProveConstMeta => const TaskConstMeta(
+            debugName: "bls12381_vc_prove",
+            argNames: ["pks", "index"],
+        );
+        
+
+@override Uint8List crateApiCommitteeBls12381VerifyAggregateReceipt({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyAggregateReceiptConstMeta,
+            argValues: [pks, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyAggregateReceiptConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_aggregate_receipt",
+            argNames: ["pks", "msg", "aggSig"],
+        );
+        
+
+@override bool crateApiCommitmentBls12381VerifyAggregateVc({required List<int> commitment , required List<Uint8List> pks , required List<Uint8List> vcProofs , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(commitment, serializer);
+sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_list_prim_u_8_strict(vcProofs, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitmentBls12381VerifyAggregateVcConstMeta,
+            argValues: [commitment, pks, vcProofs, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitmentBls12381VerifyAggregateVcConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_aggregate_vc",
+            argNames: ["commitment", "pks", "vcProofs", "msg", "aggSig"],
+        );
+        
+
+@override FallbackResult crateApiBlsBls12381VerifyAggregateWithFallback({required List<Uint8List> pks , required List<Uint8List> sigs , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_list_prim_u_8_strict(sigs, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fallback_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381VerifyAggregateWithFallbackConstMeta,
+            argValues: [pks, sigs, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381VerifyAggregateWithFallbackConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_aggregate_with_fallback",
+            argNames: ["pks", "sigs", "msg", "aggSig"],
+        );
+        
+
+@override bool crateApiCacheBls12381VerifyAndPersist({required List<Uint8List> pks , required List<int> msg , required List<int> aggSig , required String cachePath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+sse_encode_String(cachePath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCacheBls12381VerifyAndPersistConstMeta,
+            argValues: [pks, msg, aggSig, cachePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCacheBls12381VerifyAndPersistConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_and_persist",
+            argNames: ["pks", "msg", "aggSig", "cachePath"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381VerifyBft({required List<Uint8List> committeePks , required List<int> bitmap , required List<int> msg , required List<int> aggSig , required int faultModel })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+sse_encode_u_8(faultModel, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyBftConstMeta,
+            argValues: [committeePks, bitmap, msg, aggSig, faultModel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyBftConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_bft",
+            argNames: ["committeePks", "bitmap", "msg", "aggSig", "faultModel"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381VerifyByCommitteeHash({required List<int> committeeHash , required List<Uint8List> providedPks , required List<int> bitmap , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(committeeHash, serializer);
+sse_encode_list_list_prim_u_8_strict(providedPks, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyByCommitteeHashConstMeta,
+            argValues: [committeeHash, providedPks, bitmap, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyByCommitteeHashConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_by_committee_hash",
+            argNames: ["committeeHash", "providedPks", "bitmap", "msg", "aggSig"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381VerifyCommitteeChange({required CommitteeHandle oldCommittee , required List<Uint8List> proposedPks , required Uint64List proposedWeights , required List<int> bitmap , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(oldCommittee, serializer);
+sse_encode_list_list_prim_u_8_strict(proposedPks, serializer);
+sse_encode_list_prim_u_64_strict(proposedWeights, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyCommitteeChangeConstMeta,
+            argValues: [oldCommittee, proposedPks, proposedWeights, bitmap, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyCommitteeChangeConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_committee_change",
+            argNames: ["oldCommittee", "proposedPks", "proposedWeights", "bitmap", "aggSig"],
+        );
+        
+
+@override List<bool> crateApiCommitteeBls12381VerifyManyCertificates({required CommitteeHandle committee , required List<(Uint8List,Uint8List,Uint8List)> certs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(committee, serializer);
+sse_encode_list_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(certs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyManyCertificatesConstMeta,
+            argValues: [committee, certs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyManyCertificatesConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_many_certificates",
+            argNames: ["committee", "certs"],
+        );
+        
+
+@override bool crateApiBlsBls12381VerifyNested({required List<Uint8List> outerPks , required List<int> innerAggSig , required List<int> outerAggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(outerPks, serializer);
+sse_encode_list_prim_u_8_loose(innerAggSig, serializer);
+sse_encode_list_prim_u_8_loose(outerAggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlsBls12381VerifyNestedConstMeta,
+            argValues: [outerPks, innerAggSig, outerAggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlsBls12381VerifyNestedConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_nested",
+            argNames: ["outerPks", "innerAggSig", "outerAggSig"],
+        );
+        
+
+@override bool crateApiSuiBls12381VerifyObjectRef({required List<Uint8List> pks , required List<int> objectId , required BigInt version , required List<int> digest , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_prim_u_8_loose(objectId, serializer);
+sse_encode_u_64(version, serializer);
+sse_encode_list_prim_u_8_loose(digest, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSuiBls12381VerifyObjectRefConstMeta,
+            argValues: [pks, objectId, version, digest, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSuiBls12381VerifyObjectRefConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_object_ref",
+            argNames: ["pks", "objectId", "version", "digest", "aggSig"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381VerifyRoaring({required List<Uint8List> committeePks , required List<int> roaringBytes , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_prim_u_8_loose(roaringBytes, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyRoaringConstMeta,
+            argValues: [committeePks, roaringBytes, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyRoaringConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_roaring",
+            argNames: ["committeePks", "roaringBytes", "msg", "aggSig"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381VerifySignerOrder({required List<Uint8List> committeePks , required List<int> expectedOrder , required List<int> bitmap , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_prim_u_32_loose(expectedOrder, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifySignerOrderConstMeta,
+            argValues: [committeePks, expectedOrder, bitmap, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifySignerOrderConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_signer_order",
+            argNames: ["committeePks", "expectedOrder", "bitmap", "msg", "aggSig"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381VerifyStakeQuorumFlat({required List<int> pksConcat , required Uint64List stakes , required List<int> signerBitmap , required int thresholdBps , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(pksConcat, serializer);
+sse_encode_list_prim_u_64_strict(stakes, serializer);
+sse_encode_list_prim_u_8_loose(signerBitmap, serializer);
+sse_encode_u_32(thresholdBps, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyStakeQuorumFlatConstMeta,
+            argValues: [pksConcat, stakes, signerBitmap, thresholdBps, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyStakeQuorumFlatConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_stake_quorum_flat",
+            argNames: ["pksConcat", "stakes", "signerBitmap", "thresholdBps", "msg", "aggSig"],
+        );
+        
+
+@override VerifyOutcome crateApiCommitteeBls12381VerifyWithAbstentions({required List<Uint8List> committeePks , required List<int> signedBitmap , required List<int> abstainBitmap , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(committeePks, serializer);
+sse_encode_list_prim_u_8_loose(signedBitmap, serializer);
+sse_encode_list_prim_u_8_loose(abstainBitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_verify_outcome,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyWithAbstentionsConstMeta,
+            argValues: [committeePks, signedBitmap, abstainBitmap, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyWithAbstentionsConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_with_abstentions",
+            argNames: ["committeePks", "signedBitmap", "abstainBitmap", "msg", "aggSig"],
+        );
+        
+
+@override bool crateApiCommitteeBls12381VerifyWithCommitteeDelta({required CommitteeHandle prevCommittee , required List<Uint8List> added , required List<Uint8List> removed , required List<int> bitmap , required List<int> msg , required List<int> aggSig })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(prevCommittee, serializer);
+sse_encode_list_list_prim_u_8_strict(added, serializer);
+sse_encode_list_list_prim_u_8_strict(removed, serializer);
+sse_encode_list_prim_u_8_loose(bitmap, serializer);
+sse_encode_list_prim_u_8_loose(msg, serializer);
+sse_encode_list_prim_u_8_loose(aggSig, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCommitteeBls12381VerifyWithCommitteeDeltaConstMeta,
+            argValues: [prevCommittee, added, removed, bitmap, msg, aggSig],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitteeBls12381VerifyWithCommitteeDeltaConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_with_committee_delta",
+            argNames: ["prevCommittee", "added", "removed", "bitmap", "msg", "aggSig"],
+        );
+        
+
+@override Stream<VerifyEvent> crateApiBatchBls12381VerifyWithEvents({required List<Uint8List> sigs , required List<Uint8List> pks , required List<Uint8List> msgs })  { 
+            final sink = RustStreamSink<VerifyEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(sigs, serializer);
+sse_encode_list_list_prim_u_8_strict(pks, serializer);
+sse_encode_list_list_prim_u_8_strict(msgs, serializer);
+sse_encode_StreamSink_verify_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBatchBls12381VerifyWithEventsConstMeta,
+            argValues: [sigs, pks, msgs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiBatchBls12381VerifyWithEventsConstMeta => const TaskConstMeta(
+            debugName: "bls12381_verify_with_events",
+            argNames: ["sigs", "pks", "msgs", "sink"],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateApiSuiSuiIntentMessage({required int intentScope , required int version , required int appId , required List<int> payload })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_8(intentScope, serializer);
+sse_encode_u_8(version, serializer);
+sse_encode_u_8(appId, serializer);
+sse_encode_list_prim_u_8_loose(payload, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSuiSuiIntentMessageConstMeta,
+            argValues: [intentScope, version, appId, payload],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSuiSuiIntentMessageConstMeta => const TaskConstMeta(
+            debugName: "sui_intent_message",
+            argNames: ["intentScope", "version", "appId", "payload"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CancelToken => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CancelToken => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CommitteeHandle => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CommitteeHandle => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CommitteeKey => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CommitteeKey => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SessionKeyPair => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SessionKeyPair => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SignatureAggregator => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SignatureAggregator => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SigningStats => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SigningStats => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TimedCommittee => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TimedCommittee => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_VerifierKey => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_VerifierKey => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected CancelToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CommitteeHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CommitteeHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CommitteeKey dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CommitteeKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SessionKeyPair dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SessionKeyPairImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SignatureAggregator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SigningStats dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SigningStatsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TimedCommittee dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimedCommitteeImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected VerifierKey dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VerifierKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SignatureAggregator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SigningStats dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SigningStatsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CancelToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CommitteeHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CommitteeHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CommitteeKey dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CommitteeKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SessionKeyPair dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SessionKeyPairImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SignatureAggregator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SigningStats dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SigningStatsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TimedCommittee dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimedCommitteeImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected VerifierKey dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VerifierKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CancelToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CommitteeHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CommitteeHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CommitteeKey dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CommitteeKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SessionKeyPair dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SessionKeyPairImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SignatureAggregator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SigningStats dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SigningStatsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TimedCommittee dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimedCommitteeImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected VerifierKey dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VerifierKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<VerifyEvent> dco_decode_StreamSink_verify_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected BlsError dco_decode_bls_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BlsError.values[raw as int]; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CommitteeHandle dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(raw); }
+
+@protected CommitteeKey dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(raw); }
+
+@protected SessionKeyPair dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(raw); }
+
+@protected SignatureAggregator dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(raw); }
+
+@protected TimedCommittee dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(raw); }
+
+@protected VerifierKey dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(raw); }
+
+@protected BundleInfo dco_decode_box_autoadd_bundle_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bundle_info(raw); }
+
+@protected CanonicalCertificate dco_decode_box_autoadd_canonical_certificate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_canonical_certificate(raw); }
+
+@protected CommitteeSetup dco_decode_box_autoadd_committee_setup(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_committee_setup(raw); }
+
+@protected IdentityImport dco_decode_box_autoadd_identity_import(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_identity_import(raw); }
+
+@protected BuildFeatures dco_decode_build_features(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BuildFeatures(portable: dco_decode_bool(arr[0]),
+targetArch: dco_decode_String(arr[1]),); }
+
+@protected BundleInfo dco_decode_bundle_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BundleInfo(committeeSize: dco_decode_u_32(arr[0]),
+signerCount: dco_decode_u_32(arr[1]),
+epoch: dco_decode_u_64(arr[2]),
+valid: dco_decode_bool(arr[3]),); }
+
+@protected CanonicalCertificate dco_decode_canonical_certificate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CanonicalCertificate(bitmap: dco_decode_list_prim_u_8_strict(arr[0]),
+aggregate: dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected CommitteeSetup dco_decode_committee_setup(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CommitteeSetup(aggregatePk: dco_decode_list_prim_u_8_strict(arr[0]),
+digest: dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected CycleReport dco_decode_cycle_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CycleReport(valid: dco_decode_bool(arr[0]),
+cycles: dco_decode_u_64(arr[1]),
+supported: dco_decode_bool(arr[2]),); }
+
+@protected FallbackResult dco_decode_fallback_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FallbackResult(aggregateValid: dco_decode_bool(arr[0]),
+failing: dco_decode_list_prim_u_32_strict(arr[1]),); }
+
+@protected FullVerifyResult dco_decode_full_verify_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FullVerifyResult(signatureValidPoint: dco_decode_bool(arr[0]),
+pubkeyValidPoint: dco_decode_bool(arr[1]),
+pubkeyNotIdentity: dco_decode_bool(arr[2]),
+signatureValid: dco_decode_bool(arr[3]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected IdentityImport dco_decode_identity_import(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IdentityImport(secretKey: dco_decode_list_prim_u_8_strict(arr[0]),
+publicKey: dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected List<bool> dco_decode_list_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_bool).toList(); }
+
+@protected List<List<Uint8List>> dco_decode_list_list_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_list_prim_u_8_strict).toList(); }
+
+@protected List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeUint64List(raw); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(Uint8List,Uint8List,Uint8List)> dco_decode_list_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict).toList(); }
+
+@protected List<(Uint8List,BigInt,BigInt)> dco_decode_list_record_list_prim_u_8_strict_u_64_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_list_prim_u_8_strict_u_64_u_64).toList(); }
+
+@protected List<(int,Uint8List)> dco_decode_list_record_u_32_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_list_prim_u_8_strict).toList(); }
+
+@protected CommitteeHandle? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(raw); }
+
+@protected CommitteeKey? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(raw); }
+
+@protected SessionKeyPair? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(raw); }
+
+@protected SignatureAggregator? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(raw); }
+
+@protected TimedCommittee? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(raw); }
+
+@protected VerifierKey? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(raw); }
+
+@protected BundleInfo? dco_decode_opt_box_autoadd_bundle_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bundle_info(raw); }
+
+@protected CanonicalCertificate? dco_decode_opt_box_autoadd_canonical_certificate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_canonical_certificate(raw); }
+
+@protected CommitteeSetup? dco_decode_opt_box_autoadd_committee_setup(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_committee_setup(raw); }
+
+@protected IdentityImport? dco_decode_opt_box_autoadd_identity_import(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_identity_import(raw); }
+
+@protected (List<Uint8List>,List<Uint8List>) dco_decode_record_list_list_prim_u_8_strict_list_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_list_list_prim_u_8_strict(arr[0]),dco_decode_list_list_prim_u_8_strict(arr[1]),); }
+
+@protected (Uint8List,bool) dco_decode_record_list_prim_u_8_strict_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_list_prim_u_8_strict(arr[0]),dco_decode_bool(arr[1]),); }
+
+@protected (Uint8List,Uint8List) dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_list_prim_u_8_strict(arr[0]),dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected (Uint8List,Uint8List,Uint8List) dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 3) {
+                throw Exception('Expected 3 elements, got ${arr.length}');
+            }
+            return (dco_decode_list_prim_u_8_strict(arr[0]),dco_decode_list_prim_u_8_strict(arr[1]),dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected (Uint8List,BigInt,BigInt) dco_decode_record_list_prim_u_8_strict_u_64_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 3) {
+                throw Exception('Expected 3 elements, got ${arr.length}');
+            }
+            return (dco_decode_list_prim_u_8_strict(arr[0]),dco_decode_u_64(arr[1]),dco_decode_u_64(arr[2]),); }
+
+@protected (int,Uint8List) dco_decode_record_u_32_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected RepairedAggregate dco_decode_repaired_aggregate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RepairedAggregate(aggregate: dco_decode_list_prim_u_8_strict(arr[0]),
+dropped: dco_decode_list_prim_u_32_strict(arr[1]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected VerifyCategory dco_decode_verify_category(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VerifyCategory.values[raw as int]; }
+
+@protected VerifyEvent dco_decode_verify_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return VerifyEvent(index: dco_decode_u_32(arr[0]),
+valid: dco_decode_bool(arr[1]),
+elapsedUs: dco_decode_u_64(arr[2]),); }
+
+@protected VerifyOutcome dco_decode_verify_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VerifyOutcome.values[raw as int]; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected CancelToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancelTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CommitteeHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CommitteeHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CommitteeKey sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CommitteeKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SessionKeyPair sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SessionKeyPairImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SignatureAggregator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SigningStats sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SigningStatsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TimedCommittee sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TimedCommitteeImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected VerifierKey sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return VerifierKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SignatureAggregator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SigningStats sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SigningStatsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CancelToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancelTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CommitteeHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CommitteeHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CommitteeKey sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CommitteeKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SessionKeyPair sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SessionKeyPairImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SignatureAggregator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SigningStats sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SigningStatsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TimedCommittee sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TimedCommitteeImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected VerifierKey sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return VerifierKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CancelToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancelTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CommitteeHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CommitteeHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CommitteeKey sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CommitteeKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SessionKeyPair sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SessionKeyPairImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SignatureAggregator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SignatureAggregatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SigningStats sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SigningStatsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TimedCommittee sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TimedCommitteeImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected VerifierKey sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return VerifierKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<VerifyEvent> sse_decode_StreamSink_verify_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected BlsError sse_decode_bls_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BlsError.values[inner]; }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected CommitteeHandle sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(deserializer)); }
+
+@protected CommitteeKey sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(deserializer)); }
+
+@protected SessionKeyPair sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(deserializer)); }
+
+@protected SignatureAggregator sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(deserializer)); }
+
+@protected TimedCommittee sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(deserializer)); }
+
+@protected VerifierKey sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(deserializer)); }
+
+@protected BundleInfo sse_decode_box_autoadd_bundle_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bundle_info(deserializer)); }
+
+@protected CanonicalCertificate sse_decode_box_autoadd_canonical_certificate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_canonical_certificate(deserializer)); }
+
+@protected CommitteeSetup sse_decode_box_autoadd_committee_setup(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_committee_setup(deserializer)); }
+
+@protected IdentityImport sse_decode_box_autoadd_identity_import(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_identity_import(deserializer)); }
+
+@protected BuildFeatures sse_decode_build_features(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_portable = sse_decode_bool(deserializer);
+var var_targetArch = sse_decode_String(deserializer);
+return BuildFeatures(portable: var_portable, targetArch: var_targetArch); }
+
+@protected BundleInfo sse_decode_bundle_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_committeeSize = sse_decode_u_32(deserializer);
+var var_signerCount = sse_decode_u_32(deserializer);
+var var_epoch = sse_decode_u_64(deserializer);
+var var_valid = sse_decode_bool(deserializer);
+return BundleInfo(committeeSize: var_committeeSize, signerCount: var_signerCount, epoch: var_epoch, valid: var_valid); }
+
+@protected CanonicalCertificate sse_decode_canonical_certificate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bitmap = sse_decode_list_prim_u_8_strict(deserializer);
+var var_aggregate = sse_decode_list_prim_u_8_strict(deserializer);
+return CanonicalCertificate(bitmap: var_bitmap, aggregate: var_aggregate); }
+
+@protected CommitteeSetup sse_decode_committee_setup(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_aggregatePk = sse_decode_list_prim_u_8_strict(deserializer);
+var var_digest = sse_decode_list_prim_u_8_strict(deserializer);
+return CommitteeSetup(aggregatePk: var_aggregatePk, digest: var_digest); }
+
+@protected CycleReport sse_decode_cycle_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_valid = sse_decode_bool(deserializer);
+var var_cycles = sse_decode_u_64(deserializer);
+var var_supported = sse_decode_bool(deserializer);
+return CycleReport(valid: var_valid, cycles: var_cycles, supported: var_supported); }
+
+@protected FallbackResult sse_decode_fallback_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_aggregateValid = sse_decode_bool(deserializer);
+var var_failing = sse_decode_list_prim_u_32_strict(deserializer);
+return FallbackResult(aggregateValid: var_aggregateValid, failing: var_failing); }
+
+@protected FullVerifyResult sse_decode_full_verify_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_signatureValidPoint = sse_decode_bool(deserializer);
+var var_pubkeyValidPoint = sse_decode_bool(deserializer);
+var var_pubkeyNotIdentity = sse_decode_bool(deserializer);
+var var_signatureValid = sse_decode_bool(deserializer);
+return FullVerifyResult(signatureValidPoint: var_signatureValidPoint, pubkeyValidPoint: var_pubkeyValidPoint, pubkeyNotIdentity: var_pubkeyNotIdentity, signatureValid: var_signatureValid); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected IdentityImport sse_decode_identity_import(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_secretKey = sse_decode_list_prim_u_8_strict(deserializer);
+var var_publicKey = sse_decode_list_prim_u_8_strict(deserializer);
+return IdentityImport(secretKey: var_secretKey, publicKey: var_publicKey); }
+
+@protected List<bool> sse_decode_list_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <bool>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_bool(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<Uint8List>> sse_decode_list_list_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<Uint8List>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Uint8List> sse_decode_list_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Uint8List>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(Uint8List,Uint8List,Uint8List)> sse_decode_list_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(Uint8List,Uint8List,Uint8List)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(Uint8List,BigInt,BigInt)> sse_decode_list_record_list_prim_u_8_strict_u_64_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(Uint8List,BigInt,BigInt)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_list_prim_u_8_strict_u_64_u_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,Uint8List)> sse_decode_list_record_u_32_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,Uint8List)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected CommitteeHandle? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CommitteeKey? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SessionKeyPair? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SignatureAggregator? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TimedCommittee? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected VerifierKey? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BundleInfo? sse_decode_opt_box_autoadd_bundle_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bundle_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CanonicalCertificate? sse_decode_opt_box_autoadd_canonical_certificate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_canonical_certificate(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CommitteeSetup? sse_decode_opt_box_autoadd_committee_setup(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_committee_setup(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected IdentityImport? sse_decode_opt_box_autoadd_identity_import(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_identity_import(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected (List<Uint8List>,List<Uint8List>) sse_decode_record_list_list_prim_u_8_strict_list_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_list_list_prim_u_8_strict(deserializer);
+var var_field1 = sse_decode_list_list_prim_u_8_strict(deserializer);
+return (var_field0, var_field1); }
+
+@protected (Uint8List,bool) sse_decode_record_list_prim_u_8_strict_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+var var_field1 = sse_decode_bool(deserializer);
+return (var_field0, var_field1); }
+
+@protected (Uint8List,Uint8List) sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+return (var_field0, var_field1); }
+
+@protected (Uint8List,Uint8List,Uint8List) sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+var var_field2 = sse_decode_list_prim_u_8_strict(deserializer);
+return (var_field0, var_field1, var_field2); }
+
+@protected (Uint8List,BigInt,BigInt) sse_decode_record_list_prim_u_8_strict_u_64_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+var var_field1 = sse_decode_u_64(deserializer);
+var var_field2 = sse_decode_u_64(deserializer);
+return (var_field0, var_field1, var_field2); }
+
+@protected (int,Uint8List) sse_decode_record_u_32_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+return (var_field0, var_field1); }
+
+@protected RepairedAggregate sse_decode_repaired_aggregate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_aggregate = sse_decode_list_prim_u_8_strict(deserializer);
+var var_dropped = sse_decode_list_prim_u_32_strict(deserializer);
+return RepairedAggregate(aggregate: var_aggregate, dropped: var_dropped); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected VerifyCategory sse_decode_verify_category(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VerifyCategory.values[inner]; }
+
+@protected VerifyEvent sse_decode_verify_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_valid = sse_decode_bool(deserializer);
+var var_elapsedUs = sse_decode_u_64(deserializer);
+return VerifyEvent(index: var_index, valid: var_valid, elapsedUs: var_elapsedUs); }
+
+@protected VerifyOutcome sse_decode_verify_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VerifyOutcome.values[inner]; }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancelTokenImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CommitteeHandleImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CommitteeKeyImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SessionKeyPairImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SignatureAggregatorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SigningStatsImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TimedCommitteeImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as VerifierKeyImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SignatureAggregatorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SigningStatsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancelTokenImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CommitteeHandleImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CommitteeKeyImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SessionKeyPairImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SignatureAggregatorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SigningStatsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TimedCommitteeImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as VerifierKeyImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancelTokenImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CommitteeHandleImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CommitteeKeyImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SessionKeyPairImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SignatureAggregatorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SigningStatsImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TimedCommitteeImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as VerifierKeyImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_verify_event_Sse(RustStreamSink<VerifyEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_verify_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bls_error(BlsError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(self, serializer); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(self, serializer); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(self, serializer); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(self, serializer); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(self, serializer); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bundle_info(BundleInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bundle_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_canonical_certificate(CanonicalCertificate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_canonical_certificate(self, serializer); }
+
+@protected void sse_encode_box_autoadd_committee_setup(CommitteeSetup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_committee_setup(self, serializer); }
+
+@protected void sse_encode_box_autoadd_identity_import(IdentityImport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_identity_import(self, serializer); }
+
+@protected void sse_encode_build_features(BuildFeatures self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.portable, serializer);
+sse_encode_String(self.targetArch, serializer);
+ }
+
+@protected void sse_encode_bundle_info(BundleInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.committeeSize, serializer);
+sse_encode_u_32(self.signerCount, serializer);
+sse_encode_u_64(self.epoch, serializer);
+sse_encode_bool(self.valid, serializer);
+ }
+
+@protected void sse_encode_canonical_certificate(CanonicalCertificate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.bitmap, serializer);
+sse_encode_list_prim_u_8_strict(self.aggregate, serializer);
+ }
+
+@protected void sse_encode_committee_setup(CommitteeSetup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.aggregatePk, serializer);
+sse_encode_list_prim_u_8_strict(self.digest, serializer);
+ }
+
+@protected void sse_encode_cycle_report(CycleReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.valid, serializer);
+sse_encode_u_64(self.cycles, serializer);
+sse_encode_bool(self.supported, serializer);
+ }
+
+@protected void sse_encode_fallback_result(FallbackResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.aggregateValid, serializer);
+sse_encode_list_prim_u_32_strict(self.failing, serializer);
+ }
+
+@protected void sse_encode_full_verify_result(FullVerifyResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.signatureValidPoint, serializer);
+sse_encode_bool(self.pubkeyValidPoint, serializer);
+sse_encode_bool(self.pubkeyNotIdentity, serializer);
+sse_encode_bool(self.signatureValid, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_identity_import(IdentityImport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.secretKey, serializer);
+sse_encode_list_prim_u_8_strict(self.publicKey, serializer);
+ }
+
+@protected void sse_encode_list_bool(List<bool> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_bool(item, serializer); } }
+
+@protected void sse_encode_list_list_list_prim_u_8_strict(List<List<Uint8List>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_list_prim_u_8_strict(List<Uint8List> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self is Uint32List ? self : Uint32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(List<(Uint8List,Uint8List,Uint8List)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_record_list_prim_u_8_strict_u_64_u_64(List<(Uint8List,BigInt,BigInt)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_list_prim_u_8_strict_u_64_u_64(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_list_prim_u_8_strict(List<(int,Uint8List)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bundle_info(BundleInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bundle_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_canonical_certificate(CanonicalCertificate? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_canonical_certificate(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_committee_setup(CommitteeSetup? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_committee_setup(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_identity_import(IdentityImport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_identity_import(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_record_list_list_prim_u_8_strict_list_list_prim_u_8_strict((List<Uint8List>,List<Uint8List>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_list_prim_u_8_strict(self.$1, serializer);
+sse_encode_list_list_prim_u_8_strict(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_list_prim_u_8_strict_bool((Uint8List,bool) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.$1, serializer);
+sse_encode_bool(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict((Uint8List,Uint8List) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.$1, serializer);
+sse_encode_list_prim_u_8_strict(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict((Uint8List,Uint8List,Uint8List) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.$1, serializer);
+sse_encode_list_prim_u_8_strict(self.$2, serializer);
+sse_encode_list_prim_u_8_strict(self.$3, serializer);
+ }
+
+@protected void sse_encode_record_list_prim_u_8_strict_u_64_u_64((Uint8List,BigInt,BigInt) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.$1, serializer);
+sse_encode_u_64(self.$2, serializer);
+sse_encode_u_64(self.$3, serializer);
+ }
+
+@protected void sse_encode_record_u_32_list_prim_u_8_strict((int,Uint8List) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_list_prim_u_8_strict(self.$2, serializer);
+ }
+
+@protected void sse_encode_repaired_aggregate(RepairedAggregate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.aggregate, serializer);
+sse_encode_list_prim_u_32_strict(self.dropped, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_verify_category(VerifyCategory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_verify_event(VerifyEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_bool(self.valid, serializer);
+sse_encode_u_64(self.elapsedUs, serializer);
+ }
+
+@protected void sse_encode_verify_outcome(VerifyOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+                }
+                
+
+            @sealed class CancelTokenImpl extends RustOpaque implements CancelToken {
+                // Not to be used by end users
+                CancelTokenImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CancelTokenImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CancelToken,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CancelToken,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CancelTokenPtr,
+                );
+
+                /// Request cancellation of every batch observing this token.
+ void  cancel()=>RustLib.instance.api.crateApiBatchCancelTokenCancel(that: this, );
+
+
+/// Whether [`CancelToken::cancel`] has been called.
+ bool  isCancelled()=>RustLib.instance.api.crateApiBatchCancelTokenIsCancelled(that: this, );
+
+
+            }
+            @sealed class CommitteeHandleImpl extends RustOpaque implements CommitteeHandle {
+                // Not to be used by end users
+                CommitteeHandleImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CommitteeHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CommitteeHandle,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CommitteeHandle,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CommitteeHandlePtr,
+                );
+
+                /// Number of committee members.
+ int  size()=>RustLib.instance.api.crateApiCommitteeCommitteeHandleSize(that: this, );
+
+
+/// Sum of all members' voting weights.
+ BigInt  totalWeight()=>RustLib.instance.api.crateApiCommitteeCommitteeHandleTotalWeight(that: this, );
+
+
+/// Verify a certificate and require its signers to hold more than 2/3 of
+/// the committee's total voting weight.
+///
+/// * `bitmap`  – signer bitmap, in committee order
+/// * `msg`     – the message the signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+///
+/// Returns `false` when the quorum is not met, a bit is out of range, or
+/// the aggregate signature does not verify.
+ bool  verifyQuorum({required List<int> bitmap , required List<int> msg , required List<int> aggSig })=>RustLib.instance.api.crateApiCommitteeCommitteeHandleVerifyQuorum(that: this, bitmap: bitmap, msg: msg, aggSig: aggSig);
+
+
+            }
+            @sealed class CommitteeKeyImpl extends RustOpaque implements CommitteeKey {
+                // Not to be used by end users
+                CommitteeKeyImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CommitteeKeyImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CommitteeKey,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CommitteeKey,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CommitteeKeyPtr,
+                );
+
+                /// The 48-byte compressed aggregate public key.
+ Uint8List  publicKey()=>RustLib.instance.api.crateApiCommitteeCommitteeKeyPublicKey(that: this, );
+
+
+/// Verify an aggregate signature by the whole committee.
+///
+/// * `msg`           – the message every member signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Returns `true` when the aggregate signature is valid, `false`
+/// otherwise (including a malformed signature).
+ bool  verify({required List<int> msg , required List<int> aggSigBytes })=>RustLib.instance.api.crateApiCommitteeCommitteeKeyVerify(that: this, msg: msg, aggSigBytes: aggSigBytes);
+
+
+            }
+            @sealed class SessionKeyPairImpl extends RustOpaque implements SessionKeyPair {
+                // Not to be used by end users
+                SessionKeyPairImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SessionKeyPairImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SessionKeyPair,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SessionKeyPair,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SessionKeyPairPtr,
+                );
+
+                /// The 48-byte compressed G1 session public key.
+ Uint8List  publicKey()=>RustLib.instance.api.crateApiKeysSessionKeyPairPublicKey(that: this, );
+
+
+/// Sign `msg` with the session key, returning a 96-byte compressed G2
+/// signature that verifies under [`SessionKeyPair::public_key`].
+ Uint8List  sign({required List<int> msg })=>RustLib.instance.api.crateApiKeysSessionKeyPairSign(that: this, msg: msg);
+
+
+            }
+            @sealed class SignatureAggregatorImpl extends RustOpaque implements SignatureAggregator {
+                // Not to be used by end users
+                SignatureAggregatorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SignatureAggregatorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SignatureAggregator,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SignatureAggregator,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SignatureAggregatorPtr,
+                );
+
+                /// Fold one signature into the aggregate.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// The signature is group-checked first and the identity is refused, so
+/// one bad response can be dropped without spoiling the rest.
+///
+/// Returns `true` when the signature was added, `false` (leaving the
+/// aggregate unchanged) when it is malformed.
+ bool  add({required List<int> sigBytes })=>RustLib.instance.api.crateApiAggregatorSignatureAggregatorAdd(that: this, sigBytes: sigBytes);
+
+
+/// Number of signatures added so far.
+ int  count()=>RustLib.instance.api.crateApiAggregatorSignatureAggregatorCount(that: this, );
+
+
+/// The aggregate of every signature added so far.
+///
+/// The aggregator stays usable, so this can be called again after more
+/// signatures arrive.
+///
+/// Returns the 96-byte compressed aggregate, or an empty `Vec<u8>` when
+/// no signature has been added.
+ Uint8List  finalize()=>RustLib.instance.api.crateApiAggregatorSignatureAggregatorFinalize(that: this, );
+
+
+/// Serialize the aggregator's progress, e.g. to survive an app restart
+/// or move it to another isolate.
+///
+/// The layout is `u32_le(count) || aggregate`, where the aggregate is
+/// the 96-byte compressed signature and is absent while `count` is 0.
+///
+/// Returns the snapshot for [`SignatureAggregator::from_snapshot`].
+ Uint8List  snapshot()=>RustLib.instance.api.crateApiAggregatorSignatureAggregatorSnapshot(that: this, );
+
+
+            }
+            @sealed class SigningStatsImpl extends RustOpaque implements SigningStats {
+                // Not to be used by end users
+                SigningStatsImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SigningStatsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SigningStats,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SigningStats,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SigningStatsPtr,
+                );
+
+                /// Reset every bucket to zero.
+ void  clear()=>RustLib.instance.api.crateApiDiagnosticsSigningStatsClear(that: this, );
+
+
+/// Bucketed signing latency counts.
+///
+/// Bucket `i` counts signings under `[250, 500, 1000, 2000, 4000, 8000,
+/// 16000][i]` microseconds (and at least the previous bound); the last
+/// of the 8 buckets counts everything from 16 ms up.
+ Uint64List  histogram()=>RustLib.instance.api.crateApiDiagnosticsSigningStatsHistogram(that: this, );
+
+
+/// Sign `msg` and record how long it took.
+///
+/// * `sk`  – 32-byte secret key
+/// * `msg` – arbitrary-length message
+///
+/// Returns the 96-byte signature, or an empty `Vec<u8>` for a malformed
+/// key; failed signings are not recorded.
+ Uint8List  signTracked({required List<int> sk , required List<int> msg })=>RustLib.instance.api.crateApiDiagnosticsSigningStatsSignTracked(that: this, sk: sk, msg: msg);
+
+
+            }
+            @sealed class TimedCommitteeImpl extends RustOpaque implements TimedCommittee {
+                // Not to be used by end users
+                TimedCommitteeImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                TimedCommitteeImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_TimedCommittee,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_TimedCommittee,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_TimedCommitteePtr,
+                );
+
+                /// Verify a certificate whose signers must all hold a valid key at `now`.
+///
+/// * `bitmap`  – signer bitmap, in committee order
+/// * `msg`     – the message the signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+/// * `now`     – the time to check terms against, in the caller's units
+///
+/// Returns `false` when any signer's key is outside its term at `now`,
+/// a bit is out of range, or the aggregate signature does not verify.
+ bool  verify({required List<int> bitmap , required List<int> msg , required List<int> aggSig , required BigInt now })=>RustLib.instance.api.crateApiCommitteeTimedCommitteeVerify(that: this, bitmap: bitmap, msg: msg, aggSig: aggSig, now: now);
+
+
+            }
+            @sealed class VerifierKeyImpl extends RustOpaque implements VerifierKey {
+                // Not to be used by end users
+                VerifierKeyImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                VerifierKeyImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_VerifierKey,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_VerifierKey,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_VerifierKeyPtr,
+                );
+
+                /// The 48-byte compressed public key.
+ Uint8List  publicKey()=>RustLib.instance.api.crateApiVerifierVerifierKeyPublicKey(that: this, );
+
+
+/// Verify a signature by this key.
+///
+/// * `msg`       – arbitrary-length message
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including a malformed signature).
+ bool  verify({required List<int> msg , required List<int> sigBytes })=>RustLib.instance.api.crateApiVerifierVerifierKeyVerify(that: this, msg: msg, sigBytes: sigBytes);
+
+
+            }
\ No newline at end of file
@@ -3,111 +3,650 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/aggregator.dart';
+import 'api/batch.dart';
 import 'api/bls.dart';
+import 'api/bundle.dart';
+import 'api/cache.dart';
+import 'api/commitment.dart';
+import 'api/committee.dart';
+import 'api/diagnostics.dart';
+import 'api/encoding.dart';
+import 'api/keys.dart';
+import 'api/min_sig.dart';
+import 'api/prehash.dart';
+import 'api/rlp.dart';
 import 'api/simple.dart';
+import 'api/sui.dart';
+import 'api/threshold.dart';
+import 'api/verifier.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
 
-  @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancelTokenPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CommitteeHandlePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CommitteeKeyPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionKeyPairPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SignatureAggregatorPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SigningStatsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TimedCommitteePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VerifierKeyPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected CancelToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw);
+
+@protected CommitteeHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw);
+
+@protected CommitteeKey dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw);
+
+@protected SessionKeyPair dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw);
+
+@protected SignatureAggregator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw);
+
+@protected SigningStats dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw);
+
+@protected TimedCommittee dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw);
+
+@protected VerifierKey dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw);
+
+@protected SignatureAggregator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw);
+
+@protected SigningStats dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw);
+
+@protected CancelToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw);
+
+@protected CommitteeHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw);
+
+@protected CommitteeKey dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw);
+
+@protected SessionKeyPair dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw);
+
+@protected SignatureAggregator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw);
+
+@protected SigningStats dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw);
+
+@protected TimedCommittee dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw);
+
+@protected VerifierKey dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw);
+
+@protected CancelToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(dynamic raw);
+
+@protected CommitteeHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw);
+
+@protected CommitteeKey dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw);
+
+@protected SessionKeyPair dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw);
+
+@protected SignatureAggregator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw);
+
+@protected SigningStats dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(dynamic raw);
+
+@protected TimedCommittee dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw);
+
+@protected VerifierKey dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw);
+
+@protected RustStreamSink<VerifyEvent> dco_decode_StreamSink_verify_event_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected BlsError dco_decode_bls_error(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected CommitteeHandle dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw);
+
+@protected CommitteeKey dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw);
+
+@protected SessionKeyPair dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw);
+
+@protected SignatureAggregator dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw);
+
+@protected TimedCommittee dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw);
+
+@protected VerifierKey dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw);
+
+@protected BundleInfo dco_decode_box_autoadd_bundle_info(dynamic raw);
+
+@protected CanonicalCertificate dco_decode_box_autoadd_canonical_certificate(dynamic raw);
+
+@protected CommitteeSetup dco_decode_box_autoadd_committee_setup(dynamic raw);
+
+@protected IdentityImport dco_decode_box_autoadd_identity_import(dynamic raw);
+
+@protected BuildFeatures dco_decode_build_features(dynamic raw);
+
+@protected BundleInfo dco_decode_bundle_info(dynamic raw);
+
+@protected CanonicalCertificate dco_decode_canonical_certificate(dynamic raw);
+
+@protected CommitteeSetup dco_decode_committee_setup(dynamic raw);
+
+@protected CycleReport dco_decode_cycle_report(dynamic raw);
+
+@protected FallbackResult dco_decode_fallback_result(dynamic raw);
+
+@protected FullVerifyResult dco_decode_full_verify_result(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected IdentityImport dco_decode_identity_import(dynamic raw);
+
+@protected List<bool> dco_decode_list_bool(dynamic raw);
+
+@protected List<List<Uint8List>> dco_decode_list_list_list_prim_u_8_strict(dynamic raw);
+
+@protected List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(Uint8List,Uint8List,Uint8List)> dco_decode_list_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(Uint8List,BigInt,BigInt)> dco_decode_list_record_list_prim_u_8_strict_u_64_u_64(dynamic raw);
+
+@protected List<(int,Uint8List)> dco_decode_list_record_u_32_list_prim_u_8_strict(dynamic raw);
+
+@protected CommitteeHandle? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(dynamic raw);
+
+@protected CommitteeKey? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(dynamic raw);
+
+@protected SessionKeyPair? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(dynamic raw);
+
+@protected SignatureAggregator? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(dynamic raw);
+
+@protected TimedCommittee? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(dynamic raw);
+
+@protected VerifierKey? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(dynamic raw);
+
+@protected BundleInfo? dco_decode_opt_box_autoadd_bundle_info(dynamic raw);
+
+@protected CanonicalCertificate? dco_decode_opt_box_autoadd_canonical_certificate(dynamic raw);
+
+@protected CommitteeSetup? dco_decode_opt_box_autoadd_committee_setup(dynamic raw);
+
+@protected IdentityImport? dco_decode_opt_box_autoadd_identity_import(dynamic raw);
+
+@protected (List<Uint8List>,List<Uint8List>) dco_decode_record_list_list_prim_u_8_strict_list_list_prim_u_8_strict(dynamic raw);
+
+@protected (Uint8List,bool) dco_decode_record_list_prim_u_8_strict_bool(dynamic raw);
+
+@protected (Uint8List,Uint8List) dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw);
+
+@protected (Uint8List,Uint8List,Uint8List) dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw);
+
+@protected (Uint8List,BigInt,BigInt) dco_decode_record_list_prim_u_8_strict_u_64_u_64(dynamic raw);
+
+@protected (int,Uint8List) dco_decode_record_u_32_list_prim_u_8_strict(dynamic raw);
+
+@protected RepairedAggregate dco_decode_repaired_aggregate(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected VerifyCategory dco_decode_verify_category(dynamic raw);
+
+@protected VerifyEvent dco_decode_verify_event(dynamic raw);
+
+@protected VerifyOutcome dco_decode_verify_outcome(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected CancelToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer);
+
+@protected CommitteeHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer);
+
+@protected CommitteeKey sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer);
+
+@protected SessionKeyPair sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer);
+
+@protected SignatureAggregator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer);
+
+@protected SigningStats sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer);
+
+@protected TimedCommittee sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer);
+
+@protected VerifierKey sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer);
+
+@protected SignatureAggregator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer);
+
+@protected SigningStats sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer);
+
+@protected CancelToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer);
+
+@protected CommitteeHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer);
+
+@protected CommitteeKey sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer);
+
+@protected SessionKeyPair sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer);
+
+@protected SignatureAggregator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer);
+
+@protected SigningStats sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer);
+
+@protected TimedCommittee sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer);
+
+@protected VerifierKey sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer);
+
+@protected CancelToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(SseDeserializer deserializer);
+
+@protected CommitteeHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer);
+
+@protected CommitteeKey sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer);
+
+@protected SessionKeyPair sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer);
+
+@protected SignatureAggregator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer);
+
+@protected SigningStats sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SseDeserializer deserializer);
+
+@protected TimedCommittee sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer);
+
+@protected VerifierKey sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer);
+
+@protected RustStreamSink<VerifyEvent> sse_decode_StreamSink_verify_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected BlsError sse_decode_bls_error(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected CommitteeHandle sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer);
+
+@protected CommitteeKey sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer);
+
+@protected SessionKeyPair sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer);
+
+@protected SignatureAggregator sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer);
+
+@protected TimedCommittee sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer);
+
+@protected VerifierKey sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer);
+
+@protected BundleInfo sse_decode_box_autoadd_bundle_info(SseDeserializer deserializer);
+
+@protected CanonicalCertificate sse_decode_box_autoadd_canonical_certificate(SseDeserializer deserializer);
+
+@protected CommitteeSetup sse_decode_box_autoadd_committee_setup(SseDeserializer deserializer);
+
+@protected IdentityImport sse_decode_box_autoadd_identity_import(SseDeserializer deserializer);
+
+@protected BuildFeatures sse_decode_build_features(SseDeserializer deserializer);
+
+@protected BundleInfo sse_decode_bundle_info(SseDeserializer deserializer);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected CanonicalCertificate sse_decode_canonical_certificate(SseDeserializer deserializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected CommitteeSetup sse_decode_committee_setup(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected CycleReport sse_decode_cycle_report(SseDeserializer deserializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected FallbackResult sse_decode_fallback_result(SseDeserializer deserializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected FullVerifyResult sse_decode_full_verify_result(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected int sse_decode_i_32(SseDeserializer deserializer);
 
-  @protected
-  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
-    SseDeserializer deserializer,
-  );
+@protected IdentityImport sse_decode_identity_import(SseDeserializer deserializer);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected List<bool> sse_decode_list_bool(SseDeserializer deserializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected List<List<Uint8List>> sse_decode_list_list_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected List<Uint8List> sse_decode_list_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_list_prim_u_8_strict(
-    List<Uint8List> self,
-    SseSerializer serializer,
-  );
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+@protected List<(Uint8List,Uint8List,Uint8List)> sse_decode_list_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected List<(Uint8List,BigInt,BigInt)> sse_decode_list_record_list_prim_u_8_strict_u_64_u_64(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected List<(int,Uint8List)> sse_decode_list_record_u_32_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected CommitteeHandle? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(SseDeserializer deserializer);
+
+@protected CommitteeKey? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(SseDeserializer deserializer);
+
+@protected SessionKeyPair? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SseDeserializer deserializer);
+
+@protected SignatureAggregator? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SseDeserializer deserializer);
+
+@protected TimedCommittee? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(SseDeserializer deserializer);
+
+@protected VerifierKey? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(SseDeserializer deserializer);
+
+@protected BundleInfo? sse_decode_opt_box_autoadd_bundle_info(SseDeserializer deserializer);
+
+@protected CanonicalCertificate? sse_decode_opt_box_autoadd_canonical_certificate(SseDeserializer deserializer);
+
+@protected CommitteeSetup? sse_decode_opt_box_autoadd_committee_setup(SseDeserializer deserializer);
+
+@protected IdentityImport? sse_decode_opt_box_autoadd_identity_import(SseDeserializer deserializer);
+
+@protected (List<Uint8List>,List<Uint8List>) sse_decode_record_list_list_prim_u_8_strict_list_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected (Uint8List,bool) sse_decode_record_list_prim_u_8_strict_bool(SseDeserializer deserializer);
+
+@protected (Uint8List,Uint8List) sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected (Uint8List,Uint8List,Uint8List) sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected (Uint8List,BigInt,BigInt) sse_decode_record_list_prim_u_8_strict_u_64_u_64(SseDeserializer deserializer);
+
+@protected (int,Uint8List) sse_decode_record_u_32_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected RepairedAggregate sse_decode_repaired_aggregate(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected VerifyCategory sse_decode_verify_category(SseDeserializer deserializer);
+
+@protected VerifyEvent sse_decode_verify_event(SseDeserializer deserializer);
+
+@protected VerifyOutcome sse_decode_verify_outcome(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(CancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(SigningStats self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_verify_event_Sse(RustStreamSink<VerifyEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bls_error(BlsError self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bundle_info(BundleInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_canonical_certificate(CanonicalCertificate self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_committee_setup(CommitteeSetup self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_identity_import(IdentityImport self, SseSerializer serializer);
+
+@protected void sse_encode_build_features(BuildFeatures self, SseSerializer serializer);
+
+@protected void sse_encode_bundle_info(BundleInfo self, SseSerializer serializer);
+
+@protected void sse_encode_canonical_certificate(CanonicalCertificate self, SseSerializer serializer);
+
+@protected void sse_encode_committee_setup(CommitteeSetup self, SseSerializer serializer);
+
+@protected void sse_encode_cycle_report(CycleReport self, SseSerializer serializer);
+
+@protected void sse_encode_fallback_result(FallbackResult self, SseSerializer serializer);
+
+@protected void sse_encode_full_verify_result(FullVerifyResult self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_identity_import(IdentityImport self, SseSerializer serializer);
+
+@protected void sse_encode_list_bool(List<bool> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_list_prim_u_8_strict(List<List<Uint8List>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_prim_u_8_strict(List<Uint8List> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict(List<(Uint8List,Uint8List,Uint8List)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_list_prim_u_8_strict_u_64_u_64(List<(Uint8List,BigInt,BigInt)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_u_32_list_prim_u_8_strict(List<(int,Uint8List)> self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(CommitteeHandle? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(CommitteeKey? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(SessionKeyPair? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(SignatureAggregator? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(TimedCommittee? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(VerifierKey? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bundle_info(BundleInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_canonical_certificate(CanonicalCertificate? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_committee_setup(CommitteeSetup? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_identity_import(IdentityImport? self, SseSerializer serializer);
+
+@protected void sse_encode_record_list_list_prim_u_8_strict_list_list_prim_u_8_strict((List<Uint8List>,List<Uint8List>) self, SseSerializer serializer);
+
+@protected void sse_encode_record_list_prim_u_8_strict_bool((Uint8List,bool) self, SseSerializer serializer);
+
+@protected void sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict((Uint8List,Uint8List) self, SseSerializer serializer);
+
+@protected void sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict_list_prim_u_8_strict((Uint8List,Uint8List,Uint8List) self, SseSerializer serializer);
+
+@protected void sse_encode_record_list_prim_u_8_strict_u_64_u_64((Uint8List,BigInt,BigInt) self, SseSerializer serializer);
+
+@protected void sse_encode_record_u_32_list_prim_u_8_strict((int,Uint8List) self, SseSerializer serializer);
+
+@protected void sse_encode_repaired_aggregate(RepairedAggregate self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_verify_category(VerifyCategory self, SseSerializer serializer);
+
+@protected void sse_encode_verify_event(VerifyEvent self, SseSerializer serializer);
+
+@protected void sse_encode_verify_outcome(VerifyOutcome self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeHandle(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCommitteeKey(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionKeyPair(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignatureAggregator(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSigningStats(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimedCommittee(int ptr);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(int ptr);
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVerifierKey(int ptr);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,295 @@
+// Incremental aggregation for signatures that arrive one at a time, e.g.
+// storage node responses coming in over the network during Walrus blob
+// certification. Each signature is checked and folded in on arrival, so
+// nothing has to be re-aggregated when the next one shows up.
+
+use blst::min_pk::AggregateSignature;
+
+use crate::api::bls::{bls12381_min_pk_verify_aggregate, parse_valid_sig};
+
+/// Running aggregate of BLS12-381 min_pk signatures.
+#[flutter_rust_bridge::frb(opaque)]
+#[derive(Default)]
+pub struct SignatureAggregator {
+    agg: Option<AggregateSignature>,
+    count: u32,
+}
+
+impl SignatureAggregator {
+    /// Create an aggregator holding no signatures.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> SignatureAggregator {
+        SignatureAggregator::default()
+    }
+
+    /// Fold one signature into the aggregate.
+    ///
+    /// * `sig_bytes` – 96-byte compressed G2 signature
+    ///
+    /// The signature is group-checked first and the identity is refused, so
+    /// one bad response can be dropped without spoiling the rest.
+    ///
+    /// Returns `true` when the signature was added, `false` (leaving the
+    /// aggregate unchanged) when it is malformed.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn add(&mut self, sig_bytes: Vec<u8>) -> bool {
+        let sig = match parse_valid_sig(&sig_bytes) {
+            Some(s) => s,
+            None => return false,
+        };
+        match &mut self.agg {
+            // Already group-checked above.
+            Some(agg) => {
+                if agg.add_signature(&sig, false).is_err() {
+                    return false;
+                }
+            }
+            None => self.agg = Some(AggregateSignature::from_signature(&sig)),
+        }
+        self.count += 1;
+        true
+    }
+
+    /// Number of signatures added so far.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// The aggregate of every signature added so far.
+    ///
+    /// The aggregator stays usable, so this can be called again after more
+    /// signatures arrive.
+    ///
+    /// Returns the 96-byte compressed aggregate, or an empty `Vec<u8>` when
+    /// no signature has been added.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(&self) -> Vec<u8> {
+        match &self.agg {
+            Some(agg) => agg.to_signature().to_bytes().to_vec(),
+            None => vec![],
+        }
+    }
+
+    /// Serialize the aggregator's progress, e.g. to survive an app restart
+    /// or move it to another isolate.
+    ///
+    /// The layout is `u32_le(count) || aggregate`, where the aggregate is
+    /// the 96-byte compressed signature and is absent while `count` is 0.
+    ///
+    /// Returns the snapshot for [`SignatureAggregator::from_snapshot`].
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut out = self.count.to_le_bytes().to_vec();
+        out.extend_from_slice(&self.finalize());
+        out
+    }
+
+    /// Restore an aggregator from [`SignatureAggregator::snapshot`].
+    ///
+    /// The stored aggregate is group-checked like any added signature.
+    ///
+    /// Returns `None` when the snapshot is truncated, malformed, or its
+    /// count disagrees with whether an aggregate is present.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_snapshot(state: Vec<u8>) -> Option<SignatureAggregator> {
+        if state.len() < 4 {
+            return None;
+        }
+        let (count, agg) = state.split_at(4);
+        let count = u32::from_le_bytes(count.try_into().ok()?);
+        if count == 0 {
+            return agg.is_empty().then(SignatureAggregator::new);
+        }
+        let sig = parse_valid_sig(agg)?;
+        Some(SignatureAggregator {
+            agg: Some(AggregateSignature::from_signature(&sig)),
+            count,
+        })
+    }
+}
+
+/// Fold a newly arrived signer into a same-message aggregate and check
+/// that the quorum so far still verifies.
+///
+/// * `agg_sig_bytes` – 96-byte compressed aggregate of the signers so far,
+///   or empty before the first signer
+/// * `existing_pks`  – 48-byte compressed G1 keys of the signers so far,
+///   empty exactly when `agg_sig_bytes` is
+/// * `new_sig_bytes` – 96-byte compressed G2 signature of the new signer
+/// * `new_pk_bytes`  – 48-byte compressed G1 key of the new signer
+/// * `msg`           – the message every signer signed
+///
+/// Returns the 96-byte updated aggregate and whether it verifies against
+/// all keys including the new one, or `(empty, false)` when an input is
+/// malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_add_and_verify(
+    agg_sig_bytes: Vec<u8>,
+    existing_pks: Vec<Vec<u8>>,
+    new_sig_bytes: Vec<u8>,
+    new_pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+) -> (Vec<u8>, bool) {
+    if agg_sig_bytes.is_empty() != existing_pks.is_empty() {
+        return (vec![], false);
+    }
+    let mut aggregator = SignatureAggregator::new();
+    if !agg_sig_bytes.is_empty() && !aggregator.add(agg_sig_bytes) {
+        return (vec![], false);
+    }
+    if !aggregator.add(new_sig_bytes) {
+        return (vec![], false);
+    }
+    let agg = aggregator.finalize();
+
+    let mut pks = existing_pks;
+    pks.push(new_pk_bytes);
+    let valid = bls12381_min_pk_verify_aggregate(pks, msg, agg.clone());
+    (agg, valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bls::bls12381_min_pk_aggregate;
+    use crate::test_utils::{committee, sign_msg};
+
+    fn sigs(n: usize) -> Vec<Vec<u8>> {
+        committee("aggregator", n)
+            .iter()
+            .map(|(sk, _)| sign_msg(sk, b"incoming").to_bytes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn incremental_matches_batch() {
+        let sigs = sigs(3);
+        let mut aggregator = SignatureAggregator::new();
+        assert!(aggregator.finalize().is_empty());
+
+        for sig in &sigs {
+            assert!(aggregator.add(sig.clone()));
+        }
+        assert_eq!(aggregator.count(), 3);
+        let agg = aggregator.finalize();
+        assert_eq!(agg.len(), 96);
+        assert_eq!(agg, bls12381_min_pk_aggregate(sigs));
+    }
+
+    #[test]
+    fn finalize_between_arrivals() {
+        let sigs = sigs(2);
+        let mut aggregator = SignatureAggregator::new();
+        aggregator.add(sigs[0].clone());
+        assert_eq!(aggregator.finalize(), sigs[0]);
+        aggregator.add(sigs[1].clone());
+        assert_eq!(aggregator.finalize(), bls12381_min_pk_aggregate(sigs));
+    }
+
+    #[test]
+    fn snapshot_resumes_aggregation() {
+        let sigs = sigs(3);
+        let mut aggregator = SignatureAggregator::new();
+        aggregator.add(sigs[0].clone());
+        aggregator.add(sigs[1].clone());
+
+        let snapshot = aggregator.snapshot();
+        assert_eq!(snapshot.len(), 4 + 96);
+        let mut restored = SignatureAggregator::from_snapshot(snapshot).unwrap();
+        assert_eq!(restored.count(), 2);
+        assert!(restored.add(sigs[2].clone()));
+        assert_eq!(restored.count(), 3);
+        assert_eq!(restored.finalize(), bls12381_min_pk_aggregate(sigs));
+
+        let empty = SignatureAggregator::new().snapshot();
+        assert_eq!(empty, vec![0u8; 4]);
+        assert_eq!(
+            SignatureAggregator::from_snapshot(empty).unwrap().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn snapshot_rejects_malformed() {
+        let mut aggregator = SignatureAggregator::new();
+        aggregator.add(sigs(1)[0].clone());
+        let snapshot = aggregator.snapshot();
+
+        assert!(SignatureAggregator::from_snapshot(snapshot[..3].to_vec()).is_none());
+        assert!(SignatureAggregator::from_snapshot(snapshot[..99].to_vec()).is_none());
+        assert!(SignatureAggregator::from_snapshot(snapshot[..4].to_vec()).is_none());
+        let mut zero_count = snapshot.clone();
+        zero_count[..4].fill(0);
+        assert!(SignatureAggregator::from_snapshot(zero_count).is_none());
+        let mut corrupt = snapshot;
+        corrupt[4..].fill(0);
+        assert!(SignatureAggregator::from_snapshot(corrupt).is_none());
+    }
+
+    #[test]
+    fn add_and_verify_sequential_quorum() {
+        let keys = committee("aggregator", 3);
+        let msg = b"incoming".to_vec();
+        let mut agg = vec![];
+        let mut pks = vec![];
+        for (i, (sk, pk)) in keys.iter().enumerate() {
+            let sig = sign_msg(sk, &msg).to_bytes().to_vec();
+            let pk = pk.to_bytes().to_vec();
+            let (next, valid) =
+                bls12381_min_pk_add_and_verify(agg, pks.clone(), sig, pk.clone(), msg.clone());
+            assert!(valid, "signer {i}");
+            agg = next;
+            pks.push(pk);
+        }
+        assert_eq!(agg, bls12381_min_pk_aggregate(sigs(3)));
+
+        // A signer over another message is folded in but fails the check.
+        let (sk, pk) = &committee("aggregator-late", 1)[0];
+        let (next, valid) = bls12381_min_pk_add_and_verify(
+            agg.clone(),
+            pks.clone(),
+            sign_msg(sk, b"other").to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.clone(),
+        );
+        assert_eq!(next.len(), 96);
+        assert!(!valid);
+
+        let (sk, pk) = &keys[0];
+        let sig = sign_msg(sk, &msg).to_bytes().to_vec();
+        let pk = pk.to_bytes().to_vec();
+        assert_eq!(
+            bls12381_min_pk_add_and_verify(
+                agg.clone(),
+                pks.clone(),
+                vec![0u8; 96],
+                pk.clone(),
+                msg.clone()
+            ),
+            (vec![], false)
+        );
+        assert_eq!(
+            bls12381_min_pk_add_and_verify(vec![], pks, sig, pk, msg),
+            (vec![], false)
+        );
+    }
+
+    #[test]
+    fn malformed_signature_leaves_state() {
+        let sigs = sigs(2);
+        let mut aggregator = SignatureAggregator::new();
+        assert!(aggregator.add(sigs[0].clone()));
+
+        assert!(!aggregator.add(vec![0u8; 95]));
+        assert!(!aggregator.add(vec![0u8; 96]));
+        // The compressed identity decodes but is refused.
+        let mut identity = vec![0u8; 96];
+        identity[0] = 0xc0;
+        assert!(!aggregator.add(identity));
+
+        assert_eq!(aggregator.count(), 1);
+        assert!(aggregator.add(sigs[1].clone()));
+        assert_eq!(aggregator.finalize(), bls12381_min_pk_aggregate(sigs));
+    }
+}
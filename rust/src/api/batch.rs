@@ -0,0 +1,486 @@
+// Verification of many independent `(sig, pk, msg)` triples in one call.
+//
+// Parallel work runs on a rayon pool sized by `bls12381_set_verify_threads`,
+// kept between calls and rebuilt when the thread count changes.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use blst::blst_scalar;
+use blst::min_pk::{PublicKey, Signature};
+use blst::BLST_ERROR;
+
+use crate::api::bls::{is_identity_sig, parse_pks, verify_with_dst, DST};
+use crate::frb_generated::StreamSink;
+
+/// Bits of each random batch-verification coefficient.
+const RAND_BITS: usize = 64;
+
+/// Thread count for parallel verification; `0` means the default.
+static VERIFY_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// The pool parallel verification runs on, with the thread count it was
+/// built for.
+static VERIFY_POOL: Mutex<Option<(usize, Arc<ThreadPool>)>> = Mutex::new(None);
+
+/// Set once the default thread count has been halved for a slow build, see
+/// [`reduce_default_threads`].
+static REDUCED_DEFAULTS: AtomicBool = AtomicBool::new(false);
+
+/// Set how many threads parallel batch verification may use.
+///
+/// * `n` – maximum number of threads, or `0` to restore the default of one
+///   thread per physical core (half that on a portable blst build, once
+///   [`crate::api::diagnostics::bls12381_min_pk_verify_adaptive`] has run)
+///
+/// Low-end phones may want to cap this to avoid thermal throttling. The
+/// setting is global: the thread pool is rebuilt at the new size, and
+/// batches already running finish on the old one.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_set_verify_threads(n: u32) {
+    VERIFY_THREADS.store(n as usize, Ordering::Relaxed);
+    verify_pool();
+}
+
+/// The thread count parallel verification currently uses.
+pub(crate) fn verify_threads() -> usize {
+    match VERIFY_THREADS.load(Ordering::Relaxed) {
+        0 if REDUCED_DEFAULTS.load(Ordering::Relaxed) => (num_cpus::get_physical() / 2).max(1),
+        0 => num_cpus::get_physical().max(1),
+        n => n,
+    }
+}
+
+/// Halve the default thread count, keeping thermal load down where every
+/// verification already costs more. An explicit
+/// [`bls12381_set_verify_threads`] value still wins.
+pub(crate) fn reduce_default_threads() {
+    REDUCED_DEFAULTS.store(true, Ordering::Relaxed);
+}
+
+/// The rayon pool with [`verify_threads`] threads, rebuilt whenever that
+/// count changes. `None` when the pool's threads can't be spawned.
+pub(crate) fn verify_pool() -> Option<Arc<ThreadPool>> {
+    let threads = verify_threads();
+    let mut pool = VERIFY_POOL.lock().unwrap_or_else(|e| e.into_inner());
+    match &*pool {
+        Some((n, current)) if *n == threads => Some(current.clone()),
+        _ => {
+            let built = Arc::new(ThreadPoolBuilder::new().num_threads(threads).build().ok()?);
+            *pool = Some((threads, built.clone()));
+            Some(built)
+        }
+    }
+}
+
+/// Verify many independent signatures, spreading the work across threads.
+///
+/// * `pks_bytes`  – 48-byte compressed G1 public keys
+/// * `msgs`       – messages, one per key
+/// * `sigs_bytes` – 96-byte compressed G2 signatures, one per key
+///
+/// Each triple is verified on its own on the rayon pool, which has
+/// [`bls12381_set_verify_threads`] threads. When the pool can't be started
+/// the triples are verified on the calling thread instead.
+///
+/// Returns one result per triple, in input order, or an empty `Vec` when the
+/// three lists differ in length.
+pub fn bls12381_min_pk_batch_verify_parallel(
+    pks_bytes: Vec<Vec<u8>>,
+    msgs: Vec<Vec<u8>>,
+    sigs_bytes: Vec<Vec<u8>>,
+) -> Vec<bool> {
+    if pks_bytes.len() != msgs.len() || pks_bytes.len() != sigs_bytes.len() {
+        return vec![];
+    }
+    let verify =
+        |((pk, msg), sig): ((&Vec<u8>, &Vec<u8>), &Vec<u8>)| verify_with_dst(sig, pk, msg, DST);
+    match verify_pool() {
+        Some(pool) => pool.install(|| {
+            pks_bytes
+                .par_iter()
+                .zip(&msgs)
+                .zip(&sigs_bytes)
+                .map(verify)
+                .collect()
+        }),
+        None => pks_bytes
+            .iter()
+            .zip(&msgs)
+            .zip(&sigs_bytes)
+            .map(verify)
+            .collect(),
+    }
+}
+
+/// One random nonzero 64-bit coefficient per triple, drawn from the OS
+/// RNG so no signer can know theirs in advance. `None` when the entropy
+/// source fails.
+fn batch_coefficients(n: usize) -> Option<Vec<blst_scalar>> {
+    let mut rand = vec![0u8; n * RAND_BITS / 8];
+    getrandom::getrandom(&mut rand).ok()?;
+    let rands = rand
+        .chunks_exact(RAND_BITS / 8)
+        .map(|c| {
+            let mut b = [0u8; 32];
+            b[..RAND_BITS / 8].copy_from_slice(c);
+            b[0] |= 1;
+            blst_scalar { b }
+        })
+        .collect();
+    Some(rands)
+}
+
+/// Verify many independent signatures with a single final pairing check.
+///
+/// * `pks_bytes`  – 48-byte compressed G1 public keys
+/// * `msgs`       – messages, one per key
+/// * `sigs_bytes` – 96-byte compressed G2 signatures, one per key
+///
+/// Each triple is weighted by a random coefficient before the pairings are
+/// combined, so a bad signature cannot be cancelled out by another. This is
+/// much faster than verifying one by one, but only yields one verdict for
+/// the whole batch; use [`bls12381_min_pk_batch_verify_parallel`] to find
+/// the bad items.
+///
+/// Returns `true` only when every triple is valid. Returns `false` when the
+/// three lists differ in length or are empty, any input is malformed, or
+/// the OS entropy source fails.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_batch_verify(
+    pks_bytes: Vec<Vec<u8>>,
+    msgs: Vec<Vec<u8>>,
+    sigs_bytes: Vec<Vec<u8>>,
+) -> bool {
+    if pks_bytes.is_empty() || pks_bytes.len() != msgs.len() || pks_bytes.len() != sigs_bytes.len()
+    {
+        return false;
+    }
+    let pks = match parse_pks(&pks_bytes) {
+        Some(p) => p,
+        None => return false,
+    };
+    let sigs: Vec<Signature> = match sigs_bytes
+        .iter()
+        .map(|b| Signature::from_bytes(b))
+        .collect()
+    {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    if sigs.iter().any(is_identity_sig) {
+        return false;
+    }
+    let pk_refs: Vec<&PublicKey> = pks.iter().collect();
+    let sig_refs: Vec<&Signature> = sigs.iter().collect();
+    let msg_refs: Vec<&[u8]> = msgs.iter().map(|m| m.as_slice()).collect();
+    let Some(rands) = batch_coefficients(pks.len()) else {
+        return false;
+    };
+
+    Signature::verify_multiple_aggregate_signatures(
+        &msg_refs, DST, &pk_refs, true, &sig_refs, true, &rands, RAND_BITS,
+    ) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// A flag the caller can set from Dart to stop a long-running batch.
+#[flutter_rust_bridge::frb(opaque)]
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Create a token that is not yet cancelled.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Request cancellation of every batch observing this token.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`CancelToken::cancel`] has been called.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Verify a batch of signatures, stopping early if `cancel` is set.
+///
+/// * `sigs`   – 96-byte compressed G2 signatures
+/// * `pks`    – 48-byte compressed G1 public keys
+/// * `msgs`   – messages, one per signature
+/// * `cancel` – token checked before each item
+///
+/// Runs asynchronously so the app can call [`CancelToken::cancel`] while it
+/// is in progress, e.g. when the user navigates away.
+///
+/// Returns one result per triple, in input order; items not processed
+/// before cancellation are `false`. Returns an empty `Vec` when the three
+/// lists differ in length.
+pub fn bls12381_batch_verify_cancellable(
+    sigs: Vec<Vec<u8>>,
+    pks: Vec<Vec<u8>>,
+    msgs: Vec<Vec<u8>>,
+    cancel: &CancelToken,
+) -> Vec<bool> {
+    if sigs.len() != pks.len() || sigs.len() != msgs.len() {
+        return vec![];
+    }
+
+    let mut results = vec![false; sigs.len()];
+    for (i, result) in results.iter_mut().enumerate() {
+        if cancel.is_cancelled() {
+            break;
+        }
+        *result = verify_with_dst(&sigs[i], &pks[i], &msgs[i], DST);
+    }
+    results
+}
+
+/// Progress report for one item of a monitored batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyEvent {
+    /// Position of the item in the batch.
+    pub index: u32,
+    /// Whether its signature verified.
+    pub valid: bool,
+    /// Time spent verifying it, in microseconds.
+    pub elapsed_us: u64,
+}
+
+/// Verify a batch in order, reporting each item to `emit` as it finishes.
+fn verify_with_events(
+    sigs: &[Vec<u8>],
+    pks: &[Vec<u8>],
+    msgs: &[Vec<u8>],
+    mut emit: impl FnMut(VerifyEvent),
+) {
+    for (i, ((sig, pk), msg)) in sigs.iter().zip(pks).zip(msgs).enumerate() {
+        let start = Instant::now();
+        let valid = verify_with_dst(sig, pk, msg, DST);
+        emit(VerifyEvent {
+            index: i as u32,
+            valid,
+            elapsed_us: start.elapsed().as_micros() as u64,
+        });
+    }
+}
+
+/// Verify a batch while streaming one [`VerifyEvent`] per item to Dart.
+///
+/// * `sigs` – 96-byte compressed G2 signatures
+/// * `pks`  – 48-byte compressed G1 public keys, one per signature
+/// * `msgs` – messages, one per signature
+/// * `sink` – receives the events, in input order, as items complete
+///
+/// Items are verified one at a time on the calling worker. The stream
+/// closes when the batch is done; no events are sent when the three lists
+/// differ in length.
+pub fn bls12381_verify_with_events(
+    sigs: Vec<Vec<u8>>,
+    pks: Vec<Vec<u8>>,
+    msgs: Vec<Vec<u8>>,
+    sink: StreamSink<VerifyEvent>,
+) {
+    if sigs.len() != pks.len() || sigs.len() != msgs.len() {
+        return;
+    }
+    verify_with_events(&sigs, &pks, &msgs, |event| {
+        // A closed stream only means nobody is watching any more.
+        let _ = sink.add(event);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{committee, sign_msg};
+
+    /// Parallel `(sigs, pks, msgs)` lists.
+    type Triples = (Vec<Vec<u8>>, Vec<Vec<u8>>, Vec<Vec<u8>>);
+
+    /// `n` valid triples from distinct signers over distinct messages.
+    fn triples(n: usize) -> Triples {
+        let keys = committee("batch", n);
+        let msgs: Vec<Vec<u8>> = (0..n).map(|i| format!("msg {i}").into_bytes()).collect();
+        let sigs = keys
+            .iter()
+            .zip(&msgs)
+            .map(|((sk, _), m)| sign_msg(sk, m).to_bytes().to_vec())
+            .collect();
+        let pks = keys.iter().map(|(_, pk)| pk.to_bytes().to_vec()).collect();
+        (sigs, pks, msgs)
+    }
+
+    // ---- random-coefficient batch ----
+
+    #[test]
+    fn batch_verify_all_valid() {
+        let (sigs, pks, msgs) = triples(5);
+        assert!(bls12381_min_pk_batch_verify(pks, msgs, sigs));
+    }
+
+    #[test]
+    fn batch_verify_one_corrupted() {
+        let (mut sigs, pks, msgs) = triples(5);
+        sigs[3] = sigs[1].clone();
+        assert!(!bls12381_min_pk_batch_verify(pks, msgs, sigs));
+    }
+
+    #[test]
+    fn batch_verify_swapped_signatures() {
+        // Both signatures are valid, just not for their own triples.
+        let (mut sigs, pks, msgs) = triples(5);
+        sigs.swap(0, 4);
+        assert!(!bls12381_min_pk_batch_verify(pks, msgs, sigs));
+    }
+
+    #[test]
+    fn batch_verify_bad_shapes() {
+        let (sigs, pks, mut msgs) = triples(3);
+        msgs.pop();
+        assert!(!bls12381_min_pk_batch_verify(
+            pks.clone(),
+            msgs,
+            sigs.clone()
+        ));
+        assert!(!bls12381_min_pk_batch_verify(vec![], vec![], vec![]));
+
+        let (_, _, msgs) = triples(3);
+        let mut bad = sigs;
+        bad[0] = vec![0u8; 95];
+        assert!(!bls12381_min_pk_batch_verify(pks, msgs, bad));
+    }
+
+    #[test]
+    fn batch_coefficients_fresh_and_nonzero() {
+        let a = batch_coefficients(4).unwrap();
+        let b = batch_coefficients(4).unwrap();
+        assert_eq!(a.len(), 4);
+        assert!(a
+            .iter()
+            .all(|r| r.b[0] & 1 == 1 && r.b[RAND_BITS / 8..] == [0u8; 24]));
+        assert!(a.iter().zip(&b).any(|(x, y)| x.b != y.b));
+    }
+
+    // ---- cancellable ----
+
+    #[test]
+    fn cancellable_runs_to_completion() {
+        let (sigs, pks, mut msgs) = triples(4);
+        msgs[2] = b"tampered".to_vec();
+        let token = CancelToken::new();
+
+        assert_eq!(
+            bls12381_batch_verify_cancellable(sigs, pks, msgs, &token),
+            vec![true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn cancellable_stops_when_cancelled() {
+        let (sigs, pks, msgs) = triples(4);
+        let token = CancelToken::new();
+        token.cancel();
+        assert!(token.is_cancelled());
+
+        assert_eq!(
+            bls12381_batch_verify_cancellable(sigs, pks, msgs, &token),
+            vec![false; 4]
+        );
+    }
+
+    #[test]
+    fn cancellable_token_shared_across_clones() {
+        let token = CancelToken::new();
+        let other = token.clone();
+        other.cancel();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn cancellable_length_mismatch() {
+        let (sigs, pks, mut msgs) = triples(3);
+        msgs.pop();
+        assert!(bls12381_batch_verify_cancellable(sigs, pks, msgs, &CancelToken::new()).is_empty());
+    }
+
+    // ---- parallel ----
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let (sigs, pks, mut msgs) = triples(9);
+        msgs[4] = b"tampered".to_vec();
+        let expected: Vec<bool> = (0..9).map(|i| i != 4).collect();
+
+        assert_eq!(
+            bls12381_min_pk_batch_verify_parallel(pks, msgs, sigs),
+            expected
+        );
+    }
+
+    #[test]
+    fn parallel_exact_pattern() {
+        let (mut sigs, pks, mut msgs) = triples(8);
+        sigs[2] = sigs[3].clone();
+        msgs[5] = b"tampered".to_vec();
+
+        assert_eq!(
+            bls12381_min_pk_batch_verify_parallel(pks, msgs, sigs),
+            vec![true, true, false, true, true, false, true, true]
+        );
+    }
+
+    #[test]
+    fn parallel_single_thread() {
+        // Only this test changes the global setting; any value gives the
+        // same results, so concurrently running tests are unaffected.
+        bls12381_set_verify_threads(1);
+        assert_eq!(verify_threads(), 1);
+        assert_eq!(verify_pool().unwrap().current_num_threads(), 1);
+
+        let (sigs, pks, mut msgs) = triples(5);
+        msgs[0] = b"tampered".to_vec();
+        assert_eq!(
+            bls12381_min_pk_batch_verify_parallel(pks, msgs, sigs),
+            vec![false, true, true, true, true]
+        );
+
+        bls12381_set_verify_threads(0);
+        assert!(verify_threads() >= 1);
+    }
+
+    #[test]
+    fn parallel_length_mismatch() {
+        let (sigs, mut pks, msgs) = triples(3);
+        pks.pop();
+        assert!(bls12381_min_pk_batch_verify_parallel(pks, msgs, sigs).is_empty());
+    }
+
+    // ---- events ----
+
+    #[test]
+    fn verify_events_one_per_item() {
+        let (mut sigs, pks, msgs) = triples(4);
+        sigs[2] = sigs[1].clone();
+
+        let mut events = Vec::new();
+        verify_with_events(&sigs, &pks, &msgs, |e| events.push(e));
+
+        assert_eq!(events.len(), 4);
+        let indices: Vec<u32> = events.iter().map(|e| e.index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+        let valid: Vec<bool> = events.iter().map(|e| e.valid).collect();
+        assert_eq!(valid, vec![true, true, false, true]);
+    }
+}
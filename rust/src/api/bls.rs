@@ -8,13 +8,79 @@
 //   Public key: 48 bytes (compressed G1 point)
 //   Signature:  96 bytes (compressed G2 point)
 
-use blst::min_pk::{AggregateSignature, PublicKey, Signature};
-use blst::BLST_ERROR;
+use blst::min_pk::{AggregatePublicKey, AggregateSignature, PublicKey, SecretKey, Signature};
+use blst::{
+    blst_p1_affine, blst_p2, blst_p2_add_or_double_affine, blst_p2_affine, blst_p2_affine_is_inf,
+    blst_p2_cneg, blst_p2_from_affine, blst_p2_to_affine, Pairing, BLST_ERROR,
+};
+
+use crate::api::keys::SecretBytes;
 
 /// Domain Separation Tag for BLS12-381 min_pk (G2 signatures).
 /// This MUST match the DST used by Sui Move `bls12381_min_pk_verify` and
 /// `fastcrypto::bls12381::min_pk`, which is the IETF standard NUL scheme.
-const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+pub(crate) const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// DST for the message-augmentation (AUG) scheme, where every signer
+/// prepends its own compressed public key to the message.
+pub(crate) const DST_AUG: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_AUG_";
+
+/// DST for proofs of possession, which sign the signer's own compressed
+/// public key.
+pub(crate) const DST_POP: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Length of a compressed G1 public key.
+pub(crate) const PK_LEN: usize = 48;
+/// Length of a compressed G2 signature.
+pub(crate) const SIG_LEN: usize = 96;
+
+/// Outcome of a verification that can fail for more than one reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyOutcome {
+    /// The signature is valid and all policy checks passed.
+    Valid,
+    /// An input could not be parsed (wrong length, bad encoding, bit out of
+    /// range).
+    MalformedInput,
+    /// The inputs parsed but the signature does not verify.
+    InvalidSignature,
+    /// Too few committee members took part to form a quorum.
+    QuorumNotMet,
+    /// A committee member is marked as both signing and abstaining.
+    OverlappingBitmaps,
+    /// The message's nonce has already been used.
+    ReplayDetected,
+}
+
+/// Whether retrying a failed verification can help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyCategory {
+    /// The signature is valid.
+    Valid,
+    /// An input is malformed (wrong length, bad encoding, not a valid group
+    /// element); retrying with corrected input may succeed.
+    RecoverableBadInput,
+    /// The inputs are well-formed but the signature does not verify;
+    /// retrying with the same data will not help.
+    FatalInvalidSignature,
+}
+
+/// Why a checked operation failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlsError {
+    /// A public key is malformed, off the curve, outside G1 or the identity.
+    InvalidPublicKey,
+    /// A signature is malformed, off the curve, outside G2 or the identity.
+    InvalidSignature,
+    /// Input lists that must pair up differ in length.
+    InvalidLength,
+    /// The inputs are well-formed but the signature does not verify.
+    VerificationFailed,
+    /// A list that needs at least one element is empty.
+    EmptyInput,
+    /// Messages that must be pairwise distinct contain a repeat.
+    RepeatedMessage,
+}
 
 /// Verify a single BLS12-381 min_pk signature.
 ///
@@ -26,18 +92,150 @@ const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
 /// (including malformed inputs).
 #[flutter_rust_bridge::frb(sync)]
 pub fn bls12381_min_pk_verify(sig_bytes: Vec<u8>, pk_bytes: Vec<u8>, msg: Vec<u8>) -> bool {
-    let pk = match PublicKey::from_bytes(&pk_bytes) {
-        Ok(pk) => pk,
-        Err(_) => return false,
-    };
-    let sig = match Signature::from_bytes(&sig_bytes) {
-        Ok(sig) => sig,
-        Err(_) => return false,
-    };
-    // sig_groupcheck=true  → validate signature is in G2
-    // aug=&[]              → no augmentation (basic/NUL scheme)
-    // pk_validate=true     → validate public key is in G1
-    sig.verify(true, &msg, DST, &[], &pk, true) == BLST_ERROR::BLST_SUCCESS
+    verify_with_dst(&sig_bytes, &pk_bytes, &msg, DST)
+}
+
+/// Verify a single BLS12-381 min_pk signature under a caller-supplied DST.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+/// * `dst`       – domain separation tag the signer used
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including malformed inputs and an empty `dst`).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_with_dst(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+    dst: Vec<u8>,
+) -> bool {
+    !dst.is_empty() && verify_with_dst(&sig_bytes, &pk_bytes, &msg, &dst)
+}
+
+/// Verify a single BLS12-381 min_pk signature, reporting why it failed.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Returns `Ok(())` when the signature is valid,
+/// [`BlsError::InvalidPublicKey`] or [`BlsError::InvalidSignature`] for a
+/// bad point, and [`BlsError::VerificationFailed`] otherwise.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_checked(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+) -> Result<(), BlsError> {
+    verify_checked_with_dst(&sig_bytes, &pk_bytes, &msg, DST)
+}
+
+/// Check whether a signature is the identity (point at infinity) of G2.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// The identity never comes from an honest signer, and pairs to one with
+/// any key, which naive verifiers can be tricked by. The verify functions
+/// here reject it before pairing.
+///
+/// Returns `true` only for a well-formed encoding of the identity.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_is_identity_signature(sig_bytes: Vec<u8>) -> bool {
+    sig_bytes.len() == SIG_LEN
+        && Signature::from_bytes(&sig_bytes).is_ok_and(|sig| is_identity_sig(&sig))
+}
+
+/// Whether `sig` is the point at infinity.
+///
+/// Only looks at the coordinates; it runs in front of every verify, so it
+/// must not repeat the G2 subgroup check those already do.
+pub(crate) fn is_identity_sig(sig: &Signature) -> bool {
+    // SAFETY: the pointer refers to a valid, initialized point.
+    unsafe { blst_p2_affine_is_inf(<&blst_p2_affine>::from(sig)) }
+}
+
+/// Verify a single BLS12-381 min_pk signature, optionally skipping the
+/// subgroup checks.
+///
+/// * `sig_bytes`       – 96-byte compressed G2 signature
+/// * `pk_bytes`        – 48-byte compressed G1 public key
+/// * `msg`             – arbitrary-length message
+/// * `check_sig_group` – check that the signature lies in G2
+/// * `validate_pk`     – check that the key lies in G1 and isn't the identity
+///
+/// With both flags set this is [`bls12381_min_pk_verify`]. Turning a check
+/// off saves a subgroup check per call, but is only safe when that input
+/// was already validated, e.g. with [`bls12381_min_pk_validate_signature`]
+/// or [`bls12381_min_pk_validate_pubkey`]. On untrusted inputs it opens
+/// the door to small-subgroup and identity-key attacks.
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including malformed inputs).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_fast(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+    check_sig_group: bool,
+    validate_pk: bool,
+) -> bool {
+    verify_checked_with_flags(
+        &sig_bytes,
+        &pk_bytes,
+        &msg,
+        DST,
+        check_sig_group,
+        validate_pk,
+    )
+    .is_ok()
+}
+
+/// Single-signature verification under an explicit DST.
+pub(crate) fn verify_with_dst(sig_bytes: &[u8], pk_bytes: &[u8], msg: &[u8], dst: &[u8]) -> bool {
+    verify_checked_with_dst(sig_bytes, pk_bytes, msg, dst).is_ok()
+}
+
+/// Checked single-signature verification under an explicit DST.
+pub(crate) fn verify_checked_with_dst(
+    sig_bytes: &[u8],
+    pk_bytes: &[u8],
+    msg: &[u8],
+    dst: &[u8],
+) -> Result<(), BlsError> {
+    verify_checked_with_flags(sig_bytes, pk_bytes, msg, dst, true, true)
+}
+
+/// Checked single-signature verification with the subgroup checks of
+/// [`bls12381_min_pk_verify_fast`].
+fn verify_checked_with_flags(
+    sig_bytes: &[u8],
+    pk_bytes: &[u8],
+    msg: &[u8],
+    dst: &[u8],
+    check_sig_group: bool,
+    validate_pk: bool,
+) -> Result<(), BlsError> {
+    // Validate both points up front so a failure can be attributed; `verify`
+    // alone reports an identity key as a plain verification failure.
+    let pk = PublicKey::from_bytes(pk_bytes).map_err(|_| BlsError::InvalidPublicKey)?;
+    if validate_pk {
+        pk.validate().map_err(|_| BlsError::InvalidPublicKey)?;
+    }
+    let sig = Signature::from_bytes(sig_bytes).map_err(|_| BlsError::InvalidSignature)?;
+    if is_identity_sig(&sig) {
+        return Err(BlsError::InvalidSignature);
+    }
+    if check_sig_group {
+        sig.validate(false)
+            .map_err(|_| BlsError::InvalidSignature)?;
+    }
+    // aug=&[] → no augmentation (basic/NUL scheme); group checks done above.
+    match sig.verify(false, msg, dst, &[], &pk, false) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(BlsError::VerificationFailed),
+    }
 }
 
 /// Aggregate multiple BLS12-381 min_pk signatures into one.
@@ -48,24 +246,114 @@ pub fn bls12381_min_pk_verify(sig_bytes: Vec<u8>, pk_bytes: Vec<u8>, msg: Vec<u8
 /// (e.g. empty list, malformed signature).
 #[flutter_rust_bridge::frb(sync)]
 pub fn bls12381_min_pk_aggregate(sigs_bytes: Vec<Vec<u8>>) -> Vec<u8> {
+    bls12381_min_pk_aggregate_checked(sigs_bytes).unwrap_or_default()
+}
+
+/// Aggregate signatures like [`bls12381_min_pk_aggregate`], reporting why
+/// it failed.
+///
+/// * `sigs_bytes` – list of 96-byte compressed G2 signatures
+///
+/// Returns the 96-byte aggregate signature, [`BlsError::EmptyInput`] for an
+/// empty list, or [`BlsError::InvalidSignature`] for a bad signature.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_aggregate_checked(sigs_bytes: Vec<Vec<u8>>) -> Result<Vec<u8>, BlsError> {
     if sigs_bytes.is_empty() {
-        return vec![];
+        return Err(BlsError::EmptyInput);
     }
 
-    let sigs: Result<Vec<Signature>, _> = sigs_bytes
+    let sigs: Vec<Signature> = sigs_bytes
         .iter()
         .map(|b| Signature::from_bytes(b))
-        .collect();
+        .collect::<Result<_, _>>()
+        .map_err(|_| BlsError::InvalidSignature)?;
+
+    let sig_refs: Vec<&Signature> = sigs.iter().collect();
+
+    AggregateSignature::aggregate(&sig_refs, true)
+        .map(|agg| agg.to_signature().to_bytes().to_vec())
+        .map_err(|_| BlsError::InvalidSignature)
+}
+
+/// Merge aggregate signatures, e.g. partial aggregates from several shards,
+/// into one.
+///
+/// * `agg_sigs` – list of 96-byte compressed G2 aggregate signatures
+///
+/// An aggregate is itself a G2 point and aggregation is associative, so
+/// merging `[s1]` with `[s2, s3]` gives the same bytes as aggregating
+/// `[s1, s2, s3]` at once.
+///
+/// Returns the 96-byte merged aggregate, or an empty `Vec<u8>` on error
+/// (e.g. empty list, malformed aggregate).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_aggregate_aggregates(agg_sigs: Vec<Vec<u8>>) -> Vec<u8> {
+    bls12381_min_pk_aggregate(agg_sigs)
+}
 
-    let sigs = match sigs {
-        Ok(s) => s,
-        Err(_) => return vec![],
+/// Remove one signature from an aggregate, e.g. a signer later found
+/// invalid or slashed.
+///
+/// * `agg_sig_bytes` – 96-byte compressed G2 aggregate signature
+/// * `sig_to_remove` – 96-byte compressed G2 signature included in it
+///
+/// Subtracts the signature's point from the aggregate, so removing `s2`
+/// from the aggregate of `[s1, s2, s3]` gives the aggregate of `[s1, s3]`.
+/// Nothing checks that `sig_to_remove` was actually part of the aggregate;
+/// verify the result against the remaining keys. Removing the only
+/// signature leaves the identity, which never verifies.
+///
+/// Returns the 96-byte new aggregate, or an empty `Vec<u8>` when either
+/// input is malformed or outside G2.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_deaggregate(agg_sig_bytes: Vec<u8>, sig_to_remove: Vec<u8>) -> Vec<u8> {
+    let agg = match Signature::from_bytes(&agg_sig_bytes) {
+        Ok(a) if a.validate(false).is_ok() => a,
+        _ => return vec![],
+    };
+    let sig = match parse_valid_sig(&sig_to_remove) {
+        Some(s) => s,
+        None => return vec![],
     };
 
-    let sig_refs: Vec<&Signature> = sigs.iter().collect();
+    let mut neg = blst_p2::default();
+    let mut diff = blst_p2::default();
+    let mut out = blst_p2_affine::default();
+    // SAFETY: all pointers refer to valid, initialized points.
+    unsafe {
+        blst_p2_from_affine(&mut neg, &blst_p2_affine::from(sig));
+        blst_p2_cneg(&mut neg, true);
+        blst_p2_add_or_double_affine(&mut diff, &neg, &blst_p2_affine::from(agg));
+        blst_p2_to_affine(&mut out, &diff);
+    }
+    Signature::from(out).to_bytes().to_vec()
+}
+
+/// Aggregate BLS12-381 min_pk public keys into one.
+///
+/// * `pks_bytes` – list of 48-byte compressed G1 public keys
+///
+/// A same-message aggregate signature from these keys verifies against the
+/// result with [`bls12381_min_pk_verify`], so a fixed quorum's key can be
+/// computed once. Aggregation offers no rogue-key protection by itself;
+/// only combine keys whose possession was proven.
+///
+/// Returns the 48-byte aggregate public key, or an empty `Vec<u8>` on error
+/// (e.g. empty list, malformed or non-subgroup key).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_aggregate_pubkeys(pks_bytes: Vec<Vec<u8>>) -> Vec<u8> {
+    if pks_bytes.is_empty() {
+        return vec![];
+    }
 
-    match AggregateSignature::aggregate(&sig_refs, true) {
-        Ok(agg) => agg.to_signature().to_bytes().to_vec(),
+    let pks = match parse_pks(&pks_bytes) {
+        Some(p) => p,
+        None => return vec![],
+    };
+    let pk_refs: Vec<&PublicKey> = pks.iter().collect();
+
+    match AggregatePublicKey::aggregate(&pk_refs, true) {
+        Ok(agg) => agg.to_public_key().to_bytes().to_vec(),
         Err(_) => vec![],
     }
 }
@@ -84,274 +372,2395 @@ pub fn bls12381_min_pk_verify_aggregate(
     msg: Vec<u8>,
     agg_sig_bytes: Vec<u8>,
 ) -> bool {
+    bls12381_min_pk_verify_aggregate_checked(pks_bytes, msg, agg_sig_bytes).is_ok()
+}
+
+/// Verify a same-message aggregate like
+/// [`bls12381_min_pk_verify_aggregate`], reporting why it failed.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Returns `Ok(())` when the aggregate is valid, [`BlsError::EmptyInput`]
+/// for no keys, [`BlsError::InvalidPublicKey`] or
+/// [`BlsError::InvalidSignature`] for a malformed point, and
+/// [`BlsError::VerificationFailed`] otherwise.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aggregate_checked(
+    pks_bytes: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig_bytes: Vec<u8>,
+) -> Result<(), BlsError> {
+    verify_aggregate_checked_with_dst(&pks_bytes, &msg, &agg_sig_bytes, DST)
+}
+
+/// Verify a same-message aggregate under a caller-supplied DST.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+/// * `dst`           – domain separation tag the signers used
+///
+/// Returns `true` when the aggregate signature is valid, `false` otherwise
+/// (including an empty `dst`).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aggregate_with_dst(
+    pks_bytes: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig_bytes: Vec<u8>,
+    dst: Vec<u8>,
+) -> bool {
+    !dst.is_empty()
+        && verify_aggregate_checked_with_dst(&pks_bytes, &msg, &agg_sig_bytes, &dst).is_ok()
+}
+
+/// Checked same-message aggregate verification under an explicit DST.
+pub(crate) fn verify_aggregate_checked_with_dst(
+    pks_bytes: &[Vec<u8>],
+    msg: &[u8],
+    agg_sig_bytes: &[u8],
+    dst: &[u8],
+) -> Result<(), BlsError> {
     if pks_bytes.is_empty() {
+        return Err(BlsError::EmptyInput);
+    }
+    let pks = parse_pks(pks_bytes).ok_or(BlsError::InvalidPublicKey)?;
+    let pk_refs: Vec<&PublicKey> = pks.iter().collect();
+    let sig = Signature::from_bytes(agg_sig_bytes).map_err(|_| BlsError::InvalidSignature)?;
+    sig.validate(true).map_err(|_| BlsError::InvalidSignature)?;
+
+    match sig.fast_aggregate_verify(false, msg, dst, &pk_refs) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(BlsError::VerificationFailed),
+    }
+}
+
+/// Sign a message with a BLS12-381 min_pk secret key.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+/// * `msg`      – arbitrary-length message
+///
+/// Signs under the same DST the verify functions use, with no
+/// augmentation.
+///
+/// Returns the 96-byte compressed G2 signature, or an empty `Vec<u8>` when
+/// the secret key is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sign(sk_bytes: Vec<u8>, msg: Vec<u8>) -> Vec<u8> {
+    sign_with_dst(SecretBytes::new(sk_bytes).as_slice(), &msg, DST)
+}
+
+/// Sign a message under a caller-supplied DST.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+/// * `msg`      – arbitrary-length message
+/// * `dst`      – domain separation tag of the target deployment, e.g.
+///   Ethereum consensus' `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`
+///
+/// Returns the 96-byte compressed G2 signature, or an empty `Vec<u8>` when
+/// the secret key is malformed or `dst` is empty.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sign_with_dst(sk_bytes: Vec<u8>, msg: Vec<u8>, dst: Vec<u8>) -> Vec<u8> {
+    if dst.is_empty() {
+        return vec![];
+    }
+    sign_with_dst(SecretBytes::new(sk_bytes).as_slice(), &msg, &dst)
+}
+
+/// Sign `msg` under an explicit DST with a 32-byte secret key.
+///
+/// Returns the 96-byte signature, or an empty `Vec<u8>` for a malformed key.
+pub(crate) fn sign_with_dst(sk_bytes: &[u8], msg: &[u8], dst: &[u8]) -> Vec<u8> {
+    match SecretKey::from_bytes(sk_bytes) {
+        Ok(sk) => sk.sign(msg, dst, &[]).to_bytes().to_vec(),
+        Err(_) => vec![],
+    }
+}
+
+/// Check a public key once, e.g. when importing it into a key cache.
+///
+/// * `pk_bytes` – 48-byte compressed G1 public key
+///
+/// Runs the same checks verification does: the point must decode, lie on
+/// the curve and in G1, and not be the identity.
+///
+/// Returns `true` when the key is valid, `false` otherwise.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_validate_pubkey(pk_bytes: Vec<u8>) -> bool {
+    pk_bytes.len() == PK_LEN && PublicKey::key_validate(&pk_bytes).is_ok()
+}
+
+/// Check a signature before aggregating it.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// The point must decode, lie on the curve and in G2, and not be the
+/// identity, so a garbage response can be dropped on its own instead of
+/// spoiling an aggregate.
+///
+/// Returns `true` when the signature is a valid group element, `false`
+/// otherwise. Whether it verifies for any message is not checked.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_validate_signature(sig_bytes: Vec<u8>) -> bool {
+    parse_valid_sig(&sig_bytes).is_some()
+}
+
+/// Parse a compressed signature that is in G2 and not the identity.
+pub(crate) fn parse_valid_sig(sig_bytes: &[u8]) -> Option<Signature> {
+    if sig_bytes.len() != SIG_LEN {
+        return None;
+    }
+    let sig = Signature::from_bytes(sig_bytes).ok()?;
+    sig.validate(true).ok()?;
+    Some(sig)
+}
+
+/// Parse a list of compressed public keys, failing on the first malformed one.
+pub(crate) fn parse_pks(pks_bytes: &[Vec<u8>]) -> Option<Vec<PublicKey>> {
+    pks_bytes
+        .iter()
+        .map(|b| PublicKey::from_bytes(b).ok())
+        .collect()
+}
+
+/// Sum of already-parsed public keys, or `None` for an empty list.
+pub(crate) fn aggregate_pks(pks: &[&PublicKey]) -> Option<PublicKey> {
+    if pks.is_empty() {
+        return None;
+    }
+    AggregatePublicKey::aggregate(pks, false)
+        .ok()
+        .map(|agg| agg.to_public_key())
+}
+
+/// Same-message aggregate verification over already-parsed public keys.
+///
+/// Returns `false` for an empty key list or a malformed signature.
+pub(crate) fn fast_aggregate_verify(pks: &[&PublicKey], msg: &[u8], agg_sig_bytes: &[u8]) -> bool {
+    if pks.is_empty() {
+        return false;
+    }
+
+    let sig = match Signature::from_bytes(agg_sig_bytes) {
+        Ok(s) if !is_identity_sig(&s) => s,
+        _ => return false,
+    };
+
+    sig.fast_aggregate_verify(true, msg, DST, pks) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// Derive the chain-specific DST for `chain_id`.
+///
+/// The scheme is `DST || u64_be(chain_id)`: the standard NUL-scheme tag with
+/// the big-endian chain ID appended. Signatures made for one chain therefore
+/// never verify on another, which prevents cross-chain replay.
+///
+/// Returns the 51-byte DST.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_chain_dst(chain_id: u64) -> Vec<u8> {
+    let mut dst = Vec::with_capacity(DST.len() + 8);
+    dst.extend_from_slice(DST);
+    dst.extend_from_slice(&chain_id.to_be_bytes());
+    dst
+}
+
+/// Verify a single BLS12-381 min_pk signature under the DST of `chain_id`.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+/// * `chain_id`  – chain the signature was produced for
+///
+/// See [`bls12381_chain_dst`] for how the DST is derived.
+///
+/// Returns `true` when the signature is valid for that chain, `false`
+/// otherwise (including malformed inputs).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_chain(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+    chain_id: u64,
+) -> bool {
+    verify_with_dst(&sig_bytes, &pk_bytes, &msg, &bls12381_chain_dst(chain_id))
+}
+
+// Foreign signature encodings accepted by
+// `bls12381_normalize_foreign_signature`.
+const FOREIGN_PY_ECC: u8 = 0;
+const FOREIGN_ZCASH: u8 = 1;
+const FOREIGN_ETHEREUM: u8 = 2;
+
+/// Convert a signature produced by another BLS library into blst's canonical
+/// 96-byte compressed encoding.
+///
+/// * `sig_bytes` – the foreign signature bytes
+/// * `library`   – `0` = py_ecc, `1` = zcash, `2` = Ethereum consensus
+///
+/// All three build on the ZCash BLS12-381 serialization, but differ in what
+/// they emit: py_ecc and zcash tooling commonly hand out the 192-byte
+/// uncompressed point, while Ethereum mandates the 96-byte compressed form.
+/// The input is parsed in any form its library produces, subgroup-checked,
+/// then re-serialized compressed.
+///
+/// Returns the 96-byte canonical signature, or an empty `Vec<u8>` for an
+/// unsupported `library` or an invalid point.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_normalize_foreign_signature(sig_bytes: Vec<u8>, library: u8) -> Vec<u8> {
+    let accepts_uncompressed = match library {
+        FOREIGN_PY_ECC | FOREIGN_ZCASH => true,
+        FOREIGN_ETHEREUM => false,
+        _ => return vec![],
+    };
+    if sig_bytes.len() != SIG_LEN && !(accepts_uncompressed && sig_bytes.len() == 2 * SIG_LEN) {
+        return vec![];
+    }
+    // `from_bytes` dispatches on the compression flag of the first byte.
+    match Signature::from_bytes(&sig_bytes) {
+        Ok(sig) if sig.validate(false).is_ok() => sig.to_bytes().to_vec(),
+        _ => vec![],
+    }
+}
+
+/// Verify a single BLS12-381 min_pk signature, rejecting the zero message.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Some protocols reserve the all-zero message as a sentinel, so a message
+/// made entirely of zero bytes (including the empty message) is rejected
+/// before any verification is attempted.
+///
+/// Returns `true` when the message is non-zero and the signature is valid.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_nonzero(sig_bytes: Vec<u8>, pk_bytes: Vec<u8>, msg: Vec<u8>) -> bool {
+    if msg.iter().all(|&b| b == 0) {
+        return false;
+    }
+    bls12381_min_pk_verify(sig_bytes, pk_bytes, msg)
+}
+
+/// Sign a message under the AUG scheme with an explicit augmentation.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+/// * `msg`      – arbitrary-length message
+/// * `aug`      – bytes prepended to `msg` before hashing; the standard
+///   AUG scheme uses the signer's 48-byte compressed public key
+///
+/// With `aug` set to the signer's key, the result aggregates and verifies
+/// with [`bls12381_min_pk_verify_aggregate_aug`].
+///
+/// Returns the 96-byte compressed G2 signature, or an empty `Vec<u8>` when
+/// the secret key is malformed or `aug` is empty.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sign_aug(sk_bytes: Vec<u8>, msg: Vec<u8>, aug: Vec<u8>) -> Vec<u8> {
+    if aug.is_empty() {
+        return vec![];
+    }
+    match SecretBytes::new(sk_bytes).secret_key() {
+        Some(sk) => sk.sign(&msg, DST_AUG, &aug).to_bytes().to_vec(),
+        None => vec![],
+    }
+}
+
+/// Verify an AUG-scheme signature made with an explicit augmentation.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+/// * `aug`       – the augmentation the signer used
+///
+/// Returns `true` when the signature is valid over `aug || msg`, `false`
+/// otherwise (including malformed inputs, the identity signature and an
+/// empty `aug`).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aug(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+    aug: Vec<u8>,
+) -> bool {
+    if aug.is_empty() {
+        return false;
+    }
+    let (pk, sig) = match (
+        PublicKey::from_bytes(&pk_bytes),
+        Signature::from_bytes(&sig_bytes),
+    ) {
+        (Ok(pk), Ok(sig)) if !is_identity_sig(&sig) => (pk, sig),
+        _ => return false,
+    };
+    sig.verify(true, &msg, DST_AUG, &aug, &pk, true) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// Aggregate BLS12-381 min_pk signatures produced with message augmentation.
+///
+/// * `sigs` – list of 96-byte compressed G2 AUG-scheme signatures
+///
+/// Aggregation is the same point addition as for the basic scheme; this
+/// exists so callers pair it with [`bls12381_min_pk_verify_aggregate_aug`].
+///
+/// Returns the 96-byte aggregate signature, or an empty `Vec<u8>` on error.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_aggregate_aug(sigs: Vec<Vec<u8>>) -> Vec<u8> {
+    bls12381_min_pk_aggregate(sigs)
+}
+
+/// Verify an aggregate of AUG-scheme signatures where every signer signed
+/// the same message.
+///
+/// * `pks`     – list of 48-byte compressed G1 public keys
+/// * `msg`     – the message all signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+///
+/// Each signer actually signed `pk_i || msg` under the AUG DST, so the
+/// messages differ per signer and distinct-message aggregate verification
+/// is used instead of the same-message fast path.
+///
+/// Returns `true` when the aggregate signature is valid, `false` otherwise.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aggregate_aug(
+    pks: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    if pks.is_empty() {
         return false;
     }
+    let parsed = match parse_pks(&pks) {
+        Some(p) => p,
+        None => return false,
+    };
+
+    let aug_msgs: Vec<Vec<u8>> = parsed
+        .iter()
+        .map(|pk| [pk.to_bytes().as_slice(), &msg].concat())
+        .collect();
+    let msg_refs: Vec<&[u8]> = aug_msgs.iter().map(|m| m.as_slice()).collect();
+    let pk_refs: Vec<&PublicKey> = parsed.iter().collect();
+
+    aggregate_verify_with_dst(&pk_refs, &msg_refs, &agg_sig, DST_AUG)
+}
+
+/// Aggregate-verify `agg_sig_bytes` over one message per key under `dst`,
+/// with subgroup checks on the signature and every key.
+pub(crate) fn aggregate_verify_with_dst(
+    pks: &[&PublicKey],
+    msgs: &[&[u8]],
+    agg_sig_bytes: &[u8],
+    dst: &[u8],
+) -> bool {
+    if pks.is_empty() || pks.len() != msgs.len() {
+        return false;
+    }
+    let sig = match Signature::from_bytes(agg_sig_bytes) {
+        Ok(s) if !is_identity_sig(&s) => s,
+        _ => return false,
+    };
+    sig.aggregate_verify(true, msgs, dst, pks, true) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// Verify an aggregate BLS12-381 min_pk signature where each signer signed
+/// its own message.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msgs`          – one message per key, in the same order
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// As the basic scheme requires, the messages must be pairwise distinct;
+/// for a shared message use [`bls12381_min_pk_verify_aggregate`].
+///
+/// Returns `false` on a length mismatch, empty input, repeated messages, a
+/// malformed input, or an invalid aggregate.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_aggregate_verify(
+    pks_bytes: Vec<Vec<u8>>,
+    msgs: Vec<Vec<u8>>,
+    agg_sig_bytes: Vec<u8>,
+) -> bool {
+    bls12381_min_pk_aggregate_verify_checked(pks_bytes, msgs, agg_sig_bytes).is_ok()
+}
+
+/// Verify a distinct-message aggregate like
+/// [`bls12381_min_pk_aggregate_verify`], reporting why it failed.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `msgs`          – one message per key, in the same order
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// There is no limit on the number of signers beyond memory; each adds
+/// one Miller loop to the final pairing check.
+///
+/// Returns `Ok(())` when the aggregate is valid, [`BlsError::EmptyInput`]
+/// for no keys, [`BlsError::InvalidLength`] when the lists differ in
+/// length, [`BlsError::RepeatedMessage`] for a message used twice,
+/// [`BlsError::InvalidPublicKey`] or [`BlsError::InvalidSignature`] for a
+/// bad point, and [`BlsError::VerificationFailed`] otherwise.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_aggregate_verify_checked(
+    pks_bytes: Vec<Vec<u8>>,
+    msgs: Vec<Vec<u8>>,
+    agg_sig_bytes: Vec<u8>,
+) -> Result<(), BlsError> {
+    check_paired_lens(pks_bytes.len(), msgs.len())?;
+    if !messages_distinct(&msgs) {
+        return Err(BlsError::RepeatedMessage);
+    }
+    let pks = parse_valid_pks(&pks_bytes)?;
+    let sig = Signature::from_bytes(&agg_sig_bytes).map_err(|_| BlsError::InvalidSignature)?;
+    sig.validate(true).map_err(|_| BlsError::InvalidSignature)?;
+    let pk_refs: Vec<&PublicKey> = pks.iter().collect();
+    let msg_refs: Vec<&[u8]> = msgs.iter().map(|m| m.as_slice()).collect();
+
+    // Every point was validated above.
+    match sig.aggregate_verify(false, &msg_refs, DST, &pk_refs, false) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(BlsError::VerificationFailed),
+    }
+}
+
+/// Check that two index-aligned input lists are non-empty and pair up.
+fn check_paired_lens(a: usize, b: usize) -> Result<(), BlsError> {
+    if a != b {
+        Err(BlsError::InvalidLength)
+    } else if a == 0 {
+        Err(BlsError::EmptyInput)
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether no message appears twice in `msgs`.
+fn messages_distinct(msgs: &[Vec<u8>]) -> bool {
+    let mut sorted: Vec<&Vec<u8>> = msgs.iter().collect();
+    sorted.sort_unstable();
+    sorted.windows(2).all(|w| w[0] != w[1])
+}
+
+/// Parse a list of compressed public keys, requiring each to be in G1 and
+/// not the identity.
+fn parse_valid_pks(pks_bytes: &[Vec<u8>]) -> Result<Vec<PublicKey>, BlsError> {
+    pks_bytes
+        .iter()
+        .map(|b| PublicKey::key_validate(b).map_err(|_| BlsError::InvalidPublicKey))
+        .collect()
+}
+
+/// Verify an aggregate that mixes a shared-message group with signers of
+/// their own messages, in a single pairing check.
+///
+/// * `shared_pks`    – 48-byte compressed G1 public keys that signed
+///   `shared_msg`
+/// * `shared_msg`    – the message the shared group signed
+/// * `distinct_pks`  – 48-byte compressed G1 public keys of the other signers
+/// * `distinct_msgs` – one message per distinct key, in the same order
+/// * `agg_sig_bytes` – 96-byte compressed aggregate of every signature
+///
+/// The shared keys are summed into one term as in
+/// [`bls12381_min_pk_verify_aggregate`], so the whole group costs one
+/// Miller loop; each distinct signer adds one more, as in
+/// [`bls12381_min_pk_aggregate_verify`]. The distinct messages must be
+/// pairwise distinct and differ from `shared_msg`. With no shared keys this
+/// is [`bls12381_min_pk_aggregate_verify`].
+///
+/// Returns `true` when the aggregate is valid, `false` otherwise (including
+/// mismatched distinct list lengths, no signers, a repeated message, or a
+/// malformed input).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_mixed(
+    shared_pks: Vec<Vec<u8>>,
+    shared_msg: Vec<u8>,
+    distinct_pks: Vec<Vec<u8>>,
+    distinct_msgs: Vec<Vec<u8>>,
+    agg_sig_bytes: Vec<u8>,
+) -> bool {
+    if distinct_pks.len() != distinct_msgs.len() || !messages_distinct(&distinct_msgs) {
+        return false;
+    }
+    if !shared_pks.is_empty() && distinct_msgs.contains(&shared_msg) {
+        return false;
+    }
+    let (Ok(shared), Ok(distinct)) = (parse_valid_pks(&shared_pks), parse_valid_pks(&distinct_pks))
+    else {
+        return false;
+    };
+    let Some(sig) = parse_valid_sig(&agg_sig_bytes) else {
+        return false;
+    };
+    let shared_agg = aggregate_pks(&shared.iter().collect::<Vec<_>>());
+    let terms: Vec<(&PublicKey, &[u8])> = shared_agg
+        .iter()
+        .map(|pk| (pk, shared_msg.as_slice()))
+        .chain(
+            distinct
+                .iter()
+                .zip(distinct_msgs.iter().map(|m| m.as_slice())),
+        )
+        .collect();
+    if terms.is_empty() {
+        return false;
+    }
+
+    // Every point was validated above. The signature joins the first term;
+    // the others only add their key and message.
+    let mut pairing = Pairing::new(true, DST);
+    let mut sig_aff = Some(<&blst_p2_affine>::from(&sig));
+    for (pk, msg) in terms {
+        let pk_aff = <&blst_p1_affine>::from(pk);
+        let err = match sig_aff.take() {
+            Some(sig_aff) => pairing.aggregate(pk_aff, false, sig_aff, false, msg, &[]),
+            None => pairing.aggregate(pk_aff, false, &(), false, msg, &[]),
+        };
+        if err != BLST_ERROR::BLST_SUCCESS {
+            return false;
+        }
+    }
+    pairing.commit();
+    pairing.finalverify(None)
+}
+
+/// An aggregate rebuilt from only the signatures that verified.
+pub struct RepairedAggregate {
+    /// 96-byte aggregate of the valid signatures, empty if none were valid.
+    pub aggregate: Vec<u8>,
+    /// Input indices of the signatures that failed and were left out.
+    pub dropped: Vec<u32>,
+}
+
+/// Salvage a partially corrupt signature set into a valid aggregate.
+///
+/// * `sigs` – 96-byte compressed G2 signatures
+/// * `pks`  – 48-byte compressed G1 public keys, one per signature
+/// * `msg`  – the message every signer signed
+///
+/// Each signature is verified individually against its key; invalid ones
+/// are dropped and the rest re-aggregated.
+///
+/// Returns the repaired aggregate. When `sigs` and `pks` differ in length
+/// nothing is verified: the aggregate is empty and every index of `sigs` is
+/// dropped.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_repair_aggregate(
+    sigs: Vec<Vec<u8>>,
+    pks: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+) -> RepairedAggregate {
+    if sigs.len() != pks.len() {
+        return RepairedAggregate {
+            aggregate: vec![],
+            dropped: (0..sigs.len() as u32).collect(),
+        };
+    }
+
+    let mut valid = Vec::new();
+    let mut dropped = Vec::new();
+    for (i, (sig, pk)) in sigs.into_iter().zip(&pks).enumerate() {
+        if verify_with_dst(&sig, pk, &msg, DST) {
+            valid.push(sig);
+        } else {
+            dropped.push(i as u32);
+        }
+    }
+    RepairedAggregate {
+        aggregate: bls12381_min_pk_aggregate(valid),
+        dropped,
+    }
+}
+
+/// Verify an outer committee's attestation to an inner aggregate signature.
+///
+/// * `outer_pks`     – 48-byte compressed G1 public keys of the outer signers
+/// * `inner_agg_sig` – 96-byte compressed inner aggregate, the signed message
+/// * `outer_agg_sig` – 96-byte compressed outer aggregate G2 signature
+///
+/// The outer signers sign the compressed bytes of the inner aggregate, so
+/// chaining this check layer by layer certifies a multi-level attestation.
+/// The inner signature itself is not verified here.
+///
+/// Returns `false` when `inner_agg_sig` is not a compressed G2 point, or
+/// the outer aggregate does not verify over it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_nested(
+    outer_pks: Vec<Vec<u8>>,
+    inner_agg_sig: Vec<u8>,
+    outer_agg_sig: Vec<u8>,
+) -> bool {
+    if inner_agg_sig.len() != SIG_LEN || Signature::from_bytes(&inner_agg_sig).is_err() {
+        return false;
+    }
+    bls12381_min_pk_verify_aggregate(outer_pks, inner_agg_sig, outer_agg_sig)
+}
+
+/// Verify a single signature and classify any failure for retry logic.
+///
+/// * `sig` – 96-byte compressed G2 signature
+/// * `pk`  – 48-byte compressed G1 public key
+/// * `msg` – arbitrary-length message
+///
+/// Returns [`VerifyCategory::RecoverableBadInput`] when either point fails
+/// to decode, is off the curve or outside its subgroup, or the key is the
+/// identity, and [`VerifyCategory::FatalInvalidSignature`] when the
+/// signature is the identity or both points are valid but the pairing
+/// check fails.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_categorized(
+    sig: Vec<u8>,
+    pk: Vec<u8>,
+    msg: Vec<u8>,
+) -> VerifyCategory {
+    let (pk, sig) = match (PublicKey::from_bytes(&pk), Signature::from_bytes(&sig)) {
+        (Ok(pk), Ok(sig)) => (pk, sig),
+        _ => return VerifyCategory::RecoverableBadInput,
+    };
+    // `verify` reports an identity key as a plain verification failure.
+    if pk.validate().is_err() {
+        return VerifyCategory::RecoverableBadInput;
+    }
+    if is_identity_sig(&sig) {
+        return VerifyCategory::FatalInvalidSignature;
+    }
+    match sig.verify(true, &msg, DST, &[], &pk, true) {
+        BLST_ERROR::BLST_SUCCESS => VerifyCategory::Valid,
+        BLST_ERROR::BLST_VERIFY_FAIL => VerifyCategory::FatalInvalidSignature,
+        _ => VerifyCategory::RecoverableBadInput,
+    }
+}
+
+/// Verify a single signature and return blst's raw error code.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Meant for debugging failed verifications. Apart from `-1`, the codes are
+/// `BLST_ERROR` discriminants:
+///
+/// * `-1` – an input has the wrong length; nothing was parsed
+/// * `0`  – `BLST_SUCCESS`, the signature is valid
+/// * `1`  – `BLST_BAD_ENCODING`
+/// * `2`  – `BLST_POINT_NOT_ON_CURVE`
+/// * `3`  – `BLST_POINT_NOT_IN_GROUP`
+/// * `5`  – `BLST_VERIFY_FAIL`, the signature is the identity or the
+///   pairing check failed
+/// * `6`  – `BLST_PK_IS_INFINITY`
+///
+/// Decoding errors are reported for the public key first, then the
+/// signature; subgroup checks run as in [`bls12381_min_pk_verify`].
+///
+/// Returns the code.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_with_error(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+) -> i32 {
+    if sig_bytes.len() != SIG_LEN || pk_bytes.len() != PK_LEN {
+        return -1;
+    }
+    let pk = match PublicKey::from_bytes(&pk_bytes) {
+        Ok(pk) => pk,
+        Err(e) => return e as i32,
+    };
+    let sig = match Signature::from_bytes(&sig_bytes) {
+        Ok(sig) => sig,
+        Err(e) => return e as i32,
+    };
+    if is_identity_sig(&sig) {
+        return BLST_ERROR::BLST_VERIFY_FAIL as i32;
+    }
+    sig.verify(true, &msg, DST, &[], &pk, true) as i32
+}
+
+/// Aggregate only the signatures that verify individually.
+///
+/// * `sigs` – 96-byte compressed G2 signatures
+/// * `pks`  – 48-byte compressed G1 public keys, one per signature
+/// * `msg`  – the message every signer signed
+///
+/// Like [`bls12381_repair_aggregate`], but returns just the aggregate.
+///
+/// Returns the 96-byte aggregate of the valid signatures, or an empty
+/// `Vec<u8>` when none is valid or `sigs` and `pks` differ in length.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_aggregate_verified(
+    sigs: Vec<Vec<u8>>,
+    pks: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+) -> Vec<u8> {
+    bls12381_repair_aggregate(sigs, pks, msg).aggregate
+}
+
+/// Verify a signature over a nonce-suffixed message, rejecting reused
+/// nonces.
+///
+/// * `sig`         – 96-byte compressed G2 signature
+/// * `pk`          – 48-byte compressed G1 public key
+/// * `msg`         – the message, without the nonce
+/// * `nonce`       – nonce the signer appended to `msg`
+/// * `seen_nonces` – nonces already accepted in this epoch
+///
+/// The signed message is `msg || u64_be(nonce)`. Tracking `seen_nonces`
+/// is up to the caller.
+///
+/// Returns [`VerifyOutcome::ReplayDetected`] when `nonce` is in
+/// `seen_nonces` (checked before any cryptography),
+/// [`VerifyOutcome::MalformedInput`] when a point fails to decode, and
+/// [`VerifyOutcome::InvalidSignature`] when the signature does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_with_nonce(
+    sig: Vec<u8>,
+    pk: Vec<u8>,
+    msg: Vec<u8>,
+    nonce: u64,
+    seen_nonces: Vec<u64>,
+) -> VerifyOutcome {
+    if seen_nonces.contains(&nonce) {
+        return VerifyOutcome::ReplayDetected;
+    }
+    if PublicKey::from_bytes(&pk).is_err() || Signature::from_bytes(&sig).is_err() {
+        return VerifyOutcome::MalformedInput;
+    }
+    let mut signed = msg;
+    signed.extend_from_slice(&nonce.to_be_bytes());
+    if verify_with_dst(&sig, &pk, &signed, DST) {
+        VerifyOutcome::Valid
+    } else {
+        VerifyOutcome::InvalidSignature
+    }
+}
+
+/// Aggregate verdict plus the signers to blame when it fails.
+pub struct FallbackResult {
+    /// Whether the aggregate signature verified.
+    pub aggregate_valid: bool,
+    /// Indices of the signatures that failed individual verification; empty
+    /// when the aggregate verified or no individual signatures were given.
+    pub failing: Vec<u32>,
+}
+
+/// Verify an aggregate signature, falling back to per-signer checks to
+/// pinpoint the culprits when it fails.
+///
+/// * `pks`     – 48-byte compressed G1 public keys of the signers
+/// * `sigs`    – the individual 96-byte signatures, one per key, or empty
+/// * `msg`     – the shared message all signers signed
+/// * `agg_sig` – 96-byte compressed aggregate G2 signature
+///
+/// The fast aggregate check runs first; the individual signatures are only
+/// verified if it fails and `sigs` has one entry per key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_aggregate_with_fallback(
+    pks: Vec<Vec<u8>>,
+    sigs: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> FallbackResult {
+    if bls12381_min_pk_verify_aggregate(pks.clone(), msg.clone(), agg_sig) {
+        return FallbackResult {
+            aggregate_valid: true,
+            failing: vec![],
+        };
+    }
+    let failing = if sigs.len() == pks.len() {
+        (0..sigs.len())
+            .filter(|&i| !verify_with_dst(&sigs[i], &pks[i], &msg, DST))
+            .map(|i| i as u32)
+            .collect()
+    } else {
+        vec![]
+    };
+    FallbackResult {
+        aggregate_valid: false,
+        failing,
+    }
+}
+
+/// Prove possession of a BLS12-381 min_pk secret key.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+///
+/// Signs the compressed public key under the PoP DST. Registering keys only
+/// together with a valid proof rules out rogue-key attacks on same-message
+/// aggregation.
+///
+/// Returns the 96-byte proof, or an empty `Vec<u8>` when the secret key is
+/// malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_pop_prove(sk_bytes: Vec<u8>) -> Vec<u8> {
+    match SecretBytes::new(sk_bytes).secret_key() {
+        Some(sk) => sk
+            .sign(&sk.sk_to_pk().to_bytes(), DST_POP, &[])
+            .to_bytes()
+            .to_vec(),
+        None => vec![],
+    }
+}
+
+/// Verify a proof of possession from [`bls12381_min_pk_pop_prove`].
+///
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `pop_bytes` – 96-byte compressed G2 proof
+///
+/// Returns `true` when the proof is a signature over `pk_bytes` by the
+/// matching secret key, `false` otherwise (including malformed inputs).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_pop_verify(pk_bytes: Vec<u8>, pop_bytes: Vec<u8>) -> bool {
+    verify_with_dst(&pop_bytes, &pk_bytes, &pk_bytes, DST_POP)
+}
+
+/// Verify a same-message aggregate from signers who published proofs of
+/// possession.
+///
+/// * `pks_bytes`     – list of 48-byte compressed G1 public keys
+/// * `pops_bytes`    – proof of possession of each key, in the same order
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Every proof is checked with [`bls12381_min_pk_pop_verify`] before the
+/// aggregate is verified like [`bls12381_min_pk_verify_aggregate`], so a
+/// rogue key without a valid proof can't forge the aggregate.
+///
+/// Returns `true` when every proof and the aggregate signature are valid,
+/// `false` otherwise (including mismatched list lengths and no keys).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aggregate_pop(
+    pks_bytes: Vec<Vec<u8>>,
+    pops_bytes: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig_bytes: Vec<u8>,
+) -> bool {
+    if pks_bytes.len() != pops_bytes.len() {
+        return false;
+    }
+    let pops_ok = pks_bytes
+        .iter()
+        .zip(&pops_bytes)
+        .all(|(pk, pop)| verify_with_dst(pop, pk, pk, DST_POP));
+    pops_ok && bls12381_min_pk_verify_aggregate(pks_bytes, msg, agg_sig_bytes)
+}
+
+/// Look up the DST this library uses for a signature scheme.
+///
+/// * `scheme` – one of `"min_pk_nul"`, `"min_pk_aug"`, `"min_pk_pop"` or
+///   `"min_sig_nul"`
+///
+/// `"min_pk_pop"` gives the tag proofs of possession are signed under by
+/// [`bls12381_min_pk_pop_prove`]; messages in a PoP setup are still signed
+/// under `"min_pk_nul"`.
+///
+/// Returns the DST bytes, or an empty `Vec<u8>` for an unknown scheme.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_dst_for_scheme(scheme: String) -> Vec<u8> {
+    match scheme.as_str() {
+        "min_pk_nul" => DST,
+        "min_pk_aug" => DST_AUG,
+        "min_pk_pop" => DST_POP,
+        "min_sig_nul" => crate::api::min_sig::DST,
+        _ => &[],
+    }
+    .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{agg_sign, committee, keygen, pks_of, sign_msg};
+    use blst::min_pk::SecretKey;
+
+    // ---- verify ----
+
+    #[test]
+    fn verify_valid_signature() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-verification!!");
+        let msg = b"hello walrus";
+        let sig = sign_msg(&sk, msg);
+
+        assert!(bls12381_min_pk_verify(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verify_wrong_message() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-wrong-msg!!!!!");
+        let sig = sign_msg(&sk, b"correct message");
+
+        assert!(!bls12381_min_pk_verify(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            b"wrong message".to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verify_wrong_key() {
+        let (sk1, _) = keygen(b"test-seed-for-bls-wrong-key-1!!!");
+        let (_, pk2) = keygen(b"test-seed-for-bls-wrong-key-2!!!");
+        let msg = b"hello walrus";
+        let sig = sign_msg(&sk1, msg);
+
+        assert!(!bls12381_min_pk_verify(
+            sig.to_bytes().to_vec(),
+            pk2.to_bytes().to_vec(),
+            msg.to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verify_empty_inputs() {
+        assert!(!bls12381_min_pk_verify(vec![], vec![], vec![]));
+    }
+
+    #[test]
+    fn verify_wrong_size_signature() {
+        assert!(!bls12381_min_pk_verify(
+            vec![0u8; 48], // Should be 96
+            vec![0u8; 48],
+            vec![1, 2, 3],
+        ));
+    }
+
+    #[test]
+    fn verify_wrong_size_pubkey() {
+        assert!(!bls12381_min_pk_verify(
+            vec![0u8; 96],
+            vec![0u8; 32], // Should be 48
+            vec![1, 2, 3],
+        ));
+    }
+
+    // ---- identity signature ----
+
+    fn identity_sig() -> Vec<u8> {
+        let mut identity = vec![0u8; SIG_LEN];
+        identity[0] = 0xc0;
+        identity
+    }
+
+    #[test]
+    fn is_identity_signature() {
+        let (sk, _) = keygen(b"test-seed-for-bls-identity-sig!!");
+        assert!(bls12381_min_pk_is_identity_signature(identity_sig()));
+        assert!(!bls12381_min_pk_is_identity_signature(
+            sign_msg(&sk, b"normal").to_bytes().to_vec()
+        ));
+        assert!(!bls12381_min_pk_is_identity_signature(vec![0u8; SIG_LEN]));
+        assert!(!bls12381_min_pk_is_identity_signature(
+            identity_sig()[..SIG_LEN - 1].to_vec()
+        ));
+    }
+
+    #[test]
+    fn identity_signature_never_verifies() {
+        let (_, pk) = keygen(b"test-seed-for-bls-identity-sig!!");
+        let mut identity_pk = vec![0u8; PK_LEN];
+        identity_pk[0] = 0xc0;
+        let msg = b"anything".to_vec();
+
+        assert_eq!(
+            bls12381_min_pk_verify_checked(identity_sig(), pk.to_bytes().to_vec(), msg.clone()),
+            Err(BlsError::InvalidSignature)
+        );
+        for pk in [pk.to_bytes().to_vec(), identity_pk] {
+            assert!(!bls12381_min_pk_verify(
+                identity_sig(),
+                pk.clone(),
+                msg.clone()
+            ));
+            assert!(
+                bls12381_min_pk_verify_checked(identity_sig(), pk.clone(), msg.clone()).is_err()
+            );
+            // Not even with every optional check off.
+            assert!(!bls12381_min_pk_verify_fast(
+                identity_sig(),
+                pk.clone(),
+                msg.clone(),
+                false,
+                false
+            ));
+            assert!(!bls12381_min_pk_verify_aggregate(
+                vec![pk.clone()],
+                msg.clone(),
+                identity_sig()
+            ));
+            assert!(!bls12381_min_pk_verify_aug(
+                identity_sig(),
+                pk.clone(),
+                msg.clone(),
+                b"aug".to_vec()
+            ));
+            assert!(!bls12381_min_pk_aggregate_verify(
+                vec![pk],
+                vec![msg.clone()],
+                identity_sig()
+            ));
+        }
+
+        // The diagnostic variants report it as a failed signature.
+        let pk = pk.to_bytes().to_vec();
+        assert_eq!(
+            bls12381_min_pk_verify_categorized(identity_sig(), pk.clone(), msg.clone()),
+            VerifyCategory::FatalInvalidSignature
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_with_error(identity_sig(), pk, msg),
+            BLST_ERROR::BLST_VERIFY_FAIL as i32
+        );
+    }
+
+    // ---- verify fast ----
+
+    /// A compressed point that decodes (so lies on the curve) but fails
+    /// `is_valid`, i.e. lies outside the prime-order subgroup.
+    fn off_subgroup<T>(
+        len: usize,
+        decode: impl Fn(&[u8]) -> Option<T>,
+        is_valid: impl Fn(&T) -> bool,
+    ) -> Vec<u8> {
+        (0u32..)
+            .find_map(|i| {
+                let mut bytes = crate::hash::sha256(&i.to_be_bytes()).repeat(3);
+                bytes.truncate(len);
+                bytes[0] = 0x80 | (bytes[0] & 0x1f);
+                decode(&bytes).filter(|p| !is_valid(p)).map(|_| bytes)
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn verify_fast_agrees_on_honest_inputs() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-verify-fast!!!");
+        let pk = pk.to_bytes().to_vec();
+        let sig = sign_msg(&sk, b"fast").to_bytes().to_vec();
+
+        for (check, validate) in [(true, true), (true, false), (false, true), (false, false)] {
+            for msg in [b"fast".to_vec(), b"slow".to_vec()] {
+                assert_eq!(
+                    bls12381_min_pk_verify_fast(
+                        sig.clone(),
+                        pk.clone(),
+                        msg.clone(),
+                        check,
+                        validate
+                    ),
+                    bls12381_min_pk_verify(sig.clone(), pk.clone(), msg)
+                );
+            }
+        }
+        assert!(!bls12381_min_pk_verify_fast(
+            vec![0u8; 95],
+            pk,
+            b"fast".to_vec(),
+            false,
+            false
+        ));
+    }
+
+    #[test]
+    fn verify_fast_flags_skip_checks() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-verify-fast!!!");
+        let pk = pk.to_bytes().to_vec();
+        let sig = sign_msg(&sk, b"fast").to_bytes().to_vec();
+
+        let bad_sig = off_subgroup(
+            96,
+            |b| Signature::from_bytes(b).ok(),
+            |s| s.validate(true).is_ok(),
+        );
+        let bad_pk = off_subgroup(
+            48,
+            |b| PublicKey::from_bytes(b).ok(),
+            |p| p.validate().is_ok(),
+        );
+
+        // With a check on, the point is rejected before pairing; with it
+        // off, the point reaches the pairing and only fails there.
+        let check = |sig: &[u8], pk: &[u8], check_sig_group, validate_pk| {
+            verify_checked_with_flags(sig, pk, b"fast", DST, check_sig_group, validate_pk)
+        };
+        assert_eq!(
+            check(&bad_sig, &pk, true, true),
+            Err(BlsError::InvalidSignature)
+        );
+        assert_eq!(
+            check(&bad_sig, &pk, false, true),
+            Err(BlsError::VerificationFailed)
+        );
+        assert_eq!(
+            check(&sig, &bad_pk, true, true),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            check(&sig, &bad_pk, true, false),
+            Err(BlsError::VerificationFailed)
+        );
+    }
+
+    // ---- validate pubkey ----
+
+    #[test]
+    fn validate_pubkey() {
+        let (_, pk) = keygen(b"test-seed-for-bls-validate-pk!!!");
+        assert!(bls12381_min_pk_validate_pubkey(pk.to_bytes().to_vec()));
+
+        let mut identity = vec![0u8; PK_LEN];
+        identity[0] = 0xc0;
+        assert!(!bls12381_min_pk_validate_pubkey(identity));
+        assert!(!bls12381_min_pk_validate_pubkey(
+            pk.to_bytes()[..47].to_vec()
+        ));
+        // The uncompressed form is 96 bytes, not a compressed key.
+        assert!(!bls12381_min_pk_validate_pubkey(pk.serialize().to_vec()));
+    }
+
+    // ---- validate signature ----
+
+    #[test]
+    fn validate_signature() {
+        let (sk, _) = keygen(b"test-seed-for-bls-validate-sig!!");
+        let sig = sign_msg(&sk, b"any message").to_bytes().to_vec();
+        assert!(bls12381_min_pk_validate_signature(sig.clone()));
+
+        assert!(!bls12381_min_pk_validate_signature(vec![0u8; SIG_LEN]));
+        assert!(!bls12381_min_pk_validate_signature(
+            sig[..SIG_LEN - 1].to_vec()
+        ));
+        let mut identity = vec![0u8; SIG_LEN];
+        identity[0] = 0xc0;
+        assert!(!bls12381_min_pk_validate_signature(identity));
+    }
+
+    // ---- checked ----
+
+    #[test]
+    fn verify_checked_error_variants() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-checked-errors");
+        let msg = b"which part is wrong".to_vec();
+        let sig = sign_msg(&sk, &msg).to_bytes().to_vec();
+        let pk = pk.to_bytes().to_vec();
+
+        assert_eq!(
+            bls12381_min_pk_verify_checked(sig.clone(), pk.clone(), msg.clone()),
+            Ok(())
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_checked(sig.clone(), pk[..PK_LEN - 1].to_vec(), msg.clone()),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_checked(sig[..SIG_LEN - 1].to_vec(), pk.clone(), msg.clone()),
+            Err(BlsError::InvalidSignature)
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_checked(sig.clone(), pk, b"other message".to_vec()),
+            Err(BlsError::VerificationFailed)
+        );
+
+        let mut identity = vec![0u8; PK_LEN];
+        identity[0] = 0xc0;
+        assert_eq!(
+            bls12381_min_pk_verify_checked(sig, identity, msg),
+            Err(BlsError::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn aggregate_checked_error_variants() {
+        let keys = committee("checked", 3);
+        let msg = b"aggregate errors".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+
+        assert_eq!(
+            bls12381_min_pk_aggregate_checked(vec![]),
+            Err(BlsError::EmptyInput)
+        );
+        assert_eq!(
+            bls12381_min_pk_aggregate_checked(vec![vec![0u8; SIG_LEN - 1]]),
+            Err(BlsError::InvalidSignature)
+        );
+
+        assert_eq!(
+            bls12381_min_pk_verify_aggregate_checked(pks_of(&keys), msg.clone(), agg.clone()),
+            Ok(())
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_aggregate_checked(vec![], msg.clone(), agg.clone()),
+            Err(BlsError::EmptyInput)
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_aggregate_checked(
+                vec![vec![0u8; PK_LEN - 1]],
+                msg.clone(),
+                agg.clone()
+            ),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_aggregate_checked(pks_of(&keys), msg.clone(), vec![0u8; 3]),
+            Err(BlsError::InvalidSignature)
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_aggregate_checked(pks_of(&keys[..2]), msg, agg),
+            Err(BlsError::VerificationFailed)
+        );
+    }
+
+    // ---- sign ----
+
+    #[test]
+    fn sign_verify_round_trip() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-sign-roundtrip");
+        let msg = b"blob certification".to_vec();
+        let sig = bls12381_min_pk_sign(sk.to_bytes().to_vec(), msg.clone());
+
+        assert_eq!(sig.len(), 96);
+        assert_eq!(sig, sign_msg(&sk, &msg).to_bytes().to_vec());
+        assert!(bls12381_min_pk_verify(sig, pk.to_bytes().to_vec(), msg));
+    }
+
+    #[test]
+    fn sign_short_key_returns_empty() {
+        assert!(bls12381_min_pk_sign(vec![1u8; 16], b"msg".to_vec()).is_empty());
+    }
+
+    // ---- aggregate ----
+
+    #[test]
+    fn aggregate_single() {
+        let (sk, _) = keygen(b"test-seed-for-bls-aggregate-1!!!");
+        let sig = sign_msg(&sk, b"aggregate me");
+
+        let agg = bls12381_min_pk_aggregate(vec![sig.to_bytes().to_vec()]);
+        assert_eq!(agg.len(), 96);
+        assert_eq!(agg, sig.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn aggregate_multiple() {
+        let (sk1, _) = keygen(b"test-seed-for-bls-agg-multi-1!!!");
+        let (sk2, _) = keygen(b"test-seed-for-bls-agg-multi-2!!!");
+        let (sk3, _) = keygen(b"test-seed-for-bls-agg-multi-3!!!");
+        let msg = b"shared message";
+
+        let sig1 = sign_msg(&sk1, msg);
+        let sig2 = sign_msg(&sk2, msg);
+        let sig3 = sign_msg(&sk3, msg);
+
+        let agg = bls12381_min_pk_aggregate(vec![
+            sig1.to_bytes().to_vec(),
+            sig2.to_bytes().to_vec(),
+            sig3.to_bytes().to_vec(),
+        ]);
+        assert_eq!(agg.len(), 96);
+        assert_ne!(agg, sig1.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn aggregate_empty() {
+        assert!(bls12381_min_pk_aggregate(vec![]).is_empty());
+    }
+
+    #[test]
+    fn aggregate_malformed() {
+        assert!(bls12381_min_pk_aggregate(vec![vec![0u8; 10]]).is_empty());
+    }
+
+    #[test]
+    fn aggregate_aggregates_is_associative() {
+        let sigs: Vec<Vec<u8>> = (1..=3u8)
+            .map(|i| {
+                let (sk, _) = keygen(&[i; 32]);
+                sign_msg(&sk, b"sharded").to_bytes().to_vec()
+            })
+            .collect();
+
+        let left = bls12381_min_pk_aggregate(vec![sigs[0].clone()]);
+        let right = bls12381_min_pk_aggregate(sigs[1..].to_vec());
+        let merged = bls12381_min_pk_aggregate_aggregates(vec![left, right]);
+        assert_eq!(merged.len(), 96);
+        assert_eq!(merged, bls12381_min_pk_aggregate(sigs));
+
+        assert!(bls12381_min_pk_aggregate_aggregates(vec![]).is_empty());
+        assert!(bls12381_min_pk_aggregate_aggregates(vec![merged, vec![0u8; 96]]).is_empty());
+    }
+
+    #[test]
+    fn deaggregate_removes_one_signer() {
+        let sigs: Vec<Vec<u8>> = (1..=3u8)
+            .map(|i| {
+                let (sk, _) = keygen(&[i; 32]);
+                sign_msg(&sk, b"slashed").to_bytes().to_vec()
+            })
+            .collect();
+        let agg = bls12381_min_pk_aggregate(sigs.clone());
+
+        let without = bls12381_min_pk_deaggregate(agg.clone(), sigs[1].clone());
+        assert_eq!(
+            without,
+            bls12381_min_pk_aggregate(vec![sigs[0].clone(), sigs[2].clone()])
+        );
+        // Removing the rest one by one ends at the identity.
+        let single = bls12381_min_pk_deaggregate(without, sigs[0].clone());
+        assert_eq!(single, sigs[2]);
+        let identity = bls12381_min_pk_deaggregate(single, sigs[2].clone());
+        assert_eq!(identity[0], 0xc0);
+        assert!(identity[1..].iter().all(|&b| b == 0));
+
+        assert!(bls12381_min_pk_deaggregate(agg.clone(), vec![0u8; 96]).is_empty());
+        assert!(bls12381_min_pk_deaggregate(vec![0u8; 95], sigs[0].clone()).is_empty());
+    }
+
+    // ---- aggregate pubkeys ----
+
+    #[test]
+    fn aggregate_pubkeys_verifies_with_single_key() {
+        let keys = committee("agg-pks", 5);
+        let msg = b"one key for the quorum".to_vec();
+        let agg_pk = bls12381_min_pk_aggregate_pubkeys(pks_of(&keys));
+        assert_eq!(agg_pk.len(), PK_LEN);
+
+        let agg_sig = agg_sign(&keys, &[0, 1, 2, 3, 4], &msg);
+        assert!(bls12381_min_pk_verify(agg_sig.clone(), agg_pk, msg.clone()));
+
+        // Leaving one key out gives a different aggregate.
+        let partial = bls12381_min_pk_aggregate_pubkeys(pks_of(&keys[..4]));
+        assert!(!bls12381_min_pk_verify(agg_sig, partial, msg));
+    }
+
+    #[test]
+    fn aggregate_pubkeys_errors() {
+        assert!(bls12381_min_pk_aggregate_pubkeys(vec![]).is_empty());
+        assert!(bls12381_min_pk_aggregate_pubkeys(vec![vec![0u8; 47]]).is_empty());
+    }
+
+    // ---- verify aggregate ----
+
+    #[test]
+    fn verify_aggregate_valid() {
+        let (sk1, pk1) = keygen(b"test-agg-verify-valid-key-1!!!!!");
+        let (sk2, pk2) = keygen(b"test-agg-verify-valid-key-2!!!!!");
+        let (sk3, pk3) = keygen(b"test-agg-verify-valid-key-3!!!!!");
+        let msg = b"certify this blob";
+
+        let agg = bls12381_min_pk_aggregate(vec![
+            sign_msg(&sk1, msg).to_bytes().to_vec(),
+            sign_msg(&sk2, msg).to_bytes().to_vec(),
+            sign_msg(&sk3, msg).to_bytes().to_vec(),
+        ]);
+
+        assert!(bls12381_min_pk_verify_aggregate(
+            vec![
+                pk1.to_bytes().to_vec(),
+                pk2.to_bytes().to_vec(),
+                pk3.to_bytes().to_vec(),
+            ],
+            msg.to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_wrong_message() {
+        let (sk1, pk1) = keygen(b"test-agg-verify-wrong-msg-key1!!");
+        let (sk2, pk2) = keygen(b"test-agg-verify-wrong-msg-key2!!");
+        let msg = b"correct message";
+
+        let agg = bls12381_min_pk_aggregate(vec![
+            sign_msg(&sk1, msg).to_bytes().to_vec(),
+            sign_msg(&sk2, msg).to_bytes().to_vec(),
+        ]);
+
+        assert!(!bls12381_min_pk_verify_aggregate(
+            vec![pk1.to_bytes().to_vec(), pk2.to_bytes().to_vec()],
+            b"wrong message".to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_missing_signer() {
+        let (sk1, pk1) = keygen(b"test-agg-verify-missing-key-1!!!");
+        let (sk2, pk2) = keygen(b"test-agg-verify-missing-key-2!!!");
+        let (_, pk3) = keygen(b"test-agg-verify-missing-key-3!!!");
+        let msg = b"only two signed";
+
+        let agg = bls12381_min_pk_aggregate(vec![
+            sign_msg(&sk1, msg).to_bytes().to_vec(),
+            sign_msg(&sk2, msg).to_bytes().to_vec(),
+        ]);
+
+        // Verify with 3 keys but only 2 signed → should fail.
+        assert!(!bls12381_min_pk_verify_aggregate(
+            vec![
+                pk1.to_bytes().to_vec(),
+                pk2.to_bytes().to_vec(),
+                pk3.to_bytes().to_vec(),
+            ],
+            msg.to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_empty_keys() {
+        assert!(!bls12381_min_pk_verify_aggregate(
+            vec![],
+            b"msg".to_vec(),
+            vec![0u8; 96],
+        ));
+    }
+
+    // ---- aggregate verify (distinct messages) ----
+
+    fn distinct_setup() -> (Vec<Vec<u8>>, Vec<Vec<u8>>, Vec<u8>) {
+        let keys = [
+            keygen(b"test-distinct-msgs-signer-one!!!"),
+            keygen(b"test-distinct-msgs-signer-two!!!"),
+            keygen(b"test-distinct-msgs-signer-three!"),
+        ];
+        let msgs: Vec<Vec<u8>> = vec![b"alpha".to_vec(), b"beta".to_vec(), b"gamma".to_vec()];
+        let sigs: Vec<Vec<u8>> = keys
+            .iter()
+            .zip(&msgs)
+            .map(|((sk, _), m)| sign_msg(sk, m).to_bytes().to_vec())
+            .collect();
+        let pks = keys.iter().map(|(_, pk)| pk.to_bytes().to_vec()).collect();
+        (pks, msgs, bls12381_min_pk_aggregate(sigs))
+    }
+
+    #[test]
+    fn aggregate_verify_distinct_messages() {
+        let (pks, msgs, agg) = distinct_setup();
+        assert!(bls12381_min_pk_aggregate_verify(pks, msgs, agg));
+    }
+
+    #[test]
+    fn aggregate_verify_length_mismatch() {
+        let (pks, mut msgs, agg) = distinct_setup();
+        msgs.pop();
+        assert!(!bls12381_min_pk_aggregate_verify(pks, msgs, agg.clone()));
+        assert!(!bls12381_min_pk_aggregate_verify(vec![], vec![], agg));
+    }
+
+    #[test]
+    fn aggregate_verify_swapped_messages() {
+        let (pks, mut msgs, agg) = distinct_setup();
+        msgs.swap(0, 1);
+        assert!(!bls12381_min_pk_aggregate_verify(pks, msgs, agg));
+    }
+
+    #[test]
+    fn aggregate_verify_repeated_message() {
+        let (sk_a, pk_a) = keygen(b"test-distinct-msgs-signer-one!!!");
+        let (sk_b, pk_b) = keygen(b"test-distinct-msgs-signer-two!!!");
+        let msg = b"same".to_vec();
+        let agg = bls12381_min_pk_aggregate(vec![
+            sign_msg(&sk_a, &msg).to_bytes().to_vec(),
+            sign_msg(&sk_b, &msg).to_bytes().to_vec(),
+        ]);
+        assert!(!bls12381_min_pk_aggregate_verify(
+            vec![pk_a.to_bytes().to_vec(), pk_b.to_bytes().to_vec()],
+            vec![msg.clone(), msg],
+            agg,
+        ));
+    }
+
+    #[test]
+    fn aggregate_verify_checked_error_variants() {
+        let (pks, msgs, agg) = distinct_setup();
+        let check = |pks: Vec<Vec<u8>>, msgs: Vec<Vec<u8>>, agg: Vec<u8>| {
+            bls12381_min_pk_aggregate_verify_checked(pks, msgs, agg)
+        };
+
+        assert_eq!(check(pks.clone(), msgs.clone(), agg.clone()), Ok(()));
+        assert_eq!(
+            check(vec![], vec![], agg.clone()),
+            Err(BlsError::EmptyInput)
+        );
+        assert_eq!(
+            check(pks.clone(), msgs[..1].to_vec(), agg.clone()),
+            Err(BlsError::InvalidLength)
+        );
+        assert_eq!(
+            check(
+                pks.clone(),
+                vec![msgs[0].clone(), msgs[0].clone(), msgs[2].clone()],
+                agg.clone()
+            ),
+            Err(BlsError::RepeatedMessage)
+        );
+        assert_eq!(
+            check(
+                vec![pks[0].clone(), vec![0u8; PK_LEN], pks[2].clone()],
+                msgs.clone(),
+                agg.clone()
+            ),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            check(pks.clone(), msgs.clone(), vec![0u8; SIG_LEN]),
+            Err(BlsError::InvalidSignature)
+        );
+        assert_eq!(
+            check(
+                pks,
+                vec![msgs[1].clone(), msgs[0].clone(), msgs[2].clone()],
+                agg
+            ),
+            Err(BlsError::VerificationFailed)
+        );
+    }
+
+    // ---- mixed shared / distinct messages ----
+
+    /// Shared keys, distinct keys, distinct messages and the aggregate.
+    type Mixed = (Vec<Vec<u8>>, Vec<Vec<u8>>, Vec<Vec<u8>>, Vec<u8>);
+
+    /// Three signers on a shared message, two more on their own messages,
+    /// and the aggregate of all five signatures.
+    fn mixed_setup() -> Mixed {
+        let keys = committee("mixed", 5);
+        let pks = pks_of(&keys);
+        let distinct_msgs = vec![b"delta".to_vec(), b"epsilon".to_vec()];
+        let sigs: Vec<Vec<u8>> = (0..3)
+            .map(|i| sign_msg(&keys[i].0, b"shared").to_bytes().to_vec())
+            .chain(
+                distinct_msgs
+                    .iter()
+                    .zip(&keys[3..])
+                    .map(|(m, (sk, _))| sign_msg(sk, m).to_bytes().to_vec()),
+            )
+            .collect();
+        (
+            pks[..3].to_vec(),
+            pks[3..].to_vec(),
+            distinct_msgs,
+            bls12381_min_pk_aggregate(sigs),
+        )
+    }
+
+    #[test]
+    fn verify_mixed_shared_and_distinct() {
+        let (shared, distinct, msgs, agg) = mixed_setup();
+        assert!(bls12381_min_pk_verify_mixed(
+            shared.clone(),
+            b"shared".to_vec(),
+            distinct.clone(),
+            msgs.clone(),
+            agg.clone()
+        ));
+        // A shared signer moved into the distinct list still reuses the
+        // shared message, which the distinctness rule refuses.
+        let mut more_distinct = distinct;
+        more_distinct.insert(0, shared[2].clone());
+        let mut more_msgs = msgs;
+        more_msgs.insert(0, b"shared".to_vec());
+        assert!(!bls12381_min_pk_verify_mixed(
+            shared[..2].to_vec(),
+            b"shared".to_vec(),
+            more_distinct,
+            more_msgs,
+            agg
+        ));
+    }
+
+    #[test]
+    fn verify_mixed_rejects_bad_inputs() {
+        let (shared, distinct, msgs, agg) = mixed_setup();
+        let verify = |shared_msg: &[u8], msgs: Vec<Vec<u8>>, agg: Vec<u8>| {
+            bls12381_min_pk_verify_mixed(
+                shared.clone(),
+                shared_msg.to_vec(),
+                distinct.clone(),
+                msgs,
+                agg,
+            )
+        };
+
+        let mut corrupted = msgs.clone();
+        corrupted[1][0] ^= 0x01;
+        assert!(!verify(b"shared", corrupted, agg.clone()));
+        assert!(!verify(b"other", msgs.clone(), agg.clone()));
+        assert!(!verify(b"shared", msgs[..1].to_vec(), agg.clone()));
+        assert!(!verify(b"shared", msgs.clone(), vec![0u8; SIG_LEN]));
+        assert!(!verify(b"delta", msgs, agg.clone()));
+        assert!(!bls12381_min_pk_verify_mixed(
+            vec![],
+            b"shared".to_vec(),
+            vec![],
+            vec![],
+            agg
+        ));
+    }
+
+    #[test]
+    fn verify_mixed_without_shared_set() {
+        let (pks, msgs, agg) = distinct_setup();
+        assert!(bls12381_min_pk_verify_mixed(
+            vec![],
+            vec![],
+            pks.clone(),
+            msgs.clone(),
+            agg.clone()
+        ));
+        let mut swapped = msgs.clone();
+        swapped.swap(0, 2);
+        assert!(!bls12381_min_pk_verify_mixed(
+            vec![],
+            vec![],
+            pks.clone(),
+            swapped.clone(),
+            agg.clone()
+        ));
+        assert_eq!(
+            bls12381_min_pk_verify_mixed(vec![], vec![], pks.clone(), swapped.clone(), agg.clone()),
+            bls12381_min_pk_aggregate_verify(pks, swapped, agg)
+        );
+    }
+
+    // ---- end-to-end ----
+
+    #[test]
+    fn end_to_end_walrus_flow() {
+        // Simulates the Walrus blob certification flow:
+        // N storage nodes each sign the same serialized message,
+        // signatures are aggregated, then verified against signer public keys.
+
+        let seeds: [&[u8; 32]; 5] = [
+            b"walrus-node-0-secret-key-seed!!1",
+            b"walrus-node-1-secret-key-seed!!2",
+            b"walrus-node-2-secret-key-seed!!3",
+            b"walrus-node-3-secret-key-seed!!4",
+            b"walrus-node-4-secret-key-seed!!5",
+        ];
+
+        let keys: Vec<(SecretKey, PublicKey)> = seeds.iter().map(|s| keygen(s)).collect();
+        let blob_cert_msg = b"blob_cert_v1:blobid=abc123:epoch=42:size=1024";
+
+        // Quorum: nodes 0, 2, 4 respond
+        let responding = [0usize, 2, 4];
+        let sigs: Vec<Vec<u8>> = responding
+            .iter()
+            .map(|&i| sign_msg(&keys[i].0, blob_cert_msg).to_bytes().to_vec())
+            .collect();
+
+        let agg_sig = bls12381_min_pk_aggregate(sigs);
+        assert_eq!(agg_sig.len(), 96);
+
+        let responding_pks: Vec<Vec<u8>> = responding
+            .iter()
+            .map(|&i| keys[i].1.to_bytes().to_vec())
+            .collect();
+
+        assert!(bls12381_min_pk_verify_aggregate(
+            responding_pks,
+            blob_cert_msg.to_vec(),
+            agg_sig,
+        ));
+    }
+
+    #[test]
+    fn key_and_sig_sizes() {
+        let (sk, pk) = keygen(b"test-sizes-check-key-seed!!!!!!!");
+        let sig = sign_msg(&sk, b"size check");
+        assert_eq!(pk.to_bytes().len(), 48, "Public key should be 48 bytes");
+        assert_eq!(sig.to_bytes().len(), 96, "Signature should be 96 bytes");
+    }
+
+    // ---- chain DST ----
+
+    #[test]
+    fn chain_dst_layout() {
+        let dst = bls12381_chain_dst(0x0102);
+        assert_eq!(dst.len(), DST.len() + 8);
+        assert_eq!(&dst[..DST.len()], DST);
+        assert_eq!(&dst[DST.len()..], &[0, 0, 0, 0, 0, 0, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn verify_chain_valid() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-chain-dst!!!!!");
+        let msg = b"cross-chain message";
+        let sig = sk.sign(msg, &bls12381_chain_dst(1), &[]);
+
+        assert!(bls12381_min_pk_verify_chain(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.to_vec(),
+            1,
+        ));
+    }
+
+    #[test]
+    fn verify_chain_rejects_other_chain() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-chain-replay!!");
+        let msg = b"cross-chain message";
+        let sig = sk.sign(msg, &bls12381_chain_dst(1), &[]);
+
+        assert!(!bls12381_min_pk_verify_chain(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.to_vec(),
+            2,
+        ));
+        // Nor does it verify under the plain DST.
+        assert!(!bls12381_min_pk_verify(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.to_vec(),
+        ));
+    }
+
+    // ---- custom DST ----
+
+    const CUSTOM_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    #[test]
+    fn custom_dst_round_trip() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-custom-dst!!!!");
+        let msg = b"another deployment".to_vec();
+        let pk = pk.to_bytes().to_vec();
+        let sig =
+            bls12381_min_pk_sign_with_dst(sk.to_bytes().to_vec(), msg.clone(), CUSTOM_DST.to_vec());
+
+        assert!(bls12381_min_pk_verify_with_dst(
+            sig.clone(),
+            pk.clone(),
+            msg.clone(),
+            CUSTOM_DST.to_vec()
+        ));
+        assert!(bls12381_min_pk_verify_aggregate_with_dst(
+            vec![pk.clone()],
+            msg.clone(),
+            sig.clone(),
+            CUSTOM_DST.to_vec()
+        ));
+
+        // The default-DST functions are the same as passing `DST`.
+        let default_sig = bls12381_min_pk_sign(sk.to_bytes().to_vec(), msg.clone());
+        assert_eq!(
+            default_sig,
+            bls12381_min_pk_sign_with_dst(sk.to_bytes().to_vec(), msg.clone(), DST.to_vec())
+        );
+        assert!(bls12381_min_pk_verify_with_dst(
+            default_sig,
+            pk,
+            msg,
+            DST.to_vec()
+        ));
+    }
+
+    #[test]
+    fn custom_dst_mismatch_fails() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-custom-dst!!!!");
+        let msg = b"another deployment".to_vec();
+        let pk = pk.to_bytes().to_vec();
+        let sig =
+            bls12381_min_pk_sign_with_dst(sk.to_bytes().to_vec(), msg.clone(), CUSTOM_DST.to_vec());
+
+        assert!(!bls12381_min_pk_verify(
+            sig.clone(),
+            pk.clone(),
+            msg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_with_dst(
+            sig.clone(),
+            pk.clone(),
+            msg.clone(),
+            DST_AUG.to_vec()
+        ));
+        assert!(!bls12381_min_pk_verify_aggregate_with_dst(
+            vec![pk.clone()],
+            msg.clone(),
+            sig.clone(),
+            DST.to_vec()
+        ));
+        assert!(!bls12381_min_pk_verify_with_dst(
+            sig,
+            pk,
+            msg.clone(),
+            vec![]
+        ));
+        assert!(bls12381_min_pk_sign_with_dst(sk.to_bytes().to_vec(), msg, vec![]).is_empty());
+    }
 
-    let pks: Result<Vec<PublicKey>, _> =
-        pks_bytes.iter().map(|b| PublicKey::from_bytes(b)).collect();
-
-    let pks = match pks {
-        Ok(p) => p,
-        Err(_) => return false,
-    };
+    // ---- foreign signatures ----
 
-    let sig = match Signature::from_bytes(&agg_sig_bytes) {
-        Ok(s) => s,
-        Err(_) => return false,
-    };
+    #[test]
+    fn normalize_py_ecc_uncompressed() {
+        let (sk, _) = keygen(b"test-seed-for-bls-foreign-pyecc!");
+        let sig = sign_msg(&sk, b"interop");
+        let foreign = sig.serialize().to_vec();
+        assert_eq!(foreign.len(), 192);
 
-    let pk_refs: Vec<&PublicKey> = pks.iter().collect();
+        assert_eq!(
+            bls12381_normalize_foreign_signature(foreign, FOREIGN_PY_ECC),
+            sig.to_bytes().to_vec()
+        );
+    }
 
-    sig.fast_aggregate_verify(true, &msg, DST, &pk_refs) == BLST_ERROR::BLST_SUCCESS
-}
+    #[test]
+    fn normalize_compressed_is_identity() {
+        let (sk, _) = keygen(b"test-seed-for-bls-foreign-comp!!");
+        let sig = sign_msg(&sk, b"interop").to_bytes().to_vec();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use blst::min_pk::SecretKey;
+        for library in [FOREIGN_PY_ECC, FOREIGN_ZCASH, FOREIGN_ETHEREUM] {
+            assert_eq!(
+                bls12381_normalize_foreign_signature(sig.clone(), library),
+                sig
+            );
+        }
+    }
 
-    /// Helper: generate a key pair from a 32-byte seed.
-    fn keygen(seed: &[u8; 32]) -> (SecretKey, PublicKey) {
-        let sk = SecretKey::key_gen(seed, &[]).unwrap();
-        let pk = sk.sk_to_pk();
-        (sk, pk)
+    #[test]
+    fn normalize_ethereum_rejects_uncompressed() {
+        let (sk, _) = keygen(b"test-seed-for-bls-foreign-eth!!!");
+        let sig = sign_msg(&sk, b"interop").serialize().to_vec();
+        assert!(bls12381_normalize_foreign_signature(sig, FOREIGN_ETHEREUM).is_empty());
     }
 
-    /// Helper: sign a message using the hardcoded DST.
-    fn sign_msg(sk: &SecretKey, msg: &[u8]) -> Signature {
-        sk.sign(msg, DST, &[])
+    #[test]
+    fn normalize_unsupported_or_invalid() {
+        let (sk, _) = keygen(b"test-seed-for-bls-foreign-bad!!!");
+        let sig = sign_msg(&sk, b"interop").to_bytes().to_vec();
+        assert!(bls12381_normalize_foreign_signature(sig, 3).is_empty());
+        assert!(bls12381_normalize_foreign_signature(vec![0u8; 96], FOREIGN_ZCASH).is_empty());
+        assert!(bls12381_normalize_foreign_signature(vec![0u8; 50], FOREIGN_PY_ECC).is_empty());
     }
 
-    // ---- verify ----
+    // ---- non-zero message ----
 
     #[test]
-    fn verify_valid_signature() {
-        let (sk, pk) = keygen(b"test-seed-for-bls-verification!!");
-        let msg = b"hello walrus";
-        let sig = sign_msg(&sk, msg);
+    fn verify_nonzero_rejects_zero_message() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-zero-message!!");
+        let msg = [0u8; 32];
+        let sig = sign_msg(&sk, &msg);
 
+        // The plain verifier accepts it, the opt-in variant does not.
         assert!(bls12381_min_pk_verify(
             sig.to_bytes().to_vec(),
             pk.to_bytes().to_vec(),
             msg.to_vec(),
         ));
+        assert!(!bls12381_min_pk_verify_nonzero(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.to_vec(),
+        ));
     }
 
     #[test]
-    fn verify_wrong_message() {
-        let (sk, pk) = keygen(b"test-seed-for-bls-wrong-msg!!!!!");
-        let sig = sign_msg(&sk, b"correct message");
-
-        assert!(!bls12381_min_pk_verify(
+    fn verify_nonzero_rejects_empty_message() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-empty-message!");
+        let sig = sign_msg(&sk, &[]);
+        assert!(!bls12381_min_pk_verify_nonzero(
             sig.to_bytes().to_vec(),
             pk.to_bytes().to_vec(),
-            b"wrong message".to_vec(),
+            vec![],
         ));
     }
 
     #[test]
-    fn verify_wrong_key() {
-        let (sk1, _) = keygen(b"test-seed-for-bls-wrong-key-1!!!");
-        let (_, pk2) = keygen(b"test-seed-for-bls-wrong-key-2!!!");
-        let msg = b"hello walrus";
-        let sig = sign_msg(&sk1, msg);
-
-        assert!(!bls12381_min_pk_verify(
+    fn verify_nonzero_accepts_mixed_message() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-mixed-message!");
+        let msg = [0, 0, 0, 1, 0, 0];
+        let sig = sign_msg(&sk, &msg);
+        assert!(bls12381_min_pk_verify_nonzero(
             sig.to_bytes().to_vec(),
-            pk2.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
             msg.to_vec(),
         ));
     }
 
-    #[test]
-    fn verify_empty_inputs() {
-        assert!(!bls12381_min_pk_verify(vec![], vec![], vec![]));
-    }
+    // ---- aggregate aug ----
 
     #[test]
-    fn verify_wrong_size_signature() {
-        assert!(!bls12381_min_pk_verify(
-            vec![0u8; 48], // Should be 96
-            vec![0u8; 48],
-            vec![1, 2, 3],
+    fn aggregate_aug_round_trip() {
+        let keys = [
+            keygen(b"test-agg-aug-round-trip-key-1!!!"),
+            keygen(b"test-agg-aug-round-trip-key-2!!!"),
+            keygen(b"test-agg-aug-round-trip-key-3!!!"),
+        ];
+        let msg = b"augmented";
+
+        let sigs: Vec<Vec<u8>> = keys
+            .iter()
+            .map(|(sk, pk)| sk.sign(msg, DST_AUG, &pk.to_bytes()).to_bytes().to_vec())
+            .collect();
+        let pks: Vec<Vec<u8>> = keys.iter().map(|(_, pk)| pk.to_bytes().to_vec()).collect();
+
+        let agg = bls12381_min_pk_aggregate_aug(sigs);
+        assert_eq!(agg.len(), 96);
+        assert!(bls12381_min_pk_verify_aggregate_aug(
+            pks.clone(),
+            msg.to_vec(),
+            agg.clone(),
+        ));
+
+        assert!(!bls12381_min_pk_verify_aggregate_aug(
+            pks.clone(),
+            b"other".to_vec(),
+            agg.clone(),
         ));
+        // Not valid as a basic-scheme aggregate either.
+        assert!(!bls12381_min_pk_verify_aggregate(pks, msg.to_vec(), agg));
     }
 
     #[test]
-    fn verify_wrong_size_pubkey() {
-        assert!(!bls12381_min_pk_verify(
+    fn verify_aggregate_aug_empty_keys() {
+        assert!(!bls12381_min_pk_verify_aggregate_aug(
+            vec![],
+            b"msg".to_vec(),
             vec![0u8; 96],
-            vec![0u8; 32], // Should be 48
-            vec![1, 2, 3],
         ));
     }
 
-    // ---- aggregate ----
+    // ---- explicit augmentation ----
 
     #[test]
-    fn aggregate_single() {
-        let (sk, _) = keygen(b"test-seed-for-bls-aggregate-1!!!");
-        let sig = sign_msg(&sk, b"aggregate me");
+    fn sign_aug_round_trip() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-explicit-aug!!");
+        let msg = b"augmented".to_vec();
+        let aug = b"context: walrus".to_vec();
+        let sig = bls12381_min_pk_sign_aug(sk.to_bytes().to_vec(), msg.clone(), aug.clone());
+        assert_eq!(sig.len(), SIG_LEN);
 
-        let agg = bls12381_min_pk_aggregate(vec![sig.to_bytes().to_vec()]);
-        assert_eq!(agg.len(), 96);
-        assert_eq!(agg, sig.to_bytes().to_vec());
+        assert!(bls12381_min_pk_verify_aug(
+            sig.clone(),
+            pk.to_bytes().to_vec(),
+            msg.clone(),
+            aug.clone()
+        ));
+        // Same as signing `aug || msg` under the AUG DST.
+        assert!(verify_with_dst(
+            &sig,
+            &pk.to_bytes(),
+            &[aug, msg].concat(),
+            DST_AUG
+        ));
     }
 
     #[test]
-    fn aggregate_multiple() {
-        let (sk1, _) = keygen(b"test-seed-for-bls-agg-multi-1!!!");
-        let (sk2, _) = keygen(b"test-seed-for-bls-agg-multi-2!!!");
-        let (sk3, _) = keygen(b"test-seed-for-bls-agg-multi-3!!!");
-        let msg = b"shared message";
-
-        let sig1 = sign_msg(&sk1, msg);
-        let sig2 = sign_msg(&sk2, msg);
-        let sig3 = sign_msg(&sk3, msg);
+    fn sign_aug_wrong_augmentation() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-explicit-aug!!");
+        let msg = b"augmented".to_vec();
+        let sig = bls12381_min_pk_sign_aug(sk.to_bytes().to_vec(), msg.clone(), b"one".to_vec());
 
-        let agg = bls12381_min_pk_aggregate(vec![
-            sig1.to_bytes().to_vec(),
-            sig2.to_bytes().to_vec(),
-            sig3.to_bytes().to_vec(),
-        ]);
-        assert_eq!(agg.len(), 96);
-        assert_ne!(agg, sig1.to_bytes().to_vec());
+        assert!(!bls12381_min_pk_verify_aug(
+            sig.clone(),
+            pk.to_bytes().to_vec(),
+            msg.clone(),
+            b"two".to_vec()
+        ));
+        assert!(!bls12381_min_pk_verify_aug(
+            sig,
+            pk.to_bytes().to_vec(),
+            msg.clone(),
+            vec![]
+        ));
+        assert!(bls12381_min_pk_sign_aug(sk.to_bytes().to_vec(), msg, vec![]).is_empty());
     }
 
     #[test]
-    fn aggregate_empty() {
-        assert!(bls12381_min_pk_aggregate(vec![]).is_empty());
+    fn sign_aug_with_pk_matches_aggregate_aug() {
+        let keys = committee("explicit-aug", 3);
+        let msg = b"standard augmentation".to_vec();
+        let sigs: Vec<Vec<u8>> = keys
+            .iter()
+            .map(|(sk, pk)| {
+                bls12381_min_pk_sign_aug(
+                    sk.to_bytes().to_vec(),
+                    msg.clone(),
+                    pk.to_bytes().to_vec(),
+                )
+            })
+            .collect();
+        let agg = bls12381_min_pk_aggregate_aug(sigs);
+        assert!(bls12381_min_pk_verify_aggregate_aug(
+            pks_of(&keys),
+            msg,
+            agg
+        ));
     }
 
+    // ---- repair aggregate ----
+
     #[test]
-    fn aggregate_malformed() {
-        assert!(bls12381_min_pk_aggregate(vec![vec![0u8; 10]]).is_empty());
+    fn repair_aggregate_drops_corrupt_signature() {
+        let keys = [
+            keygen(b"test-repair-aggregate-key-1!!!!!"),
+            keygen(b"test-repair-aggregate-key-2!!!!!"),
+            keygen(b"test-repair-aggregate-key-3!!!!!"),
+        ];
+        let msg = b"repair me";
+        let mut sigs: Vec<Vec<u8>> = keys
+            .iter()
+            .map(|(sk, _)| sign_msg(sk, msg).to_bytes().to_vec())
+            .collect();
+        // Signer 1 signed the wrong message.
+        sigs[1] = sign_msg(&keys[1].0, b"something else").to_bytes().to_vec();
+        let pks: Vec<Vec<u8>> = keys.iter().map(|(_, pk)| pk.to_bytes().to_vec()).collect();
+
+        let repaired = bls12381_repair_aggregate(sigs, pks.clone(), msg.to_vec());
+        assert_eq!(repaired.dropped, vec![1]);
+        assert!(bls12381_min_pk_verify_aggregate(
+            vec![pks[0].clone(), pks[2].clone()],
+            msg.to_vec(),
+            repaired.aggregate,
+        ));
     }
 
-    // ---- verify aggregate ----
+    #[test]
+    fn repair_aggregate_all_invalid() {
+        let (_, pk) = keygen(b"test-repair-aggregate-none-valid");
+        let repaired =
+            bls12381_repair_aggregate(vec![vec![0u8; 96]], vec![pk.to_bytes().to_vec()], vec![1]);
+        assert!(repaired.aggregate.is_empty());
+        assert_eq!(repaired.dropped, vec![0]);
+    }
 
     #[test]
-    fn verify_aggregate_valid() {
-        let (sk1, pk1) = keygen(b"test-agg-verify-valid-key-1!!!!!");
-        let (sk2, pk2) = keygen(b"test-agg-verify-valid-key-2!!!!!");
-        let (sk3, pk3) = keygen(b"test-agg-verify-valid-key-3!!!!!");
-        let msg = b"certify this blob";
+    fn repair_aggregate_length_mismatch() {
+        let repaired = bls12381_repair_aggregate(vec![vec![0u8; 96]; 2], vec![], vec![1]);
+        assert!(repaired.aggregate.is_empty());
+        assert_eq!(repaired.dropped, vec![0, 1]);
+    }
 
-        let agg = bls12381_min_pk_aggregate(vec![
-            sign_msg(&sk1, msg).to_bytes().to_vec(),
-            sign_msg(&sk2, msg).to_bytes().to_vec(),
-            sign_msg(&sk3, msg).to_bytes().to_vec(),
-        ]);
+    // ---- nested attestation ----
+
+    #[test]
+    fn verify_nested_outer_attests_inner() {
+        let inner = committee("nested-inner", 3);
+        let outer = committee("nested-outer", 2);
+        let inner_agg = agg_sign(&inner, &[0, 1, 2], b"inner payload");
+        let outer_agg = agg_sign(&outer, &[0, 1], &inner_agg);
 
         assert!(bls12381_min_pk_verify_aggregate(
-            vec![
-                pk1.to_bytes().to_vec(),
-                pk2.to_bytes().to_vec(),
-                pk3.to_bytes().to_vec(),
-            ],
-            msg.to_vec(),
-            agg,
+            pks_of(&inner),
+            b"inner payload".to_vec(),
+            inner_agg.clone(),
+        ));
+        assert!(bls12381_verify_nested(
+            pks_of(&outer),
+            inner_agg.clone(),
+            outer_agg.clone(),
+        ));
+
+        // The outer aggregate does not vouch for a different inner aggregate.
+        let other_inner = agg_sign(&inner, &[0, 1], b"inner payload");
+        assert!(!bls12381_verify_nested(
+            pks_of(&outer),
+            other_inner,
+            outer_agg.clone()
+        ));
+        // Nor for the inner committee's keys.
+        assert!(!bls12381_verify_nested(
+            pks_of(&inner),
+            inner_agg,
+            outer_agg
         ));
     }
 
     #[test]
-    fn verify_aggregate_wrong_message() {
-        let (sk1, pk1) = keygen(b"test-agg-verify-wrong-msg-key1!!");
-        let (sk2, pk2) = keygen(b"test-agg-verify-wrong-msg-key2!!");
-        let msg = b"correct message";
+    fn verify_nested_rejects_non_point_inner() {
+        let outer = committee("nested-outer", 1);
+        let garbage = vec![0x42u8; 96];
+        let outer_agg = agg_sign(&outer, &[0], &garbage);
+        assert!(!bls12381_verify_nested(pks_of(&outer), garbage, outer_agg));
+    }
 
-        let agg = bls12381_min_pk_aggregate(vec![
-            sign_msg(&sk1, msg).to_bytes().to_vec(),
-            sign_msg(&sk2, msg).to_bytes().to_vec(),
-        ]);
+    // ---- categorized ----
 
-        assert!(!bls12381_min_pk_verify_aggregate(
-            vec![pk1.to_bytes().to_vec(), pk2.to_bytes().to_vec()],
-            b"wrong message".to_vec(),
-            agg,
-        ));
+    #[test]
+    fn categorized_each_category() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-categorized!!!");
+        let msg = b"categorize me".to_vec();
+        let sig = sign_msg(&sk, &msg).to_bytes().to_vec();
+        let pk = pk.to_bytes().to_vec();
+
+        assert_eq!(
+            bls12381_min_pk_verify_categorized(sig.clone(), pk.clone(), msg.clone()),
+            VerifyCategory::Valid
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_categorized(sig.clone(), pk.clone(), b"other".to_vec()),
+            VerifyCategory::FatalInvalidSignature
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_categorized(sig[..95].to_vec(), pk.clone(), msg.clone()),
+            VerifyCategory::RecoverableBadInput
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_categorized(sig, vec![0u8; 48], msg),
+            VerifyCategory::RecoverableBadInput
+        );
     }
 
     #[test]
-    fn verify_aggregate_missing_signer() {
-        let (sk1, pk1) = keygen(b"test-agg-verify-missing-key-1!!!");
-        let (sk2, pk2) = keygen(b"test-agg-verify-missing-key-2!!!");
-        let (_, pk3) = keygen(b"test-agg-verify-missing-key-3!!!");
-        let msg = b"only two signed";
+    fn categorized_identity_key_is_bad_input() {
+        let mut identity = vec![0u8; 48];
+        identity[0] = 0xc0;
+        let mut infinity_sig = vec![0u8; 96];
+        infinity_sig[0] = 0xc0;
+        assert_eq!(
+            bls12381_min_pk_verify_categorized(infinity_sig, identity, b"m".to_vec()),
+            VerifyCategory::RecoverableBadInput
+        );
+    }
 
-        let agg = bls12381_min_pk_aggregate(vec![
-            sign_msg(&sk1, msg).to_bytes().to_vec(),
-            sign_msg(&sk2, msg).to_bytes().to_vec(),
-        ]);
+    // ---- raw error code ----
 
-        // Verify with 3 keys but only 2 signed → should fail.
-        assert!(!bls12381_min_pk_verify_aggregate(
-            vec![
-                pk1.to_bytes().to_vec(),
-                pk2.to_bytes().to_vec(),
-                pk3.to_bytes().to_vec(),
-            ],
-            msg.to_vec(),
+    #[test]
+    fn verify_with_error_codes() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-error-codes!!!");
+        let msg = b"debug me".to_vec();
+        let sig = sign_msg(&sk, &msg).to_bytes().to_vec();
+        let pk = pk.to_bytes().to_vec();
+
+        assert_eq!(
+            bls12381_min_pk_verify_with_error(sig.clone(), pk.clone(), msg.clone()),
+            0
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_with_error(sig.clone(), pk.clone(), b"wrong".to_vec()),
+            BLST_ERROR::BLST_VERIFY_FAIL as i32
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_with_error(sig[..95].to_vec(), pk.clone(), msg.clone()),
+            -1
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_with_error(sig, vec![0u8; PK_LEN], msg),
+            BLST_ERROR::BLST_BAD_ENCODING as i32
+        );
+    }
+
+    // ---- aggregate verified ----
+
+    #[test]
+    fn aggregate_verified_skips_junk() {
+        let keys = committee("aggregate-verified", 4);
+        let pks = pks_of(&keys);
+        let msg = b"only the good ones".to_vec();
+        let sigs = vec![
+            sign_msg(&keys[0].0, &msg).to_bytes().to_vec(),
+            vec![0xab; 96],
+            sign_msg(&keys[2].0, &msg).to_bytes().to_vec(),
+            sign_msg(&keys[3].0, b"wrong").to_bytes().to_vec(),
+        ];
+
+        let agg = bls12381_min_pk_aggregate_verified(sigs, pks.clone(), msg.clone());
+        assert_eq!(agg, agg_sign(&keys, &[0, 2], &msg));
+        assert!(bls12381_min_pk_verify_aggregate(
+            vec![pks[0].clone(), pks[2].clone()],
+            msg,
             agg,
         ));
     }
 
     #[test]
-    fn verify_aggregate_empty_keys() {
-        assert!(!bls12381_min_pk_verify_aggregate(
-            vec![],
-            b"msg".to_vec(),
-            vec![0u8; 96],
-        ));
+    fn aggregate_verified_none_valid() {
+        let pks = pks_of(&committee("aggregate-verified", 1));
+        assert!(
+            bls12381_min_pk_aggregate_verified(vec![vec![0u8; 96]], pks.clone(), vec![1])
+                .is_empty()
+        );
+        assert!(bls12381_min_pk_aggregate_verified(vec![], pks, vec![1]).is_empty());
     }
 
-    // ---- end-to-end ----
+    // ---- nonce ----
 
     #[test]
-    fn end_to_end_walrus_flow() {
-        // Simulates the Walrus blob certification flow:
-        // N storage nodes each sign the same serialized message,
-        // signatures are aggregated, then verified against signer public keys.
+    fn verify_with_nonce_replay() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-nonce-replay!!");
+        let pk = pk.to_bytes().to_vec();
+        let sign_nonce = |nonce: u64| {
+            let mut m = b"transfer".to_vec();
+            m.extend_from_slice(&nonce.to_be_bytes());
+            sign_msg(&sk, &m).to_bytes().to_vec()
+        };
+        let seen = vec![1, 2, 3];
 
-        let seeds: [&[u8; 32]; 5] = [
-            b"walrus-node-0-secret-key-seed!!1",
-            b"walrus-node-1-secret-key-seed!!2",
-            b"walrus-node-2-secret-key-seed!!3",
-            b"walrus-node-3-secret-key-seed!!4",
-            b"walrus-node-4-secret-key-seed!!5",
-        ];
+        assert_eq!(
+            bls12381_min_pk_verify_with_nonce(
+                sign_nonce(2),
+                pk.clone(),
+                b"transfer".to_vec(),
+                2,
+                seen.clone()
+            ),
+            VerifyOutcome::ReplayDetected
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_with_nonce(
+                sign_nonce(4),
+                pk.clone(),
+                b"transfer".to_vec(),
+                4,
+                seen.clone()
+            ),
+            VerifyOutcome::Valid
+        );
+        // Signed under one nonce, presented under another.
+        assert_eq!(
+            bls12381_min_pk_verify_with_nonce(
+                sign_nonce(4),
+                pk.clone(),
+                b"transfer".to_vec(),
+                5,
+                seen.clone()
+            ),
+            VerifyOutcome::InvalidSignature
+        );
+        assert_eq!(
+            bls12381_min_pk_verify_with_nonce(vec![0u8; 95], pk, b"transfer".to_vec(), 5, seen),
+            VerifyOutcome::MalformedInput
+        );
+    }
 
-        let keys: Vec<(SecretKey, PublicKey)> = seeds.iter().map(|s| keygen(s)).collect();
-        let blob_cert_msg = b"blob_cert_v1:blobid=abc123:epoch=42:size=1024";
+    // ---- fallback ----
 
-        // Quorum: nodes 0, 2, 4 respond
-        let responding = vec![0usize, 2, 4];
-        let sigs: Vec<Vec<u8>> = responding
+    #[test]
+    fn fallback_reports_bad_signer() {
+        let keys = committee("fallback", 4);
+        let pks = pks_of(&keys);
+        let msg = b"find the culprit".to_vec();
+        let mut sigs: Vec<Vec<u8>> = keys
             .iter()
-            .map(|&i| sign_msg(&keys[i].0, blob_cert_msg).to_bytes().to_vec())
+            .map(|(sk, _)| sign_msg(sk, &msg).to_bytes().to_vec())
             .collect();
 
-        let agg_sig = bls12381_min_pk_aggregate(sigs);
-        assert_eq!(agg_sig.len(), 96);
+        let good = bls12381_min_pk_aggregate(sigs.clone());
+        let result =
+            bls12381_verify_aggregate_with_fallback(pks.clone(), sigs.clone(), msg.clone(), good);
+        assert!(result.aggregate_valid);
+        assert!(result.failing.is_empty());
 
-        let responding_pks: Vec<Vec<u8>> = responding
+        sigs[2] = sign_msg(&keys[2].0, b"something else").to_bytes().to_vec();
+        let bad = bls12381_min_pk_aggregate(sigs.clone());
+        let result =
+            bls12381_verify_aggregate_with_fallback(pks.clone(), sigs, msg.clone(), bad.clone());
+        assert!(!result.aggregate_valid);
+        assert_eq!(result.failing, vec![2]);
+
+        // Without individual signatures only the verdict is known.
+        let result = bls12381_verify_aggregate_with_fallback(pks, vec![], msg, bad);
+        assert!(!result.aggregate_valid);
+        assert!(result.failing.is_empty());
+    }
+
+    // ---- proof of possession ----
+
+    #[test]
+    fn pop_round_trip() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-pop-prove!!!!!");
+        let pop = bls12381_min_pk_pop_prove(sk.to_bytes().to_vec());
+        assert_eq!(pop.len(), SIG_LEN);
+        assert!(bls12381_min_pk_pop_verify(
+            pk.to_bytes().to_vec(),
+            pop.clone()
+        ));
+
+        // A PoP is not an ordinary signature over the key bytes.
+        assert!(!bls12381_min_pk_verify(
+            pop,
+            pk.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+        ));
+    }
+
+    #[test]
+    fn pop_wrong_key() {
+        let (sk, _) = keygen(b"test-seed-for-bls-pop-prove!!!!!");
+        let (_, other) = keygen(b"test-seed-for-bls-pop-other!!!!!");
+        let pop = bls12381_min_pk_pop_prove(sk.to_bytes().to_vec());
+        assert!(!bls12381_min_pk_pop_verify(other.to_bytes().to_vec(), pop));
+    }
+
+    #[test]
+    fn pop_malformed_inputs() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-pop-prove!!!!!");
+        let pop = bls12381_min_pk_pop_prove(sk.to_bytes().to_vec());
+
+        assert!(bls12381_min_pk_pop_prove(vec![0u8; 31]).is_empty());
+        assert!(!bls12381_min_pk_pop_verify(vec![0u8; PK_LEN], pop.clone()));
+        assert!(!bls12381_min_pk_pop_verify(
+            pk.to_bytes()[..PK_LEN - 1].to_vec(),
+            pop.clone()
+        ));
+        assert!(!bls12381_min_pk_pop_verify(
+            pk.to_bytes().to_vec(),
+            pop[..SIG_LEN - 1].to_vec()
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_pop() {
+        let keys = committee("pop-aggregate", 3);
+        let pks = pks_of(&keys);
+        let pops: Vec<Vec<u8>> = keys
             .iter()
-            .map(|&i| keys[i].1.to_bytes().to_vec())
+            .map(|(sk, _)| bls12381_min_pk_pop_prove(sk.to_bytes().to_vec()))
             .collect();
+        let msg = b"pop committee".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
 
-        assert!(bls12381_min_pk_verify_aggregate(
-            responding_pks,
-            blob_cert_msg.to_vec(),
-            agg_sig,
+        assert!(bls12381_min_pk_verify_aggregate_pop(
+            pks.clone(),
+            pops.clone(),
+            msg.clone(),
+            agg.clone()
+        ));
+
+        // One signer's proof swapped for another's.
+        let mut bad_pops = pops.clone();
+        bad_pops[1] = pops[0].clone();
+        assert!(!bls12381_min_pk_verify_aggregate_pop(
+            pks.clone(),
+            bad_pops,
+            msg.clone(),
+            agg.clone()
+        ));
+
+        assert!(!bls12381_min_pk_verify_aggregate_pop(
+            pks.clone(),
+            pops[..2].to_vec(),
+            msg.clone(),
+            agg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_aggregate_pop(
+            vec![],
+            vec![],
+            msg,
+            agg
         ));
     }
 
+    // ---- DST registry ----
+
     #[test]
-    fn key_and_sig_sizes() {
-        let (sk, pk) = keygen(b"test-sizes-check-key-seed!!!!!!!");
-        let sig = sign_msg(&sk, b"size check");
-        assert_eq!(pk.to_bytes().len(), 48, "Public key should be 48 bytes");
-        assert_eq!(sig.to_bytes().len(), 96, "Signature should be 96 bytes");
+    fn dst_for_known_schemes() {
+        let cases: [(&str, &[u8]); 4] = [
+            ("min_pk_nul", b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_"),
+            ("min_pk_aug", b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_AUG_"),
+            ("min_pk_pop", b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_"),
+            (
+                "min_sig_nul",
+                b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_",
+            ),
+        ];
+        for (scheme, dst) in cases {
+            assert_eq!(bls12381_dst_for_scheme(scheme.into()), dst);
+        }
+    }
+
+    #[test]
+    fn dst_for_unknown_scheme() {
+        assert!(bls12381_dst_for_scheme("min_pk_xyz".into()).is_empty());
+        assert!(bls12381_dst_for_scheme("MIN_PK_NUL".into()).is_empty());
     }
 }
@@ -0,0 +1,243 @@
+// Self-contained certificate bundles: everything a verifier needs to check
+// a committee certificate, in one byte string.
+//
+// Layout (version 1, integers little-endian):
+//
+//   version:   u8            = 1
+//   epoch:     u64
+//   n:         u32           committee size
+//   pks:       n × 48 bytes  compressed G1 keys, in committee order
+//   msg_len:   u32
+//   msg:       msg_len bytes
+//   bitmap:    ceil(n / 8) bytes
+//   agg_sig:   96 bytes
+
+use crate::api::bls::{parse_pks, PK_LEN, SIG_LEN};
+use crate::api::committee::{bitmap_indices, bitmap_len, is_bft_quorum, verify_signers};
+
+const BUNDLE_VERSION: u8 = 1;
+
+/// A bundle split back into its fields.
+struct Bundle<'a> {
+    epoch: u64,
+    pks: Vec<&'a [u8]>,
+    msg: &'a [u8],
+    signers: Vec<usize>,
+    agg_sig: &'a [u8],
+}
+
+impl<'a> Bundle<'a> {
+    /// Split `buf` into its fields, or `None` on a bad version, truncated
+    /// or trailing bytes, or a bitmap bit set past the committee size.
+    fn decode(buf: &'a [u8]) -> Option<Bundle<'a>> {
+        let mut rest = buf;
+        let mut take = |n: usize| -> Option<&'a [u8]> {
+            if rest.len() < n {
+                return None;
+            }
+            let (head, tail) = rest.split_at(n);
+            rest = tail;
+            Some(head)
+        };
+
+        if take(1)?[0] != BUNDLE_VERSION {
+            return None;
+        }
+        let epoch = u64::from_le_bytes(take(8)?.try_into().ok()?);
+        let n = u32::from_le_bytes(take(4)?.try_into().ok()?) as usize;
+        let pks = take(n.checked_mul(PK_LEN)?)?.chunks_exact(PK_LEN).collect();
+        let msg_len = u32::from_le_bytes(take(4)?.try_into().ok()?) as usize;
+        let msg = take(msg_len)?;
+        let signers = bitmap_indices(take(bitmap_len(n))?, n)?;
+        let agg_sig = take(SIG_LEN)?;
+        if !rest.is_empty() {
+            return None;
+        }
+        Some(Bundle {
+            epoch,
+            pks,
+            msg,
+            signers,
+            agg_sig,
+        })
+    }
+
+    /// Quorum of more than 2/3 of the committee and a valid aggregate.
+    fn verify(&self) -> bool {
+        if !is_bft_quorum(self.signers.len(), self.pks.len()) {
+            return false;
+        }
+        let pks: Vec<Vec<u8>> = self.pks.iter().map(|pk| pk.to_vec()).collect();
+        match parse_pks(&pks) {
+            Some(parsed) => verify_signers(&parsed, &self.signers, self.msg, self.agg_sig),
+            None => false,
+        }
+    }
+}
+
+/// Pack a committee certificate into a self-contained bundle.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `epoch`         – epoch the certificate belongs to
+/// * `bitmap`        – signer bitmap; shorter bitmaps are zero-padded
+/// * `msg`           – the certified message
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+///
+/// Returns the encoded bundle, or an empty `Vec<u8>` when a key or the
+/// signature has the wrong length, or the bitmap has bits beyond the
+/// committee.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_bundle_encode(
+    committee_pks: Vec<Vec<u8>>,
+    epoch: u64,
+    bitmap: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> Vec<u8> {
+    let n = committee_pks.len();
+    if committee_pks.iter().any(|pk| pk.len() != PK_LEN)
+        || agg_sig.len() != SIG_LEN
+        || bitmap_indices(&bitmap, n).is_none()
+        || u32::try_from(n).is_err()
+        || u32::try_from(msg.len()).is_err()
+    {
+        return vec![];
+    }
+
+    let mut out =
+        Vec::with_capacity(1 + 8 + 4 + n * PK_LEN + 4 + msg.len() + bitmap_len(n) + SIG_LEN);
+    out.push(BUNDLE_VERSION);
+    out.extend_from_slice(&epoch.to_le_bytes());
+    out.extend_from_slice(&(n as u32).to_le_bytes());
+    for pk in &committee_pks {
+        out.extend_from_slice(pk);
+    }
+    out.extend_from_slice(&(msg.len() as u32).to_le_bytes());
+    out.extend_from_slice(&msg);
+    let mut padded = bitmap;
+    padded.resize(bitmap_len(n), 0);
+    out.extend_from_slice(&padded);
+    out.extend_from_slice(&agg_sig);
+    out
+}
+
+/// Verify a certificate bundle.
+///
+/// * `bundle` – bytes produced by [`bls12381_bundle_encode`]
+///
+/// Returns `true` when the bundle decodes, its signers are more than 2/3 of
+/// the committee, and the aggregate signature verifies over the message.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_bundle_verify(bundle: Vec<u8>) -> bool {
+    Bundle::decode(&bundle).is_some_and(|b| b.verify())
+}
+
+/// What a certificate bundle contains, plus whether it verifies.
+pub struct BundleInfo {
+    /// Number of committee members.
+    pub committee_size: u32,
+    /// Number of signers set in the bitmap.
+    pub signer_count: u32,
+    /// Epoch the certificate belongs to.
+    pub epoch: u64,
+    /// Same verdict as [`bls12381_bundle_verify`].
+    pub valid: bool,
+}
+
+/// Decode a certificate bundle and report its metadata and verdict.
+///
+/// * `bundle` – bytes produced by [`bls12381_bundle_encode`]
+///
+/// Returns `None` when the bundle does not decode, including when the bitmap
+/// has bits set past the committee size.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_bundle_inspect(bundle: Vec<u8>) -> Option<BundleInfo> {
+    let b = Bundle::decode(&bundle)?;
+    Some(BundleInfo {
+        committee_size: b.pks.len() as u32,
+        signer_count: b.signers.len() as u32,
+        epoch: b.epoch,
+        valid: b.verify(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{agg_sign, committee, pks_of};
+
+    fn sample_bundle(signers: &[usize], msg: &[u8]) -> Vec<u8> {
+        let keys = committee("bundle", 4);
+        let bitmap = signers.iter().fold(0u8, |b, &i| b | (1 << i));
+        bls12381_bundle_encode(
+            pks_of(&keys),
+            17,
+            vec![bitmap],
+            msg.to_vec(),
+            agg_sign(&keys, signers, msg),
+        )
+    }
+
+    // ---- encode / verify ----
+
+    #[test]
+    fn bundle_round_trip() {
+        let bundle = sample_bundle(&[0, 1, 3], b"bundled");
+        assert_eq!(bundle.len(), 1 + 8 + 4 + 4 * 48 + 4 + 7 + 1 + 96);
+        assert!(bls12381_bundle_verify(bundle));
+    }
+
+    #[test]
+    fn bundle_rejects_below_quorum() {
+        assert!(!bls12381_bundle_verify(sample_bundle(&[0, 1], b"bundled")));
+    }
+
+    #[test]
+    fn bundle_rejects_tampering() {
+        let mut bundle = sample_bundle(&[0, 1, 3], b"bundled");
+        // Flip a message byte (after version, epoch, n, keys and msg_len).
+        bundle[1 + 8 + 4 + 4 * 48 + 4] ^= 1;
+        assert!(!bls12381_bundle_verify(bundle.clone()));
+
+        bundle.push(0);
+        assert!(Bundle::decode(&bundle).is_none());
+    }
+
+    #[test]
+    fn bundle_encode_bad_input() {
+        let pks = pks_of(&committee("bundle", 4));
+        assert!(
+            bls12381_bundle_encode(pks.clone(), 0, vec![0b1_0000], vec![], vec![0u8; 96])
+                .is_empty()
+        );
+        assert!(bls12381_bundle_encode(pks, 0, vec![1], vec![], vec![0u8; 95]).is_empty());
+    }
+
+    // ---- inspect ----
+
+    #[test]
+    fn bundle_inspect_metadata() {
+        let info = bls12381_bundle_inspect(sample_bundle(&[0, 2, 3], b"inspect")).unwrap();
+        assert_eq!(info.committee_size, 4);
+        assert_eq!(info.signer_count, 3);
+        assert_eq!(info.epoch, 17);
+        assert!(info.valid);
+
+        let info = bls12381_bundle_inspect(sample_bundle(&[2], b"inspect")).unwrap();
+        assert_eq!(info.signer_count, 1);
+        assert!(!info.valid);
+    }
+
+    #[test]
+    fn bundle_inspect_undecodable() {
+        assert!(bls12381_bundle_inspect(vec![]).is_none());
+        assert!(bls12381_bundle_inspect(vec![2; 200]).is_none());
+
+        // A padding bit past the 4-member committee in the last bitmap byte.
+        let mut padded = sample_bundle(&[0, 2, 3], b"inspect");
+        let bitmap_at = padded.len() - SIG_LEN - 1;
+        padded[bitmap_at] |= 0x80;
+        assert!(bls12381_bundle_inspect(padded.clone()).is_none());
+        assert!(!bls12381_bundle_verify(padded));
+    }
+}
@@ -0,0 +1,186 @@
+// Append-only on-disk record of verified certificates, so offline-first
+// apps can later confirm a certificate was checked without re-running the
+// pairing.
+//
+// Record layout (72 bytes, appended per verified certificate):
+//
+//   committee_digest: 32 bytes   see `bls12381_committee_digest`
+//   msg_hash:         32 bytes   SHA-256 of the message
+//   verified_at:      u64 LE     Unix time in seconds
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use blst::min_pk::PublicKey;
+
+use crate::api::bls::{fast_aggregate_verify, parse_pks};
+use crate::api::committee::digest_of;
+use crate::hash::sha256;
+
+/// Length of one cache record.
+const RECORD_LEN: usize = 32 + 32 + 8;
+
+/// Write failure of the latest [`bls12381_verify_and_persist`] call; empty
+/// if that call wrote its record or had nothing to write.
+static LAST_PERSIST_ERROR: Mutex<String> = Mutex::new(String::new());
+
+/// Build the cache record for a verified certificate.
+fn record(pks: &[PublicKey], msg: &[u8], verified_at: u64) -> [u8; RECORD_LEN] {
+    let mut out = [0u8; RECORD_LEN];
+    out[..32].copy_from_slice(&digest_of(pks));
+    out[32..64].copy_from_slice(&sha256(msg));
+    out[64..].copy_from_slice(&verified_at.to_le_bytes());
+    out
+}
+
+fn append(path: &str, record: &[u8]) -> std::io::Result<()> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(record)
+}
+
+/// Verify an aggregate signature and, on success, append a record of it to
+/// a local cache file.
+///
+/// * `pks`        – 48-byte compressed G1 public keys of the signers
+/// * `msg`        – the shared message all signers signed
+/// * `agg_sig`    – 96-byte compressed aggregate G2 signature
+/// * `cache_path` – file to append to; created if missing
+///
+/// Each record is `committee_digest || SHA-256(msg) || u64_le(unix_secs)`,
+/// 72 bytes. Nothing is written for a failed verification.
+///
+/// Returns the verification verdict, whether or not the record could be
+/// written. Whether this call's write failed is reported through
+/// [`bls12381_last_persist_error`].
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_and_persist(
+    pks: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+    cache_path: String,
+) -> bool {
+    let (verified, write) = verify_and_persist(&pks, &msg, &agg_sig, &cache_path);
+    *LAST_PERSIST_ERROR.lock().unwrap_or_else(|e| e.into_inner()) = match write {
+        Ok(()) => String::new(),
+        Err(e) => format!("verify cache {cache_path}: {e}"),
+    };
+    verified
+}
+
+/// [`bls12381_verify_and_persist`], returning the verdict together with
+/// the outcome of the write (`Ok` when nothing needed writing).
+fn verify_and_persist(
+    pks: &[Vec<u8>],
+    msg: &[u8],
+    agg_sig: &[u8],
+    cache_path: &str,
+) -> (bool, std::io::Result<()>) {
+    let parsed = match parse_pks(pks) {
+        Some(p) => p,
+        None => return (false, Ok(())),
+    };
+    let refs: Vec<&PublicKey> = parsed.iter().collect();
+    if !fast_aggregate_verify(&refs, msg, agg_sig) {
+        return (false, Ok(()));
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    (true, append(cache_path, &record(&parsed, msg, now)))
+}
+
+/// The cache write failure of the latest [`bls12381_verify_and_persist`]
+/// call.
+///
+/// Returns an empty `String` when that call wrote its record or had nothing
+/// to write.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_last_persist_error() -> String {
+    LAST_PERSIST_ERROR
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{agg_sign, committee, pks_of};
+
+    fn temp_path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("bls_dart_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn persist_appends_record_on_success() {
+        let keys = committee("persist", 3);
+        let pks = pks_of(&keys);
+        let msg = b"cache me".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+        let path = temp_path("persist_ok");
+
+        assert!(verify_and_persist(&pks, &msg, &agg, &path).0);
+        assert!(verify_and_persist(&pks, &msg, &agg, &path).0);
+
+        let data = std::fs::read(&path).unwrap();
+        assert_eq!(data.len(), 2 * RECORD_LEN);
+        let parsed = parse_pks(&pks).unwrap();
+        assert_eq!(data[..32], digest_of(&parsed));
+        assert_eq!(data[32..64], sha256(&msg));
+        assert!(u64::from_le_bytes(data[64..72].try_into().unwrap()) > 0);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn persist_skips_invalid_certificate() {
+        let keys = committee("persist", 2);
+        let agg = agg_sign(&keys, &[0, 1], b"cache me");
+        let path = temp_path("persist_invalid");
+
+        let (verified, write) = verify_and_persist(&pks_of(&keys), b"other", &agg, &path);
+        assert!(!verified);
+        assert!(write.is_ok());
+        assert!(std::fs::metadata(&path).is_err());
+    }
+
+    // The only test that calls the public function, as it is the only one
+    // that reads the shared error slot.
+    #[test]
+    fn persist_io_error_keeps_verdict() {
+        let keys = committee("persist", 2);
+        let msg = b"cache me".to_vec();
+        let agg = agg_sign(&keys, &[0, 1], &msg);
+        let dir = std::env::temp_dir().to_string_lossy().into_owned();
+
+        // A directory can't be opened for appending.
+        let (verified, write) = verify_and_persist(&pks_of(&keys), &msg, &agg, &dir);
+        assert!(verified);
+        assert!(write.is_err());
+        assert!(bls12381_verify_and_persist(
+            pks_of(&keys),
+            msg.clone(),
+            agg.clone(),
+            dir.clone()
+        ));
+        assert!(bls12381_last_persist_error().contains(&dir));
+
+        // A later successful write clears it.
+        let path = temp_path("persist_recovered");
+        assert!(bls12381_verify_and_persist(
+            pks_of(&keys),
+            msg,
+            agg,
+            path.clone()
+        ));
+        assert!(bls12381_last_persist_error().is_empty());
+        std::fs::remove_file(path).unwrap();
+    }
+}
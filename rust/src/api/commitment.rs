@@ -0,0 +1,276 @@
+// Merkle vector commitments over committee public keys.
+//
+// Very large committees are published as a single 32-byte root instead of
+// the full key list; each signer then presents its key together with an
+// inclusion proof against that root.
+//
+// Tree shape:
+//
+//   leaf(pk)    = SHA-256(0x00 || compressed_pk)
+//   node(l, r)  = SHA-256(0x01 || l || r)
+//
+// Leaves are padded with all-zero hashes up to the next power of two, so
+// every proof for a given committee has the same depth. A proof is
+// `u32_le(index) || depth × 32-byte sibling hashes`, siblings from the leaf
+// level upwards.
+
+use crate::api::bls::{fast_aggregate_verify, parse_pks, PK_LEN};
+use crate::hash::sha256;
+
+const LEAF_TAG: u8 = 0x00;
+const NODE_TAG: u8 = 0x01;
+const HASH_LEN: usize = 32;
+
+fn leaf_hash(pk: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(1 + PK_LEN);
+    buf.push(LEAF_TAG);
+    buf.extend_from_slice(pk);
+    sha256(&buf)
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buf = [0u8; 1 + 2 * HASH_LEN];
+    buf[0] = NODE_TAG;
+    buf[1..33].copy_from_slice(left);
+    buf[33..].copy_from_slice(right);
+    sha256(&buf)
+}
+
+/// All tree levels, from the padded leaves (index 0) up to the root.
+fn tree_levels(pks: &[Vec<u8>]) -> Vec<Vec<[u8; 32]>> {
+    let mut level: Vec<[u8; 32]> = pks.iter().map(|pk| leaf_hash(pk)).collect();
+    level.resize(pks.len().next_power_of_two(), [0u8; 32]);
+    let mut levels = vec![level];
+    while levels.last().unwrap().len() > 1 {
+        let next = levels
+            .last()
+            .unwrap()
+            .chunks_exact(2)
+            .map(|pair| node_hash(&pair[0], &pair[1]))
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+/// Recompute the root implied by `proof` for the key `pk`.
+fn proof_root(pk: &[u8], proof: &[u8]) -> Option<[u8; 32]> {
+    if proof.len() < 4 || !(proof.len() - 4).is_multiple_of(HASH_LEN) {
+        return None;
+    }
+    let (index, siblings) = proof.split_at(4);
+    let mut index = u32::from_le_bytes(index.try_into().ok()?);
+    let mut acc = leaf_hash(pk);
+    for sibling in siblings.chunks_exact(HASH_LEN) {
+        let sibling: [u8; 32] = sibling.try_into().ok()?;
+        acc = if index & 1 == 0 {
+            node_hash(&acc, &sibling)
+        } else {
+            node_hash(&sibling, &acc)
+        };
+        index >>= 1;
+    }
+    // Leftover index bits would address a leaf outside the tree.
+    (index == 0).then_some(acc)
+}
+
+/// Commit to a committee's public keys.
+///
+/// * `pks` – 48-byte compressed G1 public keys, in committee order
+///
+/// Returns the 32-byte Merkle root, or an empty `Vec<u8>` if the committee
+/// is empty or a key is not 48 bytes.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_vc_commit(pks: Vec<Vec<u8>>) -> Vec<u8> {
+    if pks.is_empty() || pks.iter().any(|pk| pk.len() != PK_LEN) {
+        return vec![];
+    }
+    tree_levels(&pks).last().unwrap()[0].to_vec()
+}
+
+/// Build the inclusion proof for the committee member at `index`.
+///
+/// * `pks`   – 48-byte compressed G1 public keys, in committee order
+/// * `index` – position of the member whose proof is wanted
+///
+/// Returns the encoded proof, or an empty `Vec<u8>` if `index` is out of
+/// range or a key is not 48 bytes.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_vc_prove(pks: Vec<Vec<u8>>, index: u32) -> Vec<u8> {
+    let i = index as usize;
+    if i >= pks.len() || pks.iter().any(|pk| pk.len() != PK_LEN) {
+        return vec![];
+    }
+    let levels = tree_levels(&pks);
+    let mut proof = index.to_le_bytes().to_vec();
+    let mut pos = i;
+    for level in &levels[..levels.len() - 1] {
+        proof.extend_from_slice(&level[pos ^ 1]);
+        pos >>= 1;
+    }
+    proof
+}
+
+/// Verify an aggregate signature from signers whose keys are proven members
+/// of a committed committee.
+///
+/// * `commitment` – 32-byte root from [`bls12381_vc_commit`]
+/// * `pks`        – 48-byte compressed G1 public keys of the signers
+/// * `vc_proofs`  – one proof from [`bls12381_vc_prove`] per key, same order
+/// * `msg`        – the message the signers signed
+/// * `agg_sig`    – 96-byte compressed aggregate G2 signature
+///
+/// Returns `true` only when every key's proof leads to `commitment`, no
+/// committee position is proven twice, and the aggregate signature
+/// verifies. Returns `false` on a length mismatch, no signers, a repeated
+/// signer, or any malformed input.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_aggregate_vc(
+    commitment: Vec<u8>,
+    pks: Vec<Vec<u8>>,
+    vc_proofs: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    if commitment.len() != HASH_LEN || pks.len() != vc_proofs.len() {
+        return false;
+    }
+    let included = pks.iter().zip(&vc_proofs).all(|(pk, proof)| {
+        pk.len() == PK_LEN && proof_root(pk, proof).is_some_and(|root| root[..] == commitment[..])
+    });
+    if !included {
+        return false;
+    }
+    // Each leaf may sign once; otherwise one member could count k times by
+    // sending k copies of its proof with k times its signature.
+    let mut indices: Vec<&[u8]> = vc_proofs.iter().map(|proof| &proof[..4]).collect();
+    indices.sort_unstable();
+    if indices.windows(2).any(|w| w[0] == w[1]) {
+        return false;
+    }
+    match parse_pks(&pks) {
+        Some(parsed) => {
+            let refs: Vec<_> = parsed.iter().collect();
+            fast_aggregate_verify(&refs, &msg, &agg_sig)
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{agg_sign, committee, pks_of};
+
+    #[test]
+    fn vc_commit_single_member_is_leaf() {
+        let pks = pks_of(&committee("vc", 1));
+        assert_eq!(bls12381_vc_commit(pks.clone()), leaf_hash(&pks[0]).to_vec());
+        assert_eq!(bls12381_vc_prove(pks, 0), vec![0, 0, 0, 0]);
+        assert!(bls12381_vc_commit(vec![]).is_empty());
+    }
+
+    #[test]
+    fn vc_proofs_reach_root() {
+        let pks = pks_of(&committee("vc", 5));
+        let root = bls12381_vc_commit(pks.clone());
+        for (i, pk) in pks.iter().enumerate() {
+            let proof = bls12381_vc_prove(pks.clone(), i as u32);
+            // Five members pad to eight leaves: three siblings per proof.
+            assert_eq!(proof.len(), 4 + 3 * 32);
+            assert_eq!(proof_root(pk, &proof).unwrap().to_vec(), root);
+        }
+        assert!(bls12381_vc_prove(pks, 5).is_empty());
+    }
+
+    #[test]
+    fn verify_aggregate_vc_committed_committee() {
+        let keys = committee("vc", 5);
+        let pks = pks_of(&keys);
+        let root = bls12381_vc_commit(pks.clone());
+        let msg = b"committed committee".to_vec();
+
+        let signers = [0usize, 2, 3];
+        let signer_pks: Vec<Vec<u8>> = signers.iter().map(|&i| pks[i].clone()).collect();
+        let proofs: Vec<Vec<u8>> = signers
+            .iter()
+            .map(|&i| bls12381_vc_prove(pks.clone(), i as u32))
+            .collect();
+        let agg = agg_sign(&keys, &signers, &msg);
+
+        assert!(bls12381_verify_aggregate_vc(
+            root.clone(),
+            signer_pks.clone(),
+            proofs.clone(),
+            msg.clone(),
+            agg.clone(),
+        ));
+
+        // A proof for a different position does not vouch for the key.
+        let mut swapped = proofs.clone();
+        swapped.swap(0, 1);
+        assert!(!bls12381_verify_aggregate_vc(
+            root,
+            signer_pks.clone(),
+            swapped,
+            msg.clone(),
+            agg.clone(),
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_vc_rejects_repeated_signer() {
+        let keys = committee("vc", 5);
+        let pks = pks_of(&keys);
+        let root = bls12381_vc_commit(pks.clone());
+        let msg = b"committed committee".to_vec();
+        let proof = |i: u32| bls12381_vc_prove(pks.clone(), i);
+
+        // Member 0 twice, with its signature doubled in the aggregate: the
+        // pairing equation holds, but it is still one signer.
+        assert!(!bls12381_verify_aggregate_vc(
+            root.clone(),
+            vec![pks[0].clone(), pks[0].clone(), pks[2].clone()],
+            vec![proof(0), proof(0), proof(2)],
+            msg.clone(),
+            agg_sign(&keys, &[0, 0, 2], &msg),
+        ));
+        assert!(bls12381_verify_aggregate_vc(
+            root,
+            vec![pks[0].clone(), pks[2].clone()],
+            vec![proof(0), proof(2)],
+            msg.clone(),
+            agg_sign(&keys, &[0, 2], &msg),
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_vc_rejects_outsider() {
+        let keys = committee("vc", 4);
+        let pks = pks_of(&keys);
+        let root = bls12381_vc_commit(pks[..3].to_vec());
+        let msg = b"committed committee".to_vec();
+
+        // Member 3 is not in the committed set, even with a proper-looking proof.
+        let proof = bls12381_vc_prove(pks.clone(), 3);
+        assert!(!bls12381_verify_aggregate_vc(
+            root.clone(),
+            vec![pks[3].clone()],
+            vec![proof],
+            msg.clone(),
+            agg_sign(&keys, &[3], &msg),
+        ));
+
+        // Mismatched lengths and an index past the tree.
+        assert!(!bls12381_verify_aggregate_vc(
+            root.clone(),
+            vec![pks[0].clone()],
+            vec![],
+            msg.clone(),
+            agg_sign(&keys, &[0], &msg),
+        ));
+        let mut far = bls12381_vc_prove(pks[..3].to_vec(), 0);
+        far[..4].copy_from_slice(&8u32.to_le_bytes());
+        assert!(proof_root(&pks[0], &far).is_none());
+    }
+}
@@ -0,0 +1,2078 @@
+// Committee-level helpers for certificates made of one aggregate signature
+// plus a signer bitmap.
+//
+// Bitmaps are little-endian at the bit level: committee member `i` is bit
+// `i % 8` of byte `i / 8`.
+
+use blst::min_pk::{PublicKey, Signature};
+use blst::BLST_ERROR;
+
+use crate::api::batch::bls12381_min_pk_batch_verify;
+use crate::api::bls::{
+    aggregate_pks, bls12381_min_pk_aggregate, bls12381_min_pk_verify_aggregate,
+    fast_aggregate_verify, is_identity_sig, parse_pks, VerifyOutcome, DST, PK_LEN, SIG_LEN,
+};
+use crate::hash::sha256;
+use crate::roaring;
+
+/// Number of bytes needed for a bitmap covering `n` committee members.
+pub(crate) fn bitmap_len(n: usize) -> usize {
+    n.div_ceil(8)
+}
+
+/// Indices of the set bits in `bitmap`, in ascending order.
+///
+/// Returns `None` if any set bit addresses a member at or beyond `n`.
+/// Trailing zero bytes past the committee size are allowed.
+pub(crate) fn bitmap_indices(bitmap: &[u8], n: usize) -> Option<Vec<usize>> {
+    let mut out = Vec::new();
+    for (byte_idx, &byte) in bitmap.iter().enumerate() {
+        for bit in 0..8 {
+            if byte & (1 << bit) != 0 {
+                let i = byte_idx * 8 + bit;
+                if i >= n {
+                    return None;
+                }
+                out.push(i);
+            }
+        }
+    }
+    Some(out)
+}
+
+/// Byzantine quorum: strictly more than two thirds of `n` members.
+pub(crate) fn is_bft_quorum(count: usize, n: usize) -> bool {
+    3 * count > 2 * n
+}
+
+/// Aggregate-verify `agg_sig` against the committee members at `indices`.
+pub(crate) fn verify_signers(
+    pks: &[PublicKey],
+    indices: &[usize],
+    msg: &[u8],
+    agg_sig: &[u8],
+) -> bool {
+    let signers: Vec<&PublicKey> = indices.iter().map(|&i| &pks[i]).collect();
+    fast_aggregate_verify(&signers, msg, agg_sig)
+}
+
+/// Byzantine quorum by weight: strictly more than two thirds of `total`.
+pub(crate) fn is_bft_weight_quorum(signed: u64, total: u64) -> bool {
+    3 * signed as u128 > 2 * total as u128
+}
+
+/// A committee whose public keys have been parsed once, for verifying many
+/// certificates against it without re-decompressing every key.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct CommitteeHandle {
+    pks: Vec<PublicKey>,
+    weights: Vec<u64>,
+}
+
+impl CommitteeHandle {
+    /// Prepare a committee from its 48-byte compressed G1 public keys, in
+    /// committee order. Every member gets a voting weight of 1.
+    ///
+    /// Returns `None` for an empty committee or a malformed key.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(pks: Vec<Vec<u8>>) -> Option<CommitteeHandle> {
+        let weights = vec![1; pks.len()];
+        CommitteeHandle::from_parts(parse_pks(&pks)?, weights)
+    }
+
+    /// Build a handle from parsed keys and their voting weights.
+    ///
+    /// Returns `None` for an empty committee, mismatched lengths, or a
+    /// total weight that overflows `u64`.
+    pub(crate) fn from_parts(pks: Vec<PublicKey>, weights: Vec<u64>) -> Option<CommitteeHandle> {
+        if pks.is_empty() || pks.len() != weights.len() {
+            return None;
+        }
+        weights
+            .iter()
+            .try_fold(0u64, |acc, &w| acc.checked_add(w))?;
+        Some(CommitteeHandle { pks, weights })
+    }
+
+    /// Number of committee members.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn size(&self) -> u32 {
+        self.pks.len() as u32
+    }
+
+    /// Sum of all members' voting weights.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn total_weight(&self) -> u64 {
+        self.weights.iter().sum()
+    }
+
+    /// Verify a certificate and require its signers to hold more than 2/3 of
+    /// the committee's total voting weight.
+    ///
+    /// * `bitmap`  – signer bitmap, in committee order
+    /// * `msg`     – the message the signers signed
+    /// * `agg_sig` – 96-byte compressed aggregate G2 signature
+    ///
+    /// Returns `false` when the quorum is not met, a bit is out of range, or
+    /// the aggregate signature does not verify.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn verify_quorum(&self, bitmap: Vec<u8>, msg: Vec<u8>, agg_sig: Vec<u8>) -> bool {
+        let signers = match bitmap_indices(&bitmap, self.pks.len()) {
+            Some(s) => s,
+            None => return false,
+        };
+        let signed: u64 = signers.iter().map(|&i| self.weights[i]).sum();
+        is_bft_weight_quorum(signed, self.total_weight())
+            && verify_signers(&self.pks, &signers, &msg, &agg_sig)
+    }
+
+    /// The committee after removing `removed` and appending `added`.
+    ///
+    /// Remaining members keep their order and weights; added members get a
+    /// weight of 1. Returns `None` if a removed key is not a member, an
+    /// added key already is one, either list repeats a key, or nobody is
+    /// left.
+    fn apply_delta(&self, added: &[PublicKey], removed: &[PublicKey]) -> Option<CommitteeHandle> {
+        let repeats = |keys: &[PublicKey]| {
+            keys.iter()
+                .enumerate()
+                .any(|(i, pk)| keys[..i].contains(pk))
+        };
+        if repeats(added) || repeats(removed) {
+            return None;
+        }
+        if removed.iter().any(|pk| !self.pks.contains(pk))
+            || added.iter().any(|pk| self.pks.contains(pk))
+        {
+            return None;
+        }
+
+        let (mut pks, mut weights): (Vec<PublicKey>, Vec<u64>) = self
+            .pks
+            .iter()
+            .zip(&self.weights)
+            .filter(|(pk, _)| !removed.contains(pk))
+            .map(|(pk, &w)| (*pk, w))
+            .unzip();
+        pks.extend_from_slice(added);
+        weights.resize(pks.len(), 1);
+        CommitteeHandle::from_parts(pks, weights)
+    }
+
+    /// Aggregate-verify one certificate against the cached keys.
+    fn verify_certificate(&self, bitmap: &[u8], msg: &[u8], agg_sig: &[u8]) -> bool {
+        match bitmap_indices(bitmap, self.pks.len()) {
+            Some(signers) => verify_signers(&self.pks, &signers, msg, agg_sig),
+            None => false,
+        }
+    }
+}
+
+/// A committee whose members' keys are only valid for a bounded term.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct TimedCommittee {
+    pks: Vec<PublicKey>,
+    /// `(valid_from, valid_until)` per member.
+    terms: Vec<(u64, u64)>,
+}
+
+impl TimedCommittee {
+    /// Prepare a committee from `(pk, valid_from, valid_until)` triples, in
+    /// committee order. A key is valid at times `t` with
+    /// `valid_from <= t < valid_until`.
+    ///
+    /// Returns `None` for an empty committee, a malformed key, or a term
+    /// that ends before it starts.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(members: Vec<(Vec<u8>, u64, u64)>) -> Option<TimedCommittee> {
+        if members.is_empty() || members.iter().any(|(_, from, until)| from > until) {
+            return None;
+        }
+        let pks: Vec<Vec<u8>> = members.iter().map(|(pk, _, _)| pk.clone()).collect();
+        Some(TimedCommittee {
+            pks: parse_pks(&pks)?,
+            terms: members
+                .iter()
+                .map(|&(_, from, until)| (from, until))
+                .collect(),
+        })
+    }
+
+    /// Verify a certificate whose signers must all hold a valid key at `now`.
+    ///
+    /// * `bitmap`  – signer bitmap, in committee order
+    /// * `msg`     – the message the signers signed
+    /// * `agg_sig` – 96-byte compressed aggregate G2 signature
+    /// * `now`     – the time to check terms against, in the caller's units
+    ///
+    /// Returns `false` when any signer's key is outside its term at `now`,
+    /// a bit is out of range, or the aggregate signature does not verify.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn verify(&self, bitmap: Vec<u8>, msg: Vec<u8>, agg_sig: Vec<u8>, now: u64) -> bool {
+        let signers = match bitmap_indices(&bitmap, self.pks.len()) {
+            Some(s) => s,
+            None => return false,
+        };
+        let in_term = signers.iter().all(|&i| {
+            let (from, until) = self.terms[i];
+            from <= now && now < until
+        });
+        in_term && verify_signers(&self.pks, &signers, &msg, &agg_sig)
+    }
+}
+
+/// The aggregate public key of a fixed committee, for verifying many
+/// certificates that every member signed without re-aggregating the keys.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct CommitteeKey {
+    agg_pk: PublicKey,
+}
+
+impl CommitteeKey {
+    /// Aggregate a committee's 48-byte compressed G1 public keys once.
+    ///
+    /// Every key is validated here, so [`CommitteeKey::verify`] needs no
+    /// per-call key checks.
+    ///
+    /// Returns `None` for an empty committee, a malformed or invalid key, or
+    /// keys that sum to the identity.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(pks: Vec<Vec<u8>>) -> Option<CommitteeKey> {
+        let pks = parse_pks(&pks)?;
+        if pks.iter().any(|pk| pk.validate().is_err()) {
+            return None;
+        }
+        let refs: Vec<&PublicKey> = pks.iter().collect();
+        let agg_pk = aggregate_pks(&refs)?;
+        agg_pk.validate().ok()?;
+        Some(CommitteeKey { agg_pk })
+    }
+
+    /// The 48-byte compressed aggregate public key.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.agg_pk.to_bytes().to_vec()
+    }
+
+    /// Verify an aggregate signature by the whole committee.
+    ///
+    /// * `msg`           – the message every member signed
+    /// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+    ///
+    /// Returns `true` when the aggregate signature is valid, `false`
+    /// otherwise (including a malformed signature).
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn verify(&self, msg: Vec<u8>, agg_sig_bytes: Vec<u8>) -> bool {
+        match Signature::from_bytes(&agg_sig_bytes) {
+            // The key was validated on construction.
+            Ok(sig) if !is_identity_sig(&sig) => {
+                sig.verify(true, &msg, DST, &[], &self.agg_pk, false) == BLST_ERROR::BLST_SUCCESS
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Compute the on-wire size of a set of signatures before transmission.
+///
+/// * `num_sigs`   – number of signatures collected
+/// * `aggregated` – whether they are sent as one aggregate plus a bitmap
+///
+/// Returns `num_sigs * 96` for individual signatures, or `96` plus a
+/// `ceil(num_sigs / 8)`-byte signer bitmap for an aggregate.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_transmission_size(num_sigs: u32, aggregated: bool) -> u64 {
+    if aggregated {
+        (SIG_LEN + bitmap_len(num_sigs as usize)) as u64
+    } else {
+        num_sigs as u64 * SIG_LEN as u64
+    }
+}
+
+/// Verify an aggregate signature from a committee where members may have
+/// explicitly abstained.
+///
+/// * `committee_pks`  – 48-byte compressed G1 public keys, in committee order
+/// * `signed_bitmap`  – bitmap of members whose signatures are in `agg_sig`
+/// * `abstain_bitmap` – bitmap of members that explicitly abstained
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// The two bitmaps must be disjoint, and signers plus abstainers together
+/// must form a quorum (more than 2/3 of the committee) of participation.
+///
+/// Returns [`VerifyOutcome::OverlappingBitmaps`] when a member is in both
+/// bitmaps, [`VerifyOutcome::QuorumNotMet`] when participation is too low
+/// or nobody signed, and [`VerifyOutcome::MalformedInput`] on unparseable
+/// keys or out-of-range bits.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_with_abstentions(
+    committee_pks: Vec<Vec<u8>>,
+    signed_bitmap: Vec<u8>,
+    abstain_bitmap: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> VerifyOutcome {
+    let n = committee_pks.len();
+    let (signed, abstained) = match (
+        bitmap_indices(&signed_bitmap, n),
+        bitmap_indices(&abstain_bitmap, n),
+    ) {
+        (Some(s), Some(a)) => (s, a),
+        _ => return VerifyOutcome::MalformedInput,
+    };
+    if signed.iter().any(|i| abstained.contains(i)) {
+        return VerifyOutcome::OverlappingBitmaps;
+    }
+    if signed.is_empty() || !is_bft_quorum(signed.len() + abstained.len(), n) {
+        return VerifyOutcome::QuorumNotMet;
+    }
+
+    let pks = match parse_pks(&committee_pks) {
+        Some(p) => p,
+        None => return VerifyOutcome::MalformedInput,
+    };
+    if verify_signers(&pks, &signed, &msg, &agg_sig) {
+        VerifyOutcome::Valid
+    } else {
+        VerifyOutcome::InvalidSignature
+    }
+}
+
+/// Check that a validator took part in a certificate.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `validator_pk`  – the validator's 48-byte compressed public key
+/// * `bitmap`        – the certificate's signer bitmap
+///
+/// Returns `true` when `validator_pk` is a committee member and the bit at
+/// its committee position is set. Returns `false` when the validator is not
+/// in the committee or the bitmap has bits beyond the committee size.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_participation_proof(
+    committee_pks: Vec<Vec<u8>>,
+    validator_pk: Vec<u8>,
+    bitmap: Vec<u8>,
+) -> bool {
+    let position = match committee_pks.iter().position(|pk| *pk == validator_pk) {
+        Some(p) => p,
+        None => return false,
+    };
+    match bitmap_indices(&bitmap, committee_pks.len()) {
+        Some(signers) => signers.contains(&position),
+        None => false,
+    }
+}
+
+/// Verify a stream of certificates from the same committee.
+///
+/// * `committee` – prepared committee, see [`CommitteeHandle::new`]
+/// * `certs`     – `(bitmap, msg, agg_sig)` per certificate
+///
+/// Only the signatures are checked; quorum policy is up to the caller.
+///
+/// Returns one result per certificate, in input order. A certificate with
+/// no signers, an out-of-range bit, or a malformed signature is `false`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_many_certificates(
+    committee: &CommitteeHandle,
+    certs: Vec<(Vec<u8>, Vec<u8>, Vec<u8>)>,
+) -> Vec<bool> {
+    certs
+        .iter()
+        .map(|(bitmap, msg, agg_sig)| committee.verify_certificate(bitmap, msg, agg_sig))
+        .collect()
+}
+
+/// Verify several independent aggregate signatures, e.g. one per epoch,
+/// in one call.
+///
+/// * `committees` – per aggregate, the 48-byte compressed G1 public keys of
+///   its signers
+/// * `msgs`       – per aggregate, the message its signers signed
+/// * `agg_sigs`   – the 96-byte compressed aggregate G2 signatures
+///
+/// Each committee's keys are summed, then all aggregates are checked
+/// together with [`bls12381_min_pk_batch_verify`], so the cost is one
+/// random-coefficient batch rather than one pairing check per aggregate.
+///
+/// Returns `true` only when every aggregate verifies. Returns `false` when
+/// the three lists differ in length or are empty, a committee is empty, or
+/// any input is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aggregates(
+    committees: Vec<Vec<Vec<u8>>>,
+    msgs: Vec<Vec<u8>>,
+    agg_sigs: Vec<Vec<u8>>,
+) -> bool {
+    if committees.len() != msgs.len() || committees.len() != agg_sigs.len() {
+        return false;
+    }
+    let agg_pks: Option<Vec<Vec<u8>>> = committees
+        .iter()
+        .map(|pks| {
+            let pks = parse_pks(pks)?;
+            let refs: Vec<&PublicKey> = pks.iter().collect();
+            Some(aggregate_pks(&refs)?.to_bytes().to_vec())
+        })
+        .collect();
+    match agg_pks {
+        Some(agg_pks) => bls12381_min_pk_batch_verify(agg_pks, msgs, agg_sigs),
+        None => false,
+    }
+}
+
+/// Commitment to a committee: SHA-256 over its compressed keys in order.
+pub(crate) fn digest_of(pks: &[PublicKey]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(pks.len() * 48);
+    for pk in pks {
+        buf.extend_from_slice(&pk.to_bytes());
+    }
+    sha256(&buf)
+}
+
+/// Compute the 32-byte digest that identifies a committee.
+///
+/// * `pks` – 48-byte compressed G1 public keys, in committee order
+///
+/// The digest is `SHA-256(pk_0 || pk_1 || ... || pk_{n-1})` over the
+/// canonical compressed encodings, so it changes if any key or the order
+/// changes.
+///
+/// Returns the 32-byte digest, or an empty `Vec<u8>` for an empty committee
+/// or a malformed key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_committee_digest(pks: Vec<Vec<u8>>) -> Vec<u8> {
+    match parse_pks(&pks) {
+        Some(parsed) if !parsed.is_empty() => digest_of(&parsed).to_vec(),
+        _ => vec![],
+    }
+}
+
+/// The one-time setup a verifier does per committee.
+pub struct CommitteeSetup {
+    /// 48-byte compressed aggregate of all committee keys.
+    pub aggregate_pk: Vec<u8>,
+    /// 32-byte committee digest, see [`bls12381_committee_digest`].
+    pub digest: Vec<u8>,
+}
+
+/// Compute a committee's aggregate public key and digest together.
+///
+/// * `pks` – 48-byte compressed G1 public keys, in committee order
+///
+/// Returns `None` for an empty committee or a malformed key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_committee_setup(pks: Vec<Vec<u8>>) -> Option<CommitteeSetup> {
+    let parsed = parse_pks(&pks)?;
+    let refs: Vec<&PublicKey> = parsed.iter().collect();
+    let aggregate_pk = aggregate_pks(&refs)?;
+    Some(CommitteeSetup {
+        aggregate_pk: aggregate_pk.to_bytes().to_vec(),
+        digest: digest_of(&parsed).to_vec(),
+    })
+}
+
+/// Aggregate the public keys of the committee members selected by `bitmap`.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`        – signer bitmap selecting the subset
+///
+/// The result can be reused with [`crate::api::bls::bls12381_min_pk_verify`]
+/// to check several aggregate signatures, over different messages, from the
+/// same signer subset.
+///
+/// Returns the 48-byte aggregate key, or an empty `Vec<u8>` when no bit is
+/// set, a bit is out of range, or a key is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_subset_aggregate_pk(committee_pks: Vec<Vec<u8>>, bitmap: Vec<u8>) -> Vec<u8> {
+    let signers = match bitmap_indices(&bitmap, committee_pks.len()) {
+        Some(s) => s,
+        None => return vec![],
+    };
+    let selected: Vec<Vec<u8>> = signers.iter().map(|&i| committee_pks[i].clone()).collect();
+    let pks = match parse_pks(&selected) {
+        Some(p) => p,
+        None => return vec![],
+    };
+    let refs: Vec<&PublicKey> = pks.iter().collect();
+    match aggregate_pks(&refs) {
+        Some(agg) => agg.to_bytes().to_vec(),
+        None => vec![],
+    }
+}
+
+/// Verify a same-message aggregate from the committee members selected by a
+/// signer bitmap.
+///
+/// * `all_pks`       – 48-byte compressed G1 public keys, in committee order
+/// * `signer_bitmap` – little-endian bitmap, bit `i` set when member `i`
+///   signed
+/// * `msg`           – the message every signer signed
+/// * `agg_sig_bytes` – 96-byte compressed aggregate G2 signature
+///
+/// Bits are numbered from the least significant bit of the first byte, the
+/// same indexing as the rest of this module.
+///
+/// Returns `true` when the aggregate verifies against the selected keys,
+/// `false` when no bit is set, a bit is out of range, an input is
+/// malformed, or the aggregate is invalid.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aggregate_bitmap(
+    all_pks: Vec<Vec<u8>>,
+    signer_bitmap: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig_bytes: Vec<u8>,
+) -> bool {
+    let signers = match bitmap_indices(&signer_bitmap, all_pks.len()) {
+        Some(s) if !s.is_empty() => s,
+        _ => return false,
+    };
+    let selected: Vec<Vec<u8>> = signers.iter().map(|&i| all_pks[i].clone()).collect();
+    bls12381_min_pk_verify_aggregate(selected, msg, agg_sig_bytes)
+}
+
+/// Check that the members selected by a signer bitmap carry enough weight.
+///
+/// * `signer_bitmap` – signer bitmap, indexed like
+///   [`bls12381_min_pk_verify_aggregate_bitmap`]
+/// * `weights`       – voting weight of each member, in committee order
+/// * `threshold`     – minimum total weight for a quorum
+///
+/// Returns `true` when the set members' weights sum to at least
+/// `threshold`, `false` otherwise or when a set bit is out of range.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_quorum_met(signer_bitmap: Vec<u8>, weights: Vec<u64>, threshold: u64) -> bool {
+    match bitmap_indices(&signer_bitmap, weights.len()) {
+        // Summed as u128 so large weights can't overflow.
+        Some(signers) => {
+            signers.iter().map(|&i| weights[i] as u128).sum::<u128>() >= threshold as u128
+        }
+        None => false,
+    }
+}
+
+/// Verify a certificate and check it was signed by exactly the expected
+/// validators.
+///
+/// * `committee_pks`  – 48-byte compressed G1 public keys, in committee order
+/// * `expected_order` – committee indices of the validators expected to sign
+/// * `bitmap`         – the certificate's signer bitmap
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// Aggregation is commutative, so `expected_order` is compared as a set:
+/// its order and any repeated entries are ignored.
+///
+/// Returns `false` when the bitmap's signers differ from `expected_order`
+/// in either direction, or the aggregate signature does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_signer_order(
+    committee_pks: Vec<Vec<u8>>,
+    expected_order: Vec<u32>,
+    bitmap: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    let signers = match bitmap_indices(&bitmap, committee_pks.len()) {
+        Some(s) => s,
+        None => return false,
+    };
+    let mut expected: Vec<usize> = expected_order.iter().map(|&i| i as usize).collect();
+    expected.sort_unstable();
+    expected.dedup();
+    // `signers` is already ascending and unique.
+    if signers != expected {
+        return false;
+    }
+
+    match parse_pks(&committee_pks) {
+        Some(pks) => verify_signers(&pks, &signers, &msg, &agg_sig),
+        None => false,
+    }
+}
+
+// Fault models accepted by `bls12381_verify_bft`.
+const FAULT_MODEL_CRASH: u8 = 0;
+const FAULT_MODEL_BYZANTINE: u8 = 1;
+
+/// Verify a certificate under a selectable fault-tolerance model.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`        – the certificate's signer bitmap
+/// * `msg`           – the message the signers signed
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+/// * `fault_model`   – `0` = crash faults, needs more than 1/2 of the
+///   committee; `1` = Byzantine faults, needs more than 2/3
+///
+/// Returns `false` for an unknown `fault_model`, too few signers for the
+/// selected model, or an aggregate signature that does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_bft(
+    committee_pks: Vec<Vec<u8>>,
+    bitmap: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+    fault_model: u8,
+) -> bool {
+    let n = committee_pks.len();
+    let signers = match bitmap_indices(&bitmap, n) {
+        Some(s) => s,
+        None => return false,
+    };
+    let quorum = match fault_model {
+        FAULT_MODEL_CRASH => 2 * signers.len() > n,
+        FAULT_MODEL_BYZANTINE => is_bft_quorum(signers.len(), n),
+        _ => false,
+    };
+    if !quorum {
+        return false;
+    }
+
+    match parse_pks(&committee_pks) {
+        Some(pks) => verify_signers(&pks, &signers, &msg, &agg_sig),
+        None => false,
+    }
+}
+
+/// Length of a certificate receipt id.
+const RECEIPT_LEN: usize = 16;
+
+/// Verify an aggregate signature and derive a receipt id for it.
+///
+/// * `pks`     – 48-byte compressed G1 public keys of the signers, in order
+/// * `msg`     – the message the signers signed
+/// * `agg_sig` – aggregate G2 signature, 96-byte compressed or 192-byte
+///   uncompressed
+///
+/// The receipt id is the first 16 bytes of
+/// `SHA-256(committee_digest || agg_sig || msg)`, where `committee_digest`
+/// is [`bls12381_committee_digest`] of `pks` and `agg_sig` is re-encoded in
+/// its 96-byte compressed form. The same certificate always yields the same
+/// id, whichever encoding the signature arrives in, so it can serve as a
+/// cache or dedup key.
+///
+/// Returns the 16-byte receipt id, or an empty `Vec<u8>` when the
+/// signature does not verify or an input is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_aggregate_receipt(
+    pks: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> Vec<u8> {
+    let parsed = match parse_pks(&pks) {
+        Some(p) => p,
+        None => return vec![],
+    };
+    let refs: Vec<&PublicKey> = parsed.iter().collect();
+    if !fast_aggregate_verify(&refs, &msg, &agg_sig) {
+        return vec![];
+    }
+    let sig = match Signature::from_bytes(&agg_sig) {
+        Ok(s) => s,
+        Err(_) => return vec![],
+    };
+
+    // The digest and compressed signature are fixed-length, so putting the
+    // message last keeps the preimage unambiguous.
+    let mut buf = Vec::with_capacity(32 + SIG_LEN + msg.len());
+    buf.extend_from_slice(&digest_of(&parsed));
+    buf.extend_from_slice(&sig.to_bytes());
+    buf.extend_from_slice(&msg);
+    sha256(&buf)[..RECEIPT_LEN].to_vec()
+}
+
+/// Encode a signer set as the shortest bitmap that represents it.
+///
+/// * `signer_indices` – committee indices of the signers, in any order
+/// * `committee_size` – number of committee members
+///
+/// Bits follow the module's little-endian layout and trailing zero bytes
+/// are trimmed, so equal signer sets always encode to the same bytes.
+/// Repeated indices are set once.
+///
+/// Returns the bitmap, or an empty `Vec<u8>` when an index is at or beyond
+/// `committee_size`. An empty signer set also encodes as an empty bitmap.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_encode_bitmap(signer_indices: Vec<u32>, committee_size: u32) -> Vec<u8> {
+    if signer_indices.iter().any(|&i| i >= committee_size) {
+        return vec![];
+    }
+    let len = signer_indices
+        .iter()
+        .max()
+        .map_or(0, |&max| max as usize / 8 + 1);
+    let mut bitmap = vec![0u8; len];
+    for &i in &signer_indices {
+        bitmap[i as usize / 8] |= 1 << (i % 8);
+    }
+    bitmap
+}
+
+/// Verify a certificate from the committee formed by applying an epoch
+/// delta to the previous committee.
+///
+/// * `prev_committee` – the previous epoch's committee
+/// * `added`          – 48-byte compressed G1 keys of new members
+/// * `removed`        – 48-byte compressed G1 keys of departing members
+/// * `bitmap`         – signer bitmap over the current committee
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// The current committee is the previous one, in order, without the
+/// removed members, followed by the added members in the order given.
+/// Added members get a voting weight of 1. The signers must hold more than
+/// 2/3 of the current committee's weight, as in
+/// [`CommitteeHandle::verify_quorum`].
+///
+/// Returns `false` when a removed key is not in the previous committee, an
+/// added key already is, a key is malformed or repeated, the delta leaves
+/// the committee empty, or the certificate does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_with_committee_delta(
+    prev_committee: &CommitteeHandle,
+    added: Vec<Vec<u8>>,
+    removed: Vec<Vec<u8>>,
+    bitmap: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    let (added, removed) = match (parse_pks(&added), parse_pks(&removed)) {
+        (Some(a), Some(r)) => (a, r),
+        _ => return false,
+    };
+    match prev_committee.apply_delta(&added, &removed) {
+        Some(current) => current.verify_quorum(bitmap, msg, agg_sig),
+        None => false,
+    }
+}
+
+/// Put a committee's public keys in canonical order.
+///
+/// * `pks` – 48-byte compressed or 96-byte uncompressed G1 public keys
+///
+/// Every key is re-encoded in 48-byte compressed form and the list is
+/// sorted ascending by those bytes, so mixed encodings of the same
+/// committee always sort the same way. Duplicates are kept. Keys are
+/// decoded but not subgroup-checked. The result is the order
+/// [`bls12381_committee_contains`] expects.
+///
+/// Returns the sorted compressed keys, or an empty `Vec` when any key is
+/// malformed. A bad key is never silently dropped, as that would shift
+/// every later committee index.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sort_pubkeys(pks: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    let Some(parsed) = parse_pks(&pks) else {
+        return vec![];
+    };
+    let mut sorted: Vec<Vec<u8>> = parsed.iter().map(|pk| pk.to_bytes().to_vec()).collect();
+    sorted.sort();
+    sorted
+}
+
+/// Check committee membership by binary search.
+///
+/// * `sorted_committee_pks` – 48-byte compressed G1 public keys, sorted
+///   ascending by their bytes
+/// * `pk`                   – 48-byte compressed public key to look up
+///
+/// Runs in O(log n) byte comparisons and never decompresses a key. The
+/// result is unspecified if the committee is not sorted.
+///
+/// Returns `false` when `pk` is not 48 bytes or is not in the committee.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_committee_contains(sorted_committee_pks: Vec<Vec<u8>>, pk: Vec<u8>) -> bool {
+    pk.len() == PK_LEN && sorted_committee_pks.binary_search(&pk).is_ok()
+}
+
+/// A certificate in canonical byte form.
+pub struct CanonicalCertificate {
+    /// Minimal signer bitmap, see [`bls12381_encode_bitmap`].
+    pub bitmap: Vec<u8>,
+    /// 96-byte compressed aggregate G2 signature.
+    pub aggregate: Vec<u8>,
+}
+
+/// Assemble individual committee signatures into a canonical certificate.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `signer_sigs`   – `(committee_index, 96-byte signature)` per signer, in
+///   any order
+///
+/// Aggregation is commutative and the bitmap is minimal, so any ordering of
+/// the same signatures yields byte-identical certificates. The individual
+/// signatures are not verified.
+///
+/// Returns `None` when there are no signers, an index is out of range or
+/// repeated, or a signature is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_canonical_aggregate(
+    committee_pks: Vec<Vec<u8>>,
+    signer_sigs: Vec<(u32, Vec<u8>)>,
+) -> Option<CanonicalCertificate> {
+    let mut signer_sigs = signer_sigs;
+    signer_sigs.sort_by_key(|(i, _)| *i);
+    if signer_sigs.windows(2).any(|w| w[0].0 == w[1].0) {
+        return None;
+    }
+
+    let indices: Vec<u32> = signer_sigs.iter().map(|(i, _)| *i).collect();
+    let bitmap = bls12381_encode_bitmap(indices, committee_pks.len() as u32);
+    if bitmap.is_empty() {
+        return None;
+    }
+    let aggregate = bls12381_min_pk_aggregate(signer_sigs.into_iter().map(|(_, s)| s).collect());
+    if aggregate.is_empty() {
+        return None;
+    }
+    Some(CanonicalCertificate { bitmap, aggregate })
+}
+
+/// Domain tag prefixed to committee change proposals.
+const COMMITTEE_CHANGE_TAG: &[u8] = b"BLS_COMMITTEE_CHANGE_V1";
+
+/// Canonical bytes of a proposed committee: the domain tag, `u32_le(n)`,
+/// then `pk || u64_le(weight)` per member sorted by compressed key.
+///
+/// Returns `None` for an empty proposal, mismatched lengths, a malformed
+/// key, or a repeated key.
+fn committee_change_bytes(pks: &[Vec<u8>], weights: &[u64]) -> Option<Vec<u8>> {
+    if pks.is_empty() || pks.len() != weights.len() {
+        return None;
+    }
+    let n = u32::try_from(pks.len()).ok()?;
+    let mut members: Vec<([u8; 48], u64)> = parse_pks(pks)?
+        .iter()
+        .map(PublicKey::to_bytes)
+        .zip(weights.iter().copied())
+        .collect();
+    members.sort_unstable_by_key(|m| m.0);
+    if members.windows(2).any(|w| w[0].0 == w[1].0) {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(COMMITTEE_CHANGE_TAG.len() + 4 + members.len() * 56);
+    out.extend_from_slice(COMMITTEE_CHANGE_TAG);
+    out.extend_from_slice(&n.to_le_bytes());
+    for (pk, weight) in &members {
+        out.extend_from_slice(pk);
+        out.extend_from_slice(&weight.to_le_bytes());
+    }
+    Some(out)
+}
+
+/// The message an outgoing committee signs to approve a new committee.
+///
+/// * `proposed_pks`     – 48-byte compressed G1 keys of the new committee
+/// * `proposed_weights` – voting weight per proposed key
+///
+/// The layout is `"BLS_COMMITTEE_CHANGE_V1" || u32_le(n)` followed by
+/// `pk || u64_le(weight)` for each member, sorted by compressed key, so
+/// the input order does not matter.
+///
+/// Returns the message, or an empty `Vec<u8>` for an empty proposal,
+/// mismatched lengths, or a malformed or repeated key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_committee_change_message(
+    proposed_pks: Vec<Vec<u8>>,
+    proposed_weights: Vec<u64>,
+) -> Vec<u8> {
+    committee_change_bytes(&proposed_pks, &proposed_weights).unwrap_or_default()
+}
+
+/// Verify that the outgoing committee approved a new committee.
+///
+/// * `old_committee`    – the current committee
+/// * `proposed_pks`     – 48-byte compressed G1 keys of the new committee
+/// * `proposed_weights` – voting weight per proposed key
+/// * `bitmap`           – signer bitmap over `old_committee`
+/// * `agg_sig`          – 96-byte compressed aggregate G2 signature over
+///   [`bls12381_committee_change_message`]
+///
+/// The signers must hold more than 2/3 of the old committee's weight, as in
+/// [`CommitteeHandle::verify_quorum`].
+///
+/// Returns `false` when the proposal is invalid, the quorum is not met, or
+/// the aggregate signature does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_committee_change(
+    old_committee: &CommitteeHandle,
+    proposed_pks: Vec<Vec<u8>>,
+    proposed_weights: Vec<u64>,
+    bitmap: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    match committee_change_bytes(&proposed_pks, &proposed_weights) {
+        Some(msg) => old_committee.verify_quorum(bitmap, msg, agg_sig),
+        None => false,
+    }
+}
+
+/// Verify a certificate against a committee referenced by its digest.
+///
+/// * `committee_hash` – expected 32-byte [`bls12381_committee_digest`]
+/// * `provided_pks`   – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`         – signer bitmap over `provided_pks`
+/// * `msg`            – the message the signers signed
+/// * `agg_sig`        – 96-byte compressed aggregate G2 signature
+///
+/// The provided keys are only trusted when their digest matches
+/// `committee_hash`, so a substituted or reordered key is rejected before
+/// any signature check. Quorum policy is up to the caller.
+///
+/// Returns `false` on a digest mismatch, a malformed key, an out-of-range
+/// bit, no signers, or an aggregate signature that does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_by_committee_hash(
+    committee_hash: Vec<u8>,
+    provided_pks: Vec<Vec<u8>>,
+    bitmap: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    let pks = match parse_pks(&provided_pks) {
+        Some(p) if !p.is_empty() => p,
+        _ => return false,
+    };
+    if digest_of(&pks)[..] != committee_hash[..] {
+        return false;
+    }
+    match bitmap_indices(&bitmap, pks.len()) {
+        Some(signers) => verify_signers(&pks, &signers, &msg, &agg_sig),
+        None => false,
+    }
+}
+
+/// Verify a certificate whose signers are given as a Roaring bitmap.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `roaring_bytes` – signer set in the portable Roaring serialization
+/// * `msg`           – the message the signers signed
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+///
+/// Roaring compresses sparse or clustered signer sets in very large
+/// committees far below the `ceil(n / 8)` bytes of a plain bitmap. Quorum
+/// policy is up to the caller.
+///
+/// Returns `false` when the bitmap does not decode, a signer index is out
+/// of range, there are no signers, or the aggregate does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_roaring(
+    committee_pks: Vec<Vec<u8>>,
+    roaring_bytes: Vec<u8>,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    let n = committee_pks.len();
+    let signers: Vec<usize> = match roaring::decode(&roaring_bytes, n) {
+        Some(values) => values.into_iter().map(|i| i as usize).collect(),
+        None => return false,
+    };
+    match parse_pks(&committee_pks) {
+        Some(pks) => verify_signers(&pks, &signers, &msg, &agg_sig),
+        None => false,
+    }
+}
+
+/// Map a certificate's bitmap back to committee indices.
+///
+/// * `committee_pks` – 48-byte compressed G1 public keys, in committee order
+/// * `bitmap`        – the certificate's signer bitmap
+///
+/// Only the committee size is used; the keys are not parsed.
+///
+/// Returns the signers' committee indices in ascending order, or an empty
+/// `Vec` when a bit is out of range (or no bit is set).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_resolve_signers(committee_pks: Vec<Vec<u8>>, bitmap: Vec<u8>) -> Vec<u32> {
+    bitmap_indices(&bitmap, committee_pks.len())
+        .map(|signers| signers.into_iter().map(|i| i as u32).collect())
+        .unwrap_or_default()
+}
+
+/// Basis points in a whole.
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Verify a certificate against a stake threshold over a flat key array.
+///
+/// * `pks_concat`    – committee keys as consecutive 48-byte compressed G1
+///   points, in committee order
+/// * `stakes`        – stake per committee position
+/// * `signer_bitmap` – signer bitmap, in committee order
+/// * `threshold_bps` – required share of total stake in basis points, e.g.
+///   `6667` for two thirds
+/// * `msg`           – the message the signers signed
+/// * `agg_sig`       – 96-byte compressed aggregate G2 signature
+///
+/// The signers' stake must satisfy
+/// `signed * 10_000 >= total * threshold_bps`, computed in `u128`.
+///
+/// Returns `false` when `pks_concat` is not a whole number of keys or does
+/// not match `stakes`, `threshold_bps` exceeds `10_000`, the total stake is
+/// zero, the threshold is not met, or the aggregate does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_stake_quorum_flat(
+    pks_concat: Vec<u8>,
+    stakes: Vec<u64>,
+    signer_bitmap: Vec<u8>,
+    threshold_bps: u32,
+    msg: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    if !pks_concat.len().is_multiple_of(PK_LEN)
+        || pks_concat.len() / PK_LEN != stakes.len()
+        || threshold_bps as u128 > BPS_DENOMINATOR
+    {
+        return false;
+    }
+    let signers = match bitmap_indices(&signer_bitmap, stakes.len()) {
+        Some(s) => s,
+        None => return false,
+    };
+    let total: u128 = stakes.iter().map(|&s| s as u128).sum();
+    let signed: u128 = signers.iter().map(|&i| stakes[i] as u128).sum();
+    if total == 0 || signed * BPS_DENOMINATOR < total * threshold_bps as u128 {
+        return false;
+    }
+
+    let pks: Vec<Vec<u8>> = pks_concat
+        .chunks_exact(PK_LEN)
+        .map(<[u8]>::to_vec)
+        .collect();
+    match parse_pks(&pks) {
+        Some(parsed) => verify_signers(&parsed, &signers, &msg, &agg_sig),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bls::bls12381_min_pk_verify;
+    use crate::test_utils::{agg_sign, committee, pks_of, sign_msg};
+
+    // ---- transmission size ----
+
+    #[test]
+    fn transmission_size_individual() {
+        assert_eq!(bls12381_transmission_size(0, false), 0);
+        assert_eq!(bls12381_transmission_size(1, false), 96);
+        assert_eq!(bls12381_transmission_size(10, false), 960);
+    }
+
+    #[test]
+    fn transmission_size_aggregated() {
+        assert_eq!(bls12381_transmission_size(1, true), 97);
+        assert_eq!(bls12381_transmission_size(8, true), 97);
+        assert_eq!(bls12381_transmission_size(9, true), 98);
+    }
+
+    #[test]
+    fn transmission_size_aggregation_saves_bandwidth() {
+        let individual = bls12381_transmission_size(1000, false);
+        let aggregated = bls12381_transmission_size(1000, true);
+        assert_eq!(individual, 96_000);
+        assert_eq!(aggregated, 96 + 125);
+        assert!(aggregated * 100 < individual);
+    }
+
+    #[test]
+    fn transmission_size_no_overflow() {
+        assert_eq!(
+            bls12381_transmission_size(u32::MAX, false),
+            u32::MAX as u64 * 96
+        );
+    }
+
+    // ---- bitmaps ----
+
+    #[test]
+    fn bitmap_indices_little_endian() {
+        assert_eq!(bitmap_indices(&[0b0000_0101, 0b1], 9), Some(vec![0, 2, 8]));
+        assert_eq!(bitmap_indices(&[0b0000_0101, 0], 3), Some(vec![0, 2]));
+        assert_eq!(bitmap_indices(&[], 3), Some(vec![]));
+    }
+
+    #[test]
+    fn bitmap_indices_out_of_range() {
+        assert_eq!(bitmap_indices(&[0b0000_1000], 3), None);
+    }
+
+    // ---- abstentions ----
+
+    #[test]
+    fn abstentions_disjoint_bitmaps() {
+        let keys = committee("abstain", 6);
+        let msg = b"abstain round";
+        let agg = agg_sign(&keys, &[0, 1, 2], msg);
+
+        // Members 0-2 signed, 3-4 abstained, 5 was silent: 5/6 participated.
+        assert_eq!(
+            bls12381_verify_with_abstentions(
+                pks_of(&keys),
+                vec![0b0000_0111],
+                vec![0b0001_1000],
+                msg.to_vec(),
+                agg,
+            ),
+            VerifyOutcome::Valid
+        );
+    }
+
+    #[test]
+    fn abstentions_overlapping_bitmaps() {
+        let keys = committee("abstain", 6);
+        let msg = b"abstain round";
+        let agg = agg_sign(&keys, &[0, 1, 2], msg);
+
+        assert_eq!(
+            bls12381_verify_with_abstentions(
+                pks_of(&keys),
+                vec![0b0000_0111],
+                vec![0b0001_1100],
+                msg.to_vec(),
+                agg,
+            ),
+            VerifyOutcome::OverlappingBitmaps
+        );
+    }
+
+    #[test]
+    fn abstentions_participation_below_quorum() {
+        let keys = committee("abstain", 6);
+        let msg = b"abstain round";
+        let agg = agg_sign(&keys, &[0, 1, 2], msg);
+
+        // 3 signed + 1 abstained = 4/6, which is not more than 2/3.
+        assert_eq!(
+            bls12381_verify_with_abstentions(
+                pks_of(&keys),
+                vec![0b0000_0111],
+                vec![0b0000_1000],
+                msg.to_vec(),
+                agg,
+            ),
+            VerifyOutcome::QuorumNotMet
+        );
+    }
+
+    #[test]
+    fn abstentions_wrong_message() {
+        let keys = committee("abstain", 6);
+        let agg = agg_sign(&keys, &[0, 1, 2], b"abstain round");
+
+        assert_eq!(
+            bls12381_verify_with_abstentions(
+                pks_of(&keys),
+                vec![0b0000_0111],
+                vec![0b0001_1000],
+                b"another round".to_vec(),
+                agg,
+            ),
+            VerifyOutcome::InvalidSignature
+        );
+    }
+
+    // ---- participation proof ----
+
+    #[test]
+    fn participation_proof_participating() {
+        let pks = pks_of(&committee("participation", 4));
+        assert!(bls12381_participation_proof(
+            pks.clone(),
+            pks[2].clone(),
+            vec![0b0000_0101],
+        ));
+    }
+
+    #[test]
+    fn participation_proof_not_participating() {
+        let pks = pks_of(&committee("participation", 4));
+        assert!(!bls12381_participation_proof(
+            pks.clone(),
+            pks[1].clone(),
+            vec![0b0000_0101],
+        ));
+    }
+
+    #[test]
+    fn participation_proof_not_in_committee() {
+        let pks = pks_of(&committee("participation", 4));
+        let outsider = pks_of(&committee("outsider", 1)).remove(0);
+        assert!(!bls12381_participation_proof(pks, outsider, vec![0xff]));
+    }
+
+    #[test]
+    fn participation_proof_out_of_range_bitmap() {
+        let pks = pks_of(&committee("participation", 4));
+        assert!(!bls12381_participation_proof(
+            pks.clone(),
+            pks[0].clone(),
+            vec![0b0001_0001],
+        ));
+    }
+
+    // ---- committee handle ----
+
+    #[test]
+    fn committee_handle_rejects_bad_input() {
+        assert!(CommitteeHandle::new(vec![]).is_none());
+        assert!(CommitteeHandle::new(vec![vec![0u8; 10]]).is_none());
+    }
+
+    #[test]
+    fn committee_handle_weights() {
+        let keys = committee("weights", 2);
+        let pks: Vec<PublicKey> = keys.iter().map(|(_, pk)| *pk).collect();
+        assert!(CommitteeHandle::from_parts(pks.clone(), vec![1]).is_none());
+        assert!(CommitteeHandle::from_parts(pks.clone(), vec![u64::MAX, 1]).is_none());
+
+        let handle = CommitteeHandle::from_parts(pks, vec![3, 4]).unwrap();
+        assert_eq!(handle.size(), 2);
+        assert_eq!(handle.total_weight(), 7);
+    }
+
+    #[test]
+    fn committee_handle_verify_quorum_by_count() {
+        let keys = committee("quorum", 4);
+        let handle = CommitteeHandle::new(pks_of(&keys)).unwrap();
+        let msg = b"quorum".to_vec();
+
+        let three = agg_sign(&keys, &[0, 1, 2], &msg);
+        assert!(handle.verify_quorum(vec![0b0111], msg.clone(), three));
+
+        // 2 of 4 is not more than 2/3.
+        let two = agg_sign(&keys, &[0, 1], &msg);
+        assert!(!handle.verify_quorum(vec![0b0011], msg, two));
+    }
+
+    #[test]
+    fn verify_many_certificates_ten() {
+        let keys = committee("many-certs", 5);
+        let handle = CommitteeHandle::new(pks_of(&keys)).unwrap();
+
+        let certs: Vec<(Vec<u8>, Vec<u8>, Vec<u8>)> = (0..10u8)
+            .map(|i| {
+                let msg = format!("certificate #{i}").into_bytes();
+                // Rotate through different signer subsets.
+                let signers: Vec<usize> = (0..5)
+                    .filter(|j| !(j + i as usize).is_multiple_of(3))
+                    .collect();
+                let bitmap = signers.iter().fold(0u8, |b, &j| b | (1 << j));
+                let agg = agg_sign(&keys, &signers, &msg);
+                (vec![bitmap], msg, agg)
+            })
+            .collect();
+
+        assert_eq!(
+            bls12381_verify_many_certificates(&handle, certs),
+            vec![true; 10]
+        );
+    }
+
+    #[test]
+    fn verify_many_certificates_mixed() {
+        let keys = committee("many-certs", 5);
+        let handle = CommitteeHandle::new(pks_of(&keys)).unwrap();
+        let msg = b"certificate".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+
+        let certs = vec![
+            (vec![0b0000_0111], msg.clone(), agg.clone()),
+            (vec![0b0000_1111], msg.clone(), agg.clone()),
+            (vec![0b0010_0000], msg.clone(), agg.clone()),
+            (vec![0], msg.clone(), agg.clone()),
+            (vec![0b0000_0111], b"other".to_vec(), agg),
+        ];
+        assert_eq!(
+            bls12381_verify_many_certificates(&handle, certs),
+            vec![true, false, false, false, false]
+        );
+    }
+
+    // ---- independent aggregates ----
+
+    #[test]
+    fn verify_aggregates_two_epochs() {
+        let epoch1 = committee("epoch-1", 4);
+        let epoch2 = committee("epoch-2", 3);
+        let msgs = vec![b"epoch 1 cert".to_vec(), b"epoch 2 cert".to_vec()];
+        let sigs = vec![
+            agg_sign(&epoch1, &[0, 1, 3], &msgs[0]),
+            agg_sign(&epoch2, &[0, 1, 2], &msgs[1]),
+        ];
+        let pks1 = pks_of(&epoch1);
+        let committees = vec![
+            vec![pks1[0].clone(), pks1[1].clone(), pks1[3].clone()],
+            pks_of(&epoch2),
+        ];
+
+        assert!(bls12381_min_pk_verify_aggregates(
+            committees.clone(),
+            msgs.clone(),
+            sigs.clone()
+        ));
+
+        let mut wrong = msgs.clone();
+        wrong[1] = b"epoch 3 cert".to_vec();
+        assert!(!bls12381_min_pk_verify_aggregates(
+            committees.clone(),
+            wrong,
+            sigs.clone()
+        ));
+    }
+
+    #[test]
+    fn verify_aggregates_bad_shapes() {
+        let keys = committee("epoch-1", 2);
+        let msg = b"epoch 1 cert".to_vec();
+        let sig = agg_sign(&keys, &[0, 1], &msg);
+
+        assert!(!bls12381_min_pk_verify_aggregates(
+            vec![pks_of(&keys)],
+            vec![msg.clone(), msg.clone()],
+            vec![sig.clone()]
+        ));
+        assert!(!bls12381_min_pk_verify_aggregates(
+            vec![vec![]],
+            vec![msg.clone()],
+            vec![sig.clone()]
+        ));
+        assert!(!bls12381_min_pk_verify_aggregates(vec![], vec![], vec![]));
+    }
+
+    // ---- committee digest / setup ----
+
+    #[test]
+    fn committee_digest_depends_on_order() {
+        let mut pks = pks_of(&committee("digest", 3));
+        let digest = bls12381_committee_digest(pks.clone());
+        assert_eq!(digest.len(), 32);
+        assert_eq!(digest, bls12381_committee_digest(pks.clone()));
+
+        pks.swap(0, 1);
+        assert_ne!(digest, bls12381_committee_digest(pks));
+    }
+
+    #[test]
+    fn committee_digest_bad_input() {
+        assert!(bls12381_committee_digest(vec![]).is_empty());
+        assert!(bls12381_committee_digest(vec![vec![1u8; 48]]).is_empty());
+    }
+
+    #[test]
+    fn committee_setup_matches_individual_functions() {
+        let keys = committee("setup", 4);
+        let pks = pks_of(&keys);
+        let setup = bls12381_committee_setup(pks.clone()).unwrap();
+
+        let refs: Vec<&PublicKey> = keys.iter().map(|(_, pk)| pk).collect();
+        let expected_agg = aggregate_pks(&refs).unwrap();
+        assert_eq!(setup.aggregate_pk, expected_agg.to_bytes().to_vec());
+        assert_eq!(setup.digest, bls12381_committee_digest(pks));
+    }
+
+    #[test]
+    fn committee_setup_bad_input() {
+        assert!(bls12381_committee_setup(vec![]).is_none());
+        assert!(bls12381_committee_setup(vec![vec![0u8; 47]]).is_none());
+    }
+
+    // ---- subset aggregate key ----
+
+    #[test]
+    fn subset_aggregate_pk_verifies_subset_signature() {
+        let keys = committee("subset", 5);
+        let agg_pk = bls12381_subset_aggregate_pk(pks_of(&keys), vec![0b0001_0101]);
+        assert_eq!(agg_pk.len(), 48);
+
+        for msg in [b"first".as_slice(), b"second".as_slice()] {
+            let agg_sig = agg_sign(&keys, &[0, 2, 4], msg);
+            assert!(bls12381_min_pk_verify(
+                agg_sig,
+                agg_pk.clone(),
+                msg.to_vec()
+            ));
+        }
+
+        // A different subset's signature does not verify.
+        let other = agg_sign(&keys, &[0, 2], b"first");
+        assert!(!bls12381_min_pk_verify(other, agg_pk, b"first".to_vec()));
+    }
+
+    #[test]
+    fn subset_aggregate_pk_bad_bitmap() {
+        let pks = pks_of(&committee("subset", 5));
+        assert!(bls12381_subset_aggregate_pk(pks.clone(), vec![0]).is_empty());
+        assert!(bls12381_subset_aggregate_pk(pks.clone(), vec![]).is_empty());
+        assert!(bls12381_subset_aggregate_pk(pks, vec![0b0010_0001]).is_empty());
+    }
+
+    // ---- bitmap verify ----
+
+    #[test]
+    fn verify_aggregate_bitmap_selects_signers() {
+        let keys = committee("bitmap-verify", 5);
+        let pks = pks_of(&keys);
+        let msg = b"bitmap cert".to_vec();
+        let agg = agg_sign(&keys, &[0, 2, 4], &msg);
+
+        assert!(bls12381_min_pk_verify_aggregate_bitmap(
+            pks.clone(),
+            vec![0b0001_0101],
+            msg.clone(),
+            agg.clone()
+        ));
+        // Trailing zero bytes are fine; a different subset is not.
+        assert!(bls12381_min_pk_verify_aggregate_bitmap(
+            pks.clone(),
+            vec![0b0001_0101, 0],
+            msg.clone(),
+            agg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_aggregate_bitmap(
+            pks.clone(),
+            vec![0b0000_0101],
+            msg.clone(),
+            agg.clone()
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_bitmap_bad_bitmap() {
+        let keys = committee("bitmap-verify", 5);
+        let pks = pks_of(&keys);
+        let msg = b"bitmap cert".to_vec();
+        let agg = agg_sign(&keys, &[0, 2, 4], &msg);
+
+        // Bit 5 addresses a sixth member.
+        assert!(!bls12381_min_pk_verify_aggregate_bitmap(
+            pks.clone(),
+            vec![0b0011_0101],
+            msg.clone(),
+            agg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_aggregate_bitmap(
+            pks.clone(),
+            vec![0],
+            msg.clone(),
+            agg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_aggregate_bitmap(
+            pks,
+            vec![],
+            msg,
+            agg
+        ));
+    }
+
+    // ---- quorum weight ----
+
+    #[test]
+    fn quorum_met_by_weight() {
+        let weights = vec![10, 20, 30, 40, 50];
+        // Members 0, 2 and 4: 10 + 30 + 50 = 90.
+        assert!(bls12381_quorum_met(vec![0b0001_0101], weights.clone(), 90));
+        assert!(bls12381_quorum_met(vec![0b0001_0101], weights.clone(), 0));
+        assert!(!bls12381_quorum_met(vec![0b0001_0101], weights.clone(), 91));
+        assert!(!bls12381_quorum_met(vec![], weights, 1));
+        assert!(bls12381_quorum_met(
+            vec![0b11],
+            vec![u64::MAX, u64::MAX],
+            u64::MAX
+        ));
+    }
+
+    #[test]
+    fn quorum_met_out_of_range_bit() {
+        let weights = vec![10, 20, 30, 40, 50];
+        assert!(!bls12381_quorum_met(vec![0b0010_0001], weights.clone(), 0));
+        assert!(!bls12381_quorum_met(vec![0b0000_0001, 0b1], weights, 0));
+    }
+
+    // ---- signer order ----
+
+    #[test]
+    fn signer_order_matches_in_any_order() {
+        let keys = committee("signer-order", 5);
+        let msg = b"exact signers".to_vec();
+        let agg = agg_sign(&keys, &[1, 3, 4], &msg);
+
+        assert!(bls12381_verify_signer_order(
+            pks_of(&keys),
+            vec![4, 1, 3],
+            vec![0b0001_1010],
+            msg,
+            agg,
+        ));
+    }
+
+    #[test]
+    fn signer_order_rejects_extra_signer() {
+        let keys = committee("signer-order", 5);
+        let msg = b"exact signers".to_vec();
+        let agg = agg_sign(&keys, &[1, 3, 4], &msg);
+
+        // The certificate is valid, but member 4 was not expected.
+        assert!(!bls12381_verify_signer_order(
+            pks_of(&keys),
+            vec![1, 3],
+            vec![0b0001_1010],
+            msg,
+            agg,
+        ));
+    }
+
+    #[test]
+    fn signer_order_rejects_missing_signer() {
+        let keys = committee("signer-order", 5);
+        let msg = b"exact signers".to_vec();
+        let agg = agg_sign(&keys, &[1, 3], &msg);
+
+        assert!(!bls12381_verify_signer_order(
+            pks_of(&keys),
+            vec![1, 3, 4],
+            vec![0b0000_1010],
+            msg,
+            agg,
+        ));
+    }
+
+    // ---- fault models ----
+
+    /// Bitmap with the first `k` members set.
+    fn first_k(k: usize) -> Vec<u8> {
+        let mut bitmap = vec![0u8; bitmap_len(k.max(1))];
+        for i in 0..k {
+            bitmap[i / 8] |= 1 << (i % 8);
+        }
+        bitmap
+    }
+
+    fn verify_first_k(keys: &[(blst::min_pk::SecretKey, PublicKey)], k: usize, model: u8) -> bool {
+        let msg = b"fault model".to_vec();
+        let signers: Vec<usize> = (0..k).collect();
+        let agg = agg_sign(keys, &signers, &msg);
+        bls12381_verify_bft(pks_of(keys), first_k(k), msg, agg, model)
+    }
+
+    #[test]
+    fn bft_crash_fault_boundary() {
+        let keys = committee("crash-fault", 10);
+        // More than half of 10 is 6.
+        assert!(!verify_first_k(&keys, 5, FAULT_MODEL_CRASH));
+        assert!(verify_first_k(&keys, 6, FAULT_MODEL_CRASH));
+    }
+
+    #[test]
+    fn bft_byzantine_fault_boundary() {
+        let keys = committee("byzantine-fault", 10);
+        // More than two thirds of 10 is 7.
+        assert!(!verify_first_k(&keys, 6, FAULT_MODEL_BYZANTINE));
+        assert!(verify_first_k(&keys, 7, FAULT_MODEL_BYZANTINE));
+    }
+
+    #[test]
+    fn bft_unknown_model() {
+        let keys = committee("unknown-fault", 4);
+        assert!(!verify_first_k(&keys, 4, 2));
+    }
+
+    // ---- receipt id ----
+
+    #[test]
+    fn receipt_is_deterministic() {
+        let keys = committee("receipt", 3);
+        let msg = b"receipt".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+
+        let a = bls12381_verify_aggregate_receipt(pks_of(&keys), msg.clone(), agg.clone());
+        let b = bls12381_verify_aggregate_receipt(pks_of(&keys), msg.clone(), agg);
+        assert_eq!(a.len(), 16);
+        assert_eq!(a, b);
+
+        let other_msg = b"another receipt".to_vec();
+        let other = agg_sign(&keys, &[0, 1, 2], &other_msg);
+        assert_ne!(
+            a,
+            bls12381_verify_aggregate_receipt(pks_of(&keys), other_msg, other)
+        );
+    }
+
+    #[test]
+    fn receipt_ignores_signature_encoding() {
+        let keys = committee("receipt", 3);
+        let msg = b"receipt".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+        let uncompressed = Signature::from_bytes(&agg).unwrap().serialize().to_vec();
+        assert_eq!(uncompressed.len(), 192);
+
+        let a = bls12381_verify_aggregate_receipt(pks_of(&keys), msg.clone(), agg);
+        let b = bls12381_verify_aggregate_receipt(pks_of(&keys), msg, uncompressed);
+        assert_eq!(a.len(), 16);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn receipt_empty_on_failure() {
+        let keys = committee("receipt", 3);
+        let agg = agg_sign(&keys, &[0, 1, 2], b"receipt");
+        assert!(
+            bls12381_verify_aggregate_receipt(pks_of(&keys), b"tampered".to_vec(), agg).is_empty()
+        );
+        assert!(
+            bls12381_verify_aggregate_receipt(vec![], b"receipt".to_vec(), vec![0u8; 96])
+                .is_empty()
+        );
+    }
+
+    // ---- bitmap encoding ----
+
+    #[test]
+    fn encode_bitmap_round_trip() {
+        let signers = vec![9, 0, 3, 9];
+        let bitmap = bls12381_encode_bitmap(signers, 20);
+        assert_eq!(bitmap, vec![0b0000_1001, 0b0000_0010]);
+        assert_eq!(bitmap_indices(&bitmap, 20), Some(vec![0, 3, 9]));
+    }
+
+    #[test]
+    fn encode_bitmap_is_minimal() {
+        // A 64-member committee, but only low indices sign.
+        assert_eq!(bls12381_encode_bitmap(vec![1, 7], 64).len(), 1);
+        assert_eq!(bls12381_encode_bitmap(vec![8], 64).len(), 2);
+        assert_eq!(bls12381_encode_bitmap(vec![63], 64).len(), 8);
+        assert!(bls12381_encode_bitmap(vec![], 64).is_empty());
+    }
+
+    #[test]
+    fn encode_bitmap_rejects_out_of_range() {
+        assert!(bls12381_encode_bitmap(vec![0, 4], 4).is_empty());
+    }
+
+    // ---- committee delta ----
+
+    #[test]
+    fn committee_delta_applied() {
+        let keys = committee("delta", 5);
+        let pks = pks_of(&keys);
+        let prev = CommitteeHandle::new(pks[..4].to_vec()).unwrap();
+
+        // Current committee: [0, 2, 3, 4] after removing 1 and adding 4.
+        let msg = b"next epoch".to_vec();
+        let agg = agg_sign(&keys, &[0, 2, 4], &msg);
+        let bitmap = vec![0b1011];
+        assert!(bls12381_verify_with_committee_delta(
+            &prev,
+            vec![pks[4].clone()],
+            vec![pks[1].clone()],
+            bitmap.clone(),
+            msg.clone(),
+            agg.clone(),
+        ));
+
+        // The same certificate does not verify against the old committee.
+        assert!(!prev.verify_quorum(bitmap, msg, agg));
+    }
+
+    #[test]
+    fn committee_delta_invalid() {
+        let keys = committee("delta", 5);
+        let pks = pks_of(&keys);
+        let prev = CommitteeHandle::new(pks[..4].to_vec()).unwrap();
+        let msg = b"next epoch".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+        let verify = |added: Vec<Vec<u8>>, removed: Vec<Vec<u8>>| {
+            bls12381_verify_with_committee_delta(
+                &prev,
+                added,
+                removed,
+                vec![0b0111],
+                msg.clone(),
+                agg.clone(),
+            )
+        };
+
+        assert!(verify(vec![], vec![]));
+        // Removing a non-member.
+        assert!(!verify(vec![], vec![pks[4].clone()]));
+        // Adding an existing member.
+        assert!(!verify(vec![pks[0].clone()], vec![]));
+        // Repeated key.
+        assert!(!verify(vec![pks[4].clone(), pks[4].clone()], vec![]));
+        // Emptying the committee.
+        assert!(!verify(vec![], pks[..4].to_vec()));
+    }
+
+    // ---- sorted membership ----
+
+    #[test]
+    fn committee_contains_large_sorted() {
+        let mut pks = pks_of(&committee("contains", 1001));
+        let outsider = pks.pop().unwrap();
+        pks.sort();
+        assert_eq!(pks.len(), 1000);
+
+        for pk in [&pks[0], &pks[499], &pks[999]] {
+            assert!(bls12381_committee_contains(pks.clone(), pk.clone()));
+        }
+        assert!(!bls12381_committee_contains(pks.clone(), outsider));
+        assert!(!bls12381_committee_contains(
+            pks.clone(),
+            pks[0][..47].to_vec()
+        ));
+        assert!(!bls12381_committee_contains(vec![], pks[0].clone()));
+    }
+
+    #[test]
+    fn sort_pubkeys_canonical() {
+        let keys = committee("sort", 8);
+        let pks = pks_of(&keys);
+        let sorted = bls12381_min_pk_sort_pubkeys(pks.clone());
+        assert_eq!(sorted.len(), 8);
+        assert!(sorted.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(bls12381_min_pk_sort_pubkeys(sorted.clone()), sorted);
+
+        // Reversed input, mixed encodings and a duplicate all land in the
+        // same order.
+        let mut mixed: Vec<Vec<u8>> = keys
+            .iter()
+            .rev()
+            .map(|(_, pk)| pk.serialize().to_vec())
+            .collect();
+        mixed.push(pks[3].clone());
+        let resorted = bls12381_min_pk_sort_pubkeys(mixed);
+        assert_eq!(resorted.len(), 9);
+        let mut expected = sorted.clone();
+        expected.push(pks[3].clone());
+        expected.sort();
+        assert_eq!(resorted, expected);
+        for pk in &pks {
+            assert!(bls12381_committee_contains(sorted.clone(), pk.clone()));
+        }
+    }
+
+    #[test]
+    fn sort_pubkeys_malformed() {
+        let mut pks = pks_of(&committee("sort", 3));
+        pks.push(vec![0u8; PK_LEN]);
+        assert!(bls12381_min_pk_sort_pubkeys(pks.clone()).is_empty());
+        pks.pop();
+        pks.push(pks[0][..47].to_vec());
+        assert!(bls12381_min_pk_sort_pubkeys(pks).is_empty());
+        assert!(bls12381_min_pk_sort_pubkeys(vec![]).is_empty());
+    }
+
+    // ---- canonical aggregate ----
+
+    #[test]
+    fn canonical_aggregate_order_independent() {
+        let keys = committee("canonical", 10);
+        let pks = pks_of(&keys);
+        let msg = b"canonical";
+        let sig = |i: usize| sign_msg(&keys[i].0, msg).to_bytes().to_vec();
+
+        let a =
+            bls12381_canonical_aggregate(pks.clone(), vec![(0, sig(0)), (4, sig(4)), (9, sig(9))])
+                .unwrap();
+        let b =
+            bls12381_canonical_aggregate(pks.clone(), vec![(9, sig(9)), (0, sig(0)), (4, sig(4))])
+                .unwrap();
+
+        assert_eq!(a.bitmap, b.bitmap);
+        assert_eq!(a.aggregate, b.aggregate);
+        assert_eq!(a.bitmap, vec![0b0001_0001, 0b0000_0010]);
+        let handle = CommitteeHandle::new(pks).unwrap();
+        assert!(handle.verify_certificate(&a.bitmap, msg, &a.aggregate));
+    }
+
+    #[test]
+    fn canonical_aggregate_invalid() {
+        let keys = committee("canonical", 3);
+        let pks = pks_of(&keys);
+        let sig = sign_msg(&keys[0].0, b"m").to_bytes().to_vec();
+
+        assert!(bls12381_canonical_aggregate(pks.clone(), vec![]).is_none());
+        assert!(bls12381_canonical_aggregate(pks.clone(), vec![(3, sig.clone())]).is_none());
+        assert!(
+            bls12381_canonical_aggregate(pks.clone(), vec![(0, sig.clone()), (0, sig)]).is_none()
+        );
+        assert!(bls12381_canonical_aggregate(pks, vec![(1, vec![0u8; 95])]).is_none());
+    }
+
+    // ---- timed committee ----
+
+    #[test]
+    fn timed_committee_rejects_expired_signer() {
+        let keys = committee("timed", 3);
+        let pks = pks_of(&keys);
+        let timed = TimedCommittee::new(vec![
+            (pks[0].clone(), 0, 1_000),
+            (pks[1].clone(), 0, 500),
+            (pks[2].clone(), 100, 1_000),
+        ])
+        .unwrap();
+        let msg = b"term limits".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+
+        assert!(timed.verify(vec![0b111], msg.clone(), agg.clone(), 200));
+        // Member 1's term ended at 500.
+        assert!(!timed.verify(vec![0b111], msg.clone(), agg.clone(), 500));
+        // Member 2's term has not started yet.
+        assert!(!timed.verify(vec![0b111], msg.clone(), agg, 50));
+
+        // Without the expired member the certificate is fine.
+        let agg = agg_sign(&keys, &[0, 2], &msg);
+        assert!(timed.verify(vec![0b101], msg, agg, 700));
+    }
+
+    #[test]
+    fn timed_committee_invalid_terms() {
+        let pks = pks_of(&committee("timed", 1));
+        assert!(TimedCommittee::new(vec![]).is_none());
+        assert!(TimedCommittee::new(vec![(pks[0].clone(), 10, 5)]).is_none());
+        assert!(TimedCommittee::new(vec![(vec![0u8; 48], 0, 5)]).is_none());
+    }
+
+    // ---- committee key ----
+
+    #[test]
+    fn committee_key_matches_verify_aggregate() {
+        let keys = committee("committee-key", 4);
+        let pks = pks_of(&keys);
+        let key = CommitteeKey::new(pks.clone()).unwrap();
+        assert_eq!(key.public_key().len(), PK_LEN);
+
+        for msg in [b"blob cert one".to_vec(), b"blob cert two".to_vec()] {
+            let agg = agg_sign(&keys, &[0, 1, 2, 3], &msg);
+            assert!(key.verify(msg.clone(), agg.clone()));
+            assert_eq!(
+                key.verify(msg.clone(), agg.clone()),
+                bls12381_min_pk_verify_aggregate(pks.clone(), msg.clone(), agg.clone())
+            );
+            assert!(!key.verify(b"other".to_vec(), agg));
+        }
+
+        // A partial quorum is not the whole committee.
+        let msg = b"blob cert one".to_vec();
+        assert!(!key.verify(msg.clone(), agg_sign(&keys, &[0, 1, 2], &msg)));
+        assert!(!key.verify(msg, vec![0u8; 96]));
+    }
+
+    #[test]
+    fn committee_key_invalid_members() {
+        let pks = pks_of(&committee("committee-key", 2));
+        assert!(CommitteeKey::new(vec![]).is_none());
+        assert!(CommitteeKey::new(vec![pks[0].clone(), vec![0u8; 47]]).is_none());
+        let mut identity = vec![0u8; PK_LEN];
+        identity[0] = 0xc0;
+        assert!(CommitteeKey::new(vec![pks[0].clone(), identity]).is_none());
+    }
+
+    // ---- committee change ----
+
+    #[test]
+    fn committee_change_approved_by_quorum() {
+        let old = committee("change-old", 4);
+        let new = pks_of(&committee("change-new", 3));
+        let handle = CommitteeHandle::new(pks_of(&old)).unwrap();
+        let weights = vec![10, 20, 30];
+
+        let msg = bls12381_committee_change_message(new.clone(), weights.clone());
+        assert_eq!(msg.len(), 23 + 4 + 3 * 56);
+        // Reordering the proposal does not change what is signed.
+        assert_eq!(
+            msg,
+            bls12381_committee_change_message(
+                vec![new[2].clone(), new[0].clone(), new[1].clone()],
+                vec![30, 10, 20],
+            )
+        );
+
+        let agg = agg_sign(&old, &[0, 1, 3], &msg);
+        assert!(bls12381_verify_committee_change(
+            &handle,
+            new.clone(),
+            weights.clone(),
+            vec![0b1011],
+            agg.clone(),
+        ));
+        // Different weights are a different proposal.
+        assert!(!bls12381_verify_committee_change(
+            &handle,
+            new.clone(),
+            vec![10, 20, 31],
+            vec![0b1011],
+            agg,
+        ));
+
+        // Two of four is not a quorum.
+        let agg = agg_sign(&old, &[0, 1], &msg);
+        assert!(!bls12381_verify_committee_change(
+            &handle,
+            new,
+            weights,
+            vec![0b0011],
+            agg
+        ));
+    }
+
+    #[test]
+    fn committee_change_invalid_proposal() {
+        let new = pks_of(&committee("change-new", 2));
+        assert!(bls12381_committee_change_message(vec![], vec![]).is_empty());
+        assert!(bls12381_committee_change_message(new.clone(), vec![1]).is_empty());
+        assert!(bls12381_committee_change_message(
+            vec![new[0].clone(), new[0].clone()],
+            vec![1, 1]
+        )
+        .is_empty());
+    }
+
+    // ---- verify by committee hash ----
+
+    #[test]
+    fn verify_by_committee_hash_rejects_substitution() {
+        let keys = committee("by-hash", 4);
+        let pks = pks_of(&keys);
+        let hash = bls12381_committee_digest(pks.clone());
+        let msg = b"content addressed".to_vec();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+
+        assert!(bls12381_verify_by_committee_hash(
+            hash.clone(),
+            pks.clone(),
+            vec![0b0111],
+            msg.clone(),
+            agg,
+        ));
+
+        // An attacker swaps in their own key for member 3 and signs with it.
+        let attacker = committee("by-hash-attacker", 1);
+        let mut substituted = pks.clone();
+        substituted[3] = pks_of(&attacker)[0].clone();
+        assert_ne!(bls12381_committee_digest(substituted.clone()), hash);
+        let forged = agg_sign(&attacker, &[0], &msg);
+        assert!(!bls12381_verify_by_committee_hash(
+            hash,
+            substituted,
+            vec![0b1000],
+            msg,
+            forged,
+        ));
+    }
+
+    // ---- roaring signers ----
+
+    #[test]
+    fn verify_roaring_matches_plain_bitmap() {
+        let keys = committee("roaring", 20);
+        let pks = pks_of(&keys);
+        let handle = CommitteeHandle::new(pks.clone()).unwrap();
+        let msg = b"sparse signers".to_vec();
+        let signers = [2usize, 3, 17];
+        let agg = agg_sign(&keys, &signers, &msg);
+
+        // {2, 3, 17}: one array container under key 0.
+        let roaring = [
+            0x3a, 0x30, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 16, 0, 0, 0, 2, 0, 3, 0, 17, 0,
+        ];
+        let bitmap = bls12381_encode_bitmap(vec![2, 3, 17], 20);
+
+        for sig in [agg.clone(), agg_sign(&keys, &[2, 3], &msg)] {
+            assert_eq!(
+                bls12381_verify_roaring(pks.clone(), roaring.to_vec(), msg.clone(), sig.clone()),
+                handle.verify_certificate(&bitmap, &msg, &sig),
+            );
+        }
+        assert!(bls12381_verify_roaring(
+            pks.clone(),
+            roaring.to_vec(),
+            msg.clone(),
+            agg.clone()
+        ));
+
+        // Index 17 is out of range for a 10-member committee.
+        assert!(!bls12381_verify_roaring(
+            pks[..10].to_vec(),
+            roaring.to_vec(),
+            msg,
+            agg
+        ));
+    }
+
+    // ---- resolve signers ----
+
+    #[test]
+    fn resolve_signers_expected_indices() {
+        let pks = pks_of(&committee("resolve", 12));
+        assert_eq!(
+            bls12381_resolve_signers(pks.clone(), vec![0b1000_0101, 0b0000_1001]),
+            vec![0, 2, 7, 8, 11]
+        );
+        assert!(bls12381_resolve_signers(pks.clone(), vec![0, 0b0001_0000]).is_empty());
+        assert!(bls12381_resolve_signers(pks, vec![]).is_empty());
+    }
+
+    // ---- flat stake quorum ----
+
+    #[test]
+    fn stake_quorum_flat_bps_boundary() {
+        let keys = committee("stake-flat", 3);
+        let pks_concat = pks_of(&keys).concat();
+        let stakes = vec![100, 100, 100];
+        let msg = b"two thirds exactly".to_vec();
+        // 200 of 300 is 6666.67 bps.
+        let agg = agg_sign(&keys, &[0, 2], &msg);
+        let verify = |bps: u32| {
+            bls12381_verify_stake_quorum_flat(
+                pks_concat.clone(),
+                stakes.clone(),
+                vec![0b101],
+                bps,
+                msg.clone(),
+                agg.clone(),
+            )
+        };
+
+        assert!(verify(6666));
+        assert!(!verify(6667));
+        assert!(verify(0));
+        assert!(!verify(10_001));
+    }
+
+    #[test]
+    fn stake_quorum_flat_malformed() {
+        let keys = committee("stake-flat", 2);
+        let pks_concat = pks_of(&keys).concat();
+        let msg = b"m".to_vec();
+        let agg = agg_sign(&keys, &[0, 1], &msg);
+
+        // Key array not a multiple of 48 bytes, or not matching the stakes.
+        assert!(!bls12381_verify_stake_quorum_flat(
+            pks_concat[..95].to_vec(),
+            vec![1, 1],
+            vec![0b11],
+            5000,
+            msg.clone(),
+            agg.clone(),
+        ));
+        assert!(!bls12381_verify_stake_quorum_flat(
+            pks_concat.clone(),
+            vec![1],
+            vec![0b1],
+            5000,
+            msg.clone(),
+            agg.clone(),
+        ));
+        // No stake at all.
+        assert!(!bls12381_verify_stake_quorum_flat(
+            pks_concat,
+            vec![0, 0],
+            vec![0b11],
+            0,
+            msg,
+            agg,
+        ));
+    }
+}
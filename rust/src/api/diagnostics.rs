@@ -0,0 +1,555 @@
+// Instrumented variants of the verify functions for profiling and
+// troubleshooting. Results always match the plain functions in `bls`.
+
+use std::sync::{Mutex, Once};
+use std::time::Instant;
+
+use blst::min_pk::{PublicKey, SecretKey, Signature};
+use blst::BLST_ERROR;
+
+use crate::api::batch::reduce_default_threads;
+use crate::api::bls::{bls12381_min_pk_aggregate, bls12381_min_pk_verify, sign_with_dst, DST};
+use crate::api::encoding::decode_hex;
+use crate::api::keys::SecretBytes;
+
+/// Verification result plus the CPU cycles it took.
+pub struct CycleReport {
+    /// Same result as [`bls12381_min_pk_verify`].
+    pub valid: bool,
+    /// Approximate cycle count, `0` when `supported` is `false`.
+    pub cycles: u64,
+    /// Whether this platform has a cycle counter (x86_64 `rdtsc`).
+    pub supported: bool,
+}
+
+/// Current value of the CPU timestamp counter, if the platform has one.
+#[cfg(target_arch = "x86_64")]
+fn read_cycle_counter() -> Option<u64> {
+    // SAFETY: `rdtsc` is available on every x86_64 CPU.
+    Some(unsafe { core::arch::x86_64::_rdtsc() })
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn read_cycle_counter() -> Option<u64> {
+    None
+}
+
+/// Verify a single BLS12-381 min_pk signature and measure the CPU cycles
+/// spent.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Cycles are read with `rdtsc` on x86_64, which counts at a constant
+/// reference rate on modern CPUs, so treat the value as approximate. On
+/// other architectures `supported` is `false` and `cycles` is `0`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_cycles(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+) -> CycleReport {
+    let start = read_cycle_counter();
+    let valid = bls12381_min_pk_verify(sig_bytes, pk_bytes, msg);
+    match (start, read_cycle_counter()) {
+        (Some(start), Some(end)) => CycleReport {
+            valid,
+            cycles: end.wrapping_sub(start),
+            supported: true,
+        },
+        _ => CycleReport {
+            valid,
+            cycles: 0,
+            supported: false,
+        },
+    }
+}
+
+/// Every check behind a verification, computed in one pass.
+pub struct FullVerifyResult {
+    /// The signature decodes to a point in the G2 subgroup.
+    pub signature_valid_point: bool,
+    /// The public key decodes to a point in the G1 subgroup.
+    pub pubkey_valid_point: bool,
+    /// The public key is not the identity (point at infinity).
+    pub pubkey_not_identity: bool,
+    /// All of the above hold and the signature verifies over the message.
+    pub signature_valid: bool,
+}
+
+/// Verify a single BLS12-381 min_pk signature and report each check
+/// separately.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// `signature_valid` agrees with [`bls12381_min_pk_verify`]; the other
+/// fields say which input was at fault when it is `false`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_full(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+) -> FullVerifyResult {
+    let sig = Signature::from_bytes(&sig_bytes)
+        .ok()
+        .filter(|s| s.validate(false).is_ok());
+    let pk = PublicKey::from_bytes(&pk_bytes).ok();
+    let (pubkey_valid_point, pubkey_not_identity) = match pk.as_ref().map(|pk| pk.validate()) {
+        Some(Ok(())) => (true, true),
+        // The identity is in the subgroup; it just isn't a usable key.
+        Some(Err(BLST_ERROR::BLST_PK_IS_INFINITY)) => (true, false),
+        Some(Err(_)) => (false, true),
+        None => (false, false),
+    };
+
+    let signature_valid = match (&sig, &pk) {
+        (Some(sig), Some(pk)) if pubkey_valid_point && pubkey_not_identity => {
+            sig.verify(false, &msg, DST, &[], pk, false) == BLST_ERROR::BLST_SUCCESS
+        }
+        _ => false,
+    };
+
+    FullVerifyResult {
+        signature_valid_point: sig.is_some(),
+        pubkey_valid_point,
+        pubkey_not_identity,
+        signature_valid,
+    }
+}
+
+fn yes_no(b: bool) -> &'static str {
+    if b {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// Verify a signature given as hex and return a human-readable report.
+///
+/// * `sig_hex` – hex of the 96-byte compressed G2 signature
+/// * `pk_hex`  – hex of the 48-byte compressed G1 public key
+/// * `msg_hex` – hex of the message
+///
+/// Each hex string may carry a `0x` prefix. The report has one
+/// `key: value` line each for `sig`, `pk`, `msg`, `dst` and `verdict`, in
+/// that order; the format is stable so tooling can grep it. The verdict
+/// line is `verdict: VALID` or `verdict: INVALID`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_debug_verify(sig_hex: String, pk_hex: String, msg_hex: String) -> String {
+    let sig = decode_hex(&sig_hex);
+    let pk = decode_hex(&pk_hex);
+    let msg = decode_hex(&msg_hex);
+
+    let full = match (&sig, &pk, &msg) {
+        (Some(sig), Some(pk), Some(msg)) => Some(bls12381_min_pk_verify_full(
+            sig.clone(),
+            pk.clone(),
+            msg.clone(),
+        )),
+        _ => None,
+    };
+    // Point checks only depend on their own input, so run them even if the
+    // message didn't decode.
+    let points = bls12381_min_pk_verify_full(
+        sig.clone().unwrap_or_default(),
+        pk.clone().unwrap_or_default(),
+        vec![],
+    );
+
+    let sig_line = match &sig {
+        Some(b) => format!(
+            "sig: {} bytes (expected 96), valid G2 point: {}",
+            b.len(),
+            yes_no(points.signature_valid_point)
+        ),
+        None => "sig: invalid hex".to_string(),
+    };
+    let pk_line = match &pk {
+        Some(b) => format!(
+            "pk: {} bytes (expected 48), valid G1 point: {}, identity: {}",
+            b.len(),
+            yes_no(points.pubkey_valid_point),
+            yes_no(points.pubkey_valid_point && !points.pubkey_not_identity)
+        ),
+        None => "pk: invalid hex".to_string(),
+    };
+    let msg_line = match &msg {
+        Some(b) => format!("msg: {} bytes", b.len()),
+        None => "msg: invalid hex".to_string(),
+    };
+    let valid = full.is_some_and(|r| r.signature_valid);
+
+    [
+        sig_line,
+        pk_line,
+        msg_line,
+        format!("dst: {}", String::from_utf8_lossy(DST)),
+        format!("verdict: {}", if valid { "VALID" } else { "INVALID" }),
+    ]
+    .join("\n")
+}
+
+/// Upper bounds (exclusive, in microseconds) of the [`SigningStats`]
+/// histogram buckets. A final bucket catches everything slower.
+const SIGNING_BUCKETS_US: [u64; 7] = [250, 500, 1_000, 2_000, 4_000, 8_000, 16_000];
+
+/// Signing latency histogram for monitoring bulk signing over time.
+#[flutter_rust_bridge::frb(opaque)]
+#[derive(Default)]
+pub struct SigningStats {
+    counts: [u64; SIGNING_BUCKETS_US.len() + 1],
+}
+
+impl SigningStats {
+    /// Create an empty histogram.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> SigningStats {
+        SigningStats::default()
+    }
+
+    /// Sign `msg` and record how long it took.
+    ///
+    /// * `sk`  – 32-byte secret key
+    /// * `msg` – arbitrary-length message
+    ///
+    /// Returns the 96-byte signature, or an empty `Vec<u8>` for a malformed
+    /// key; failed signings are not recorded.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn sign_tracked(&mut self, sk: Vec<u8>, msg: Vec<u8>) -> Vec<u8> {
+        let start = Instant::now();
+        let sig = sign_with_dst(SecretBytes::new(sk).as_slice(), &msg, DST);
+        if !sig.is_empty() {
+            self.record(start.elapsed().as_micros() as u64);
+        }
+        sig
+    }
+
+    /// Bucketed signing latency counts.
+    ///
+    /// Bucket `i` counts signings under `[250, 500, 1000, 2000, 4000, 8000,
+    /// 16000][i]` microseconds (and at least the previous bound); the last
+    /// of the 8 buckets counts everything from 16 ms up.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn histogram(&self) -> Vec<u64> {
+        self.counts.to_vec()
+    }
+
+    /// Reset every bucket to zero.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn clear(&mut self) {
+        self.counts = Default::default();
+    }
+
+    fn record(&mut self, elapsed_us: u64) {
+        let bucket = SIGNING_BUCKETS_US
+            .iter()
+            .position(|&bound| elapsed_us < bound)
+            .unwrap_or(SIGNING_BUCKETS_US.len());
+        self.counts[bucket] += 1;
+    }
+}
+
+/// Compressed signatures reused by [`bls12381_bench_aggregate`]; grown on
+/// demand and never shrunk, so repeated runs skip the signing.
+static BENCH_SIGS: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+
+/// Deterministic signature `i` of the benchmark set.
+fn bench_signature(i: u32) -> Vec<u8> {
+    let mut seed = [0x5bu8; 32];
+    seed[..4].copy_from_slice(&i.to_le_bytes());
+    let sk = SecretKey::key_gen(&seed, &[]).expect("32-byte seed");
+    sk.sign(b"bls12381_bench_aggregate", DST, &[])
+        .to_bytes()
+        .to_vec()
+}
+
+/// Time the aggregation of `num_sigs` signatures.
+///
+/// * `num_sigs` – how many signatures to aggregate
+///
+/// The timed part matches [`crate::api::bls::bls12381_min_pk_aggregate`]:
+/// decompressing and group-checking every signature, then summing them.
+/// The signatures are generated once and cached, so only the first call for
+/// a given size pays for signing.
+///
+/// Returns the elapsed time in microseconds, `0` for `num_sigs == 0`.
+pub fn bls12381_bench_aggregate(num_sigs: u32) -> u64 {
+    if num_sigs == 0 {
+        return 0;
+    }
+    let mut cache = BENCH_SIGS.lock().unwrap_or_else(|e| e.into_inner());
+    for i in cache.len() as u32..num_sigs {
+        cache.push(bench_signature(i));
+    }
+    let sigs = cache[..num_sigs as usize].to_vec();
+    drop(cache);
+
+    let start = Instant::now();
+    let agg = bls12381_min_pk_aggregate(sigs);
+    let elapsed = start.elapsed().as_micros() as u64;
+    debug_assert_eq!(agg.len(), 96);
+    elapsed
+}
+
+/// How the native library was built.
+pub struct BuildFeatures {
+    /// Whether blst uses its portable code instead of CPU-specific assembly
+    /// (ADX on x86_64), which makes every pairing noticeably slower.
+    pub portable: bool,
+    /// Target architecture, e.g. `"x86_64"` or `"aarch64"`.
+    pub target_arch: String,
+}
+
+/// Report the build configuration of the native library.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_build_features() -> BuildFeatures {
+    BuildFeatures {
+        portable: cfg!(feature = "portable"),
+        target_arch: std::env::consts::ARCH.to_string(),
+    }
+}
+
+/// Most recent warning raised by an adaptive function; empty if none.
+static LAST_WARNING: Mutex<String> = Mutex::new(String::new());
+/// Guards the one-time adaptation to a portable build.
+static PORTABLE_NOTICE: Once = Once::new();
+
+/// Record `warning` for [`bls12381_last_warning`].
+pub(crate) fn set_last_warning(warning: String) {
+    *LAST_WARNING.lock().unwrap_or_else(|e| e.into_inner()) = warning;
+}
+
+/// On a portable build, lower the default parallelism and record a
+/// warning, the first time only.
+fn adapt_to_build() {
+    PORTABLE_NOTICE.call_once(|| {
+        if bls12381_build_features().portable {
+            reduce_default_threads();
+            set_last_warning(
+                "blst portable build: optimized assembly unavailable, default verify threads halved"
+                    .to_string(),
+            );
+        }
+    });
+}
+
+/// Verify a single signature, adapting defaults to a slow build.
+///
+/// * `sig` – 96-byte compressed G2 signature
+/// * `pk`  – 48-byte compressed G1 public key
+/// * `msg` – arbitrary-length message
+///
+/// The result is always the same as [`bls12381_min_pk_verify`]. When
+/// [`bls12381_build_features`] reports a portable build, the first call
+/// also halves the default thread count of parallel batch verification and
+/// leaves a warning for [`bls12381_last_warning`].
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_adaptive(sig: Vec<u8>, pk: Vec<u8>, msg: Vec<u8>) -> bool {
+    adapt_to_build();
+    bls12381_min_pk_verify(sig, pk, msg)
+}
+
+/// The most recent warning raised by an adaptive function.
+///
+/// Returns an empty `String` when nothing has been reported.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_last_warning() -> String {
+    LAST_WARNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{keygen, sign_msg};
+
+    // ---- cycles ----
+
+    #[test]
+    fn verify_cycles_reports_result() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-cycle-count!!!");
+        let msg = b"profile me";
+        let sig = sign_msg(&sk, msg).to_bytes().to_vec();
+
+        let report =
+            bls12381_min_pk_verify_cycles(sig.clone(), pk.to_bytes().to_vec(), msg.to_vec());
+        assert!(report.valid);
+        assert_eq!(report.supported, cfg!(target_arch = "x86_64"));
+        if report.supported {
+            assert!(report.cycles > 0);
+        } else {
+            assert_eq!(report.cycles, 0);
+        }
+
+        let report = bls12381_min_pk_verify_cycles(sig, pk.to_bytes().to_vec(), b"x".to_vec());
+        assert!(!report.valid);
+    }
+
+    // ---- full verify ----
+
+    /// Compressed encoding of the G1 identity.
+    fn identity_pk() -> Vec<u8> {
+        let mut pk = vec![0u8; 48];
+        pk[0] = 0xc0;
+        pk
+    }
+
+    #[test]
+    fn verify_full_valid() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-full-valid!!!!");
+        let sig = sign_msg(&sk, b"full").to_bytes().to_vec();
+        let r = bls12381_min_pk_verify_full(sig, pk.to_bytes().to_vec(), b"full".to_vec());
+        assert!(r.signature_valid_point);
+        assert!(r.pubkey_valid_point);
+        assert!(r.pubkey_not_identity);
+        assert!(r.signature_valid);
+    }
+
+    #[test]
+    fn verify_full_valid_points_wrong_message() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-full-wrong!!!!");
+        let sig = sign_msg(&sk, b"full").to_bytes().to_vec();
+        let r = bls12381_min_pk_verify_full(sig, pk.to_bytes().to_vec(), b"other".to_vec());
+        assert!(r.signature_valid_point);
+        assert!(r.pubkey_valid_point);
+        assert!(r.pubkey_not_identity);
+        assert!(!r.signature_valid);
+    }
+
+    #[test]
+    fn verify_full_identity_pubkey() {
+        let (sk, _) = keygen(b"test-seed-for-bls-full-ident!!!!");
+        let sig = sign_msg(&sk, b"full").to_bytes().to_vec();
+        let r = bls12381_min_pk_verify_full(sig, identity_pk(), b"full".to_vec());
+        assert!(r.signature_valid_point);
+        assert!(r.pubkey_valid_point);
+        assert!(!r.pubkey_not_identity);
+        assert!(!r.signature_valid);
+    }
+
+    #[test]
+    fn verify_full_malformed_inputs() {
+        let (_, pk) = keygen(b"test-seed-for-bls-full-bad!!!!!!");
+        let r = bls12381_min_pk_verify_full(vec![0u8; 96], pk.to_bytes().to_vec(), b"m".to_vec());
+        assert!(!r.signature_valid_point);
+        assert!(r.pubkey_valid_point);
+        assert!(!r.signature_valid);
+
+        let r = bls12381_min_pk_verify_full(vec![0u8; 96], vec![0u8; 10], b"m".to_vec());
+        assert!(!r.signature_valid_point);
+        assert!(!r.pubkey_valid_point);
+        assert!(!r.pubkey_not_identity);
+        assert!(!r.signature_valid);
+    }
+
+    // ---- debug verify ----
+
+    #[test]
+    fn debug_verify_valid_report() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-debug-verify!!");
+        let sig = sign_msg(&sk, b"debug");
+
+        let report = bls12381_debug_verify(
+            format!("0x{}", hex::encode(sig.to_bytes())),
+            hex::encode(pk.to_bytes()),
+            hex::encode(b"debug"),
+        );
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "sig: 96 bytes (expected 96), valid G2 point: yes",
+                "pk: 48 bytes (expected 48), valid G1 point: yes, identity: no",
+                "msg: 5 bytes",
+                "dst: BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_",
+                "verdict: VALID",
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_verify_invalid_report() {
+        let (_, pk) = keygen(b"test-seed-for-bls-debug-invalid!");
+        let report = bls12381_debug_verify(
+            "zz".to_string(),
+            hex::encode(pk.to_bytes()),
+            "00".to_string(),
+        );
+        assert!(report.contains("sig: invalid hex"));
+        assert!(report.contains("valid G1 point: yes"));
+        assert!(report.ends_with("verdict: INVALID"));
+    }
+
+    // ---- signing stats ----
+
+    #[test]
+    fn signing_stats_populates_histogram() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-signing-stats!");
+        let mut stats = SigningStats::new();
+        assert_eq!(stats.histogram(), vec![0; 8]);
+
+        for i in 0..5u8 {
+            let sig = stats.sign_tracked(sk.to_bytes().to_vec(), vec![i]);
+            assert!(bls12381_min_pk_verify(sig, pk.to_bytes().to_vec(), vec![i]));
+        }
+        assert_eq!(stats.histogram().len(), 8);
+        assert_eq!(stats.histogram().iter().sum::<u64>(), 5);
+
+        // Malformed keys are not counted.
+        assert!(stats.sign_tracked(vec![0u8; 3], vec![0]).is_empty());
+        assert_eq!(stats.histogram().iter().sum::<u64>(), 5);
+
+        stats.clear();
+        assert_eq!(stats.histogram(), vec![0; 8]);
+    }
+
+    #[test]
+    fn signing_stats_bucket_bounds() {
+        let mut stats = SigningStats::new();
+        for us in [0, 249, 250, 999, 1_000, 15_999, 16_000, u64::MAX] {
+            stats.record(us);
+        }
+        assert_eq!(stats.histogram(), vec![2, 1, 1, 1, 0, 0, 1, 2]);
+    }
+
+    // ---- aggregation benchmark ----
+
+    #[test]
+    fn bench_aggregate_scales_with_size() {
+        assert_eq!(bls12381_bench_aggregate(0), 0);
+        // Warm the cache so neither timing includes signing.
+        bls12381_bench_aggregate(400);
+
+        let small = bls12381_bench_aggregate(4);
+        let large = bls12381_bench_aggregate(400);
+        assert!(large > small, "400 sigs took {large}us, 4 took {small}us");
+    }
+
+    // ---- adaptive ----
+
+    #[test]
+    fn verify_adaptive_matches_plain() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-adaptive-path!");
+        let msg = b"any code path".to_vec();
+        let sig = sign_msg(&sk, &msg).to_bytes().to_vec();
+        let pk = pk.to_bytes().to_vec();
+
+        for m in [msg.clone(), b"other".to_vec()] {
+            assert_eq!(
+                bls12381_min_pk_verify_adaptive(sig.clone(), pk.clone(), m.clone()),
+                bls12381_min_pk_verify(sig.clone(), pk.clone(), m),
+            );
+        }
+        assert!(bls12381_min_pk_verify_adaptive(sig, pk, msg));
+        assert_eq!(
+            bls12381_last_warning().is_empty(),
+            !bls12381_build_features().portable
+        );
+    }
+}
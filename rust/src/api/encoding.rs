@@ -0,0 +1,532 @@
+// Conversions between the encodings of keys and signatures.
+//
+// The rest of the API takes compressed points (48-byte keys, 96-byte
+// signatures). Decompressing one costs a square root, which dominates when
+// the same keys are verified against over and over; callers that cache keys
+// can keep them uncompressed (96-byte keys, 192-byte signatures) instead.
+//
+// Hex is how these values travel through Sui RPC, logs and config files, so
+// the hex helpers live here too.
+
+use blst::min_pk::{PublicKey, Signature};
+use blst::BLST_ERROR;
+
+use crate::api::bls::{is_identity_sig, DST, PK_LEN, SIG_LEN};
+
+/// Length of an uncompressed G1 public key.
+pub(crate) const PK_UNCOMPRESSED_LEN: usize = 2 * PK_LEN;
+/// Length of an uncompressed G2 signature.
+pub(crate) const SIG_UNCOMPRESSED_LEN: usize = 2 * SIG_LEN;
+
+/// Decode hex with an optional `0x`/`0X` prefix, ignoring surrounding
+/// whitespace.
+pub(crate) fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim();
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    hex::decode(digits).ok()
+}
+
+/// Encode bytes as lowercase hex.
+///
+/// * `bytes` – any bytes, e.g. a key or signature
+///
+/// Returns the hex string, without a `0x` prefix.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_bytes_to_hex(bytes: Vec<u8>) -> String {
+    hex::encode(bytes)
+}
+
+/// Decode a hex string.
+///
+/// * `hex` – hex digits in either case, optionally prefixed with `0x`
+///
+/// Surrounding whitespace is ignored.
+///
+/// Returns the decoded bytes, or an empty `Vec<u8>` when `hex` has an odd
+/// number of digits or a non-hex character.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_hex_to_bytes(hex: String) -> Vec<u8> {
+    decode_hex(&hex).unwrap_or_default()
+}
+
+/// Parse an uncompressed public key, refusing the compressed form.
+fn pk_from_uncompressed(pk_bytes: &[u8]) -> Option<PublicKey> {
+    if pk_bytes.len() != PK_UNCOMPRESSED_LEN {
+        return None;
+    }
+    PublicKey::deserialize(pk_bytes).ok()
+}
+
+/// Parse an uncompressed signature, refusing the compressed form.
+fn sig_from_uncompressed(sig_bytes: &[u8]) -> Option<Signature> {
+    if sig_bytes.len() != SIG_UNCOMPRESSED_LEN {
+        return None;
+    }
+    Signature::deserialize(sig_bytes).ok()
+}
+
+/// Parse a public key in either encoding, picked by its length.
+fn pk_from_any(pk_bytes: &[u8]) -> Option<PublicKey> {
+    match pk_bytes.len() {
+        PK_LEN => PublicKey::uncompress(pk_bytes).ok(),
+        _ => pk_from_uncompressed(pk_bytes),
+    }
+}
+
+/// Parse a signature in either encoding, picked by its length.
+fn sig_from_any(sig_bytes: &[u8]) -> Option<Signature> {
+    match sig_bytes.len() {
+        SIG_LEN => Signature::uncompress(sig_bytes).ok(),
+        _ => sig_from_uncompressed(sig_bytes),
+    }
+}
+
+/// Convert a public key to uncompressed form.
+///
+/// * `pk_bytes` – 48-byte compressed G1 public key
+///
+/// Returns the 96-byte uncompressed key, or an empty `Vec<u8>` when
+/// `pk_bytes` is not a valid compressed point.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_pubkey_uncompress(pk_bytes: Vec<u8>) -> Vec<u8> {
+    if pk_bytes.len() != PK_LEN {
+        return vec![];
+    }
+    match PublicKey::uncompress(&pk_bytes) {
+        Ok(pk) => pk.serialize().to_vec(),
+        Err(_) => vec![],
+    }
+}
+
+/// Decompress a public key; the same as [`bls12381_min_pk_pubkey_uncompress`],
+/// under the name some tooling expects.
+///
+/// * `compressed_48` – 48-byte compressed G1 public key
+///
+/// Returns the 96-byte uncompressed key, or an empty `Vec<u8>` when the
+/// input has the wrong length or is not a point on the curve.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_pubkey_decompress(compressed_48: Vec<u8>) -> Vec<u8> {
+    bls12381_min_pk_pubkey_uncompress(compressed_48)
+}
+
+/// Convert an uncompressed public key back to compressed form.
+///
+/// * `pk_bytes` – 96-byte uncompressed G1 public key
+///
+/// Returns the 48-byte compressed key, or an empty `Vec<u8>` when
+/// `pk_bytes` is not a valid uncompressed point.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_pubkey_compress(pk_bytes: Vec<u8>) -> Vec<u8> {
+    match pk_from_uncompressed(&pk_bytes) {
+        Some(pk) => pk.compress().to_vec(),
+        None => vec![],
+    }
+}
+
+/// Convert a signature to uncompressed form.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+///
+/// Returns the 192-byte uncompressed signature, or an empty `Vec<u8>` when
+/// `sig_bytes` is not a valid compressed point.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sig_uncompress(sig_bytes: Vec<u8>) -> Vec<u8> {
+    if sig_bytes.len() != SIG_LEN {
+        return vec![];
+    }
+    match Signature::uncompress(&sig_bytes) {
+        Ok(sig) => sig.serialize().to_vec(),
+        Err(_) => vec![],
+    }
+}
+
+/// Convert an uncompressed signature back to compressed form.
+///
+/// * `sig_bytes` – 192-byte uncompressed G2 signature
+///
+/// Returns the 96-byte compressed signature, or an empty `Vec<u8>` when
+/// `sig_bytes` is not a valid uncompressed point.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sig_compress(sig_bytes: Vec<u8>) -> Vec<u8> {
+    match sig_from_uncompressed(&sig_bytes) {
+        Some(sig) => sig.compress().to_vec(),
+        None => vec![],
+    }
+}
+
+/// Verify a single signature given in uncompressed form.
+///
+/// * `sig_bytes` – 192-byte uncompressed G2 signature
+/// * `pk_bytes`  – 96-byte uncompressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Same checks as [`crate::api::bls::bls12381_min_pk_verify`], minus the
+/// decompression.
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including compressed or malformed inputs).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_uncompressed(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    msg: Vec<u8>,
+) -> bool {
+    let (Some(sig), Some(pk)) = (
+        sig_from_uncompressed(&sig_bytes),
+        pk_from_uncompressed(&pk_bytes),
+    ) else {
+        return false;
+    };
+    if is_identity_sig(&sig) {
+        return false;
+    }
+    sig.verify(true, &msg, DST, &[], &pk, true) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// Verify a single signature given in either encoding.
+///
+/// * `sig_bytes` – 96-byte compressed or 192-byte uncompressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed or 96-byte uncompressed G1 public key
+/// * `msg`       – arbitrary-length message
+///
+/// The encoding of each input is picked from its length, so data from
+/// sources using different encodings can be mixed freely. Same checks as
+/// [`crate::api::bls::bls12381_min_pk_verify`] otherwise.
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including inputs of any other length and malformed inputs).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_any(sig_bytes: Vec<u8>, pk_bytes: Vec<u8>, msg: Vec<u8>) -> bool {
+    let (Some(sig), Some(pk)) = (sig_from_any(&sig_bytes), pk_from_any(&pk_bytes)) else {
+        return false;
+    };
+    if is_identity_sig(&sig) {
+        return false;
+    }
+    sig.verify(true, &msg, DST, &[], &pk, true) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// Compare two public keys as points, whatever their encodings.
+///
+/// * `a` – 48-byte compressed or 96-byte uncompressed G1 public key
+/// * `b` – 48-byte compressed or 96-byte uncompressed G1 public key
+///
+/// Returns `true` when both decode to the same point, `false` otherwise
+/// (including when either is malformed).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_pubkeys_equal(a: Vec<u8>, b: Vec<u8>) -> bool {
+    match (pk_from_any(&a), pk_from_any(&b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Compare two signatures as points, whatever their encodings.
+///
+/// * `a` – 96-byte compressed or 192-byte uncompressed G2 signature
+/// * `b` – 96-byte compressed or 192-byte uncompressed G2 signature
+///
+/// Returns `true` when both decode to the same point, `false` otherwise
+/// (including when either is malformed).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sigs_equal(a: Vec<u8>, b: Vec<u8>) -> bool {
+    match (sig_from_any(&a), sig_from_any(&b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Verify a same-message aggregate against uncompressed public keys.
+///
+/// * `pks_bytes`     – 96-byte uncompressed G1 public keys
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 96-byte compressed or 192-byte uncompressed
+///   aggregate G2 signature
+///
+/// Returns `true` when the aggregate signature is valid, `false` otherwise
+/// (including no keys or a malformed input).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_aggregate_uncompressed(
+    pks_bytes: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig_bytes: Vec<u8>,
+) -> bool {
+    if pks_bytes.is_empty() {
+        return false;
+    }
+    let pks: Option<Vec<PublicKey>> = pks_bytes.iter().map(|b| pk_from_uncompressed(b)).collect();
+    let pks = match pks {
+        Some(p) => p,
+        None => return false,
+    };
+    let sig = match sig_from_any(&agg_sig_bytes) {
+        Some(s) if !is_identity_sig(&s) => s,
+        _ => return false,
+    };
+    let pk_refs: Vec<&PublicKey> = pks.iter().collect();
+
+    sig.fast_aggregate_verify(true, &msg, DST, &pk_refs) == BLST_ERROR::BLST_SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bls::bls12381_min_pk_verify;
+    use crate::test_utils::{agg_sign, committee, keygen, pks_of, sign_msg};
+
+    // ---- hex ----
+
+    #[test]
+    fn hex_round_trip() {
+        let (_, pk) = keygen(b"test-seed-for-bls-uncompressed!!");
+        let pk = pk.to_bytes().to_vec();
+        let hex = bls12381_bytes_to_hex(pk.clone());
+        assert_eq!(hex.len(), 2 * PK_LEN);
+        assert_eq!(bls12381_hex_to_bytes(hex.clone()), pk);
+        assert_eq!(bls12381_hex_to_bytes(format!("0x{hex}")), pk);
+        assert_eq!(bls12381_bytes_to_hex(vec![]), "");
+    }
+
+    #[test]
+    fn hex_prefix_and_case() {
+        assert_eq!(
+            bls12381_hex_to_bytes("0xdeadBEEF".into()),
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+        assert_eq!(
+            bls12381_hex_to_bytes("0XDEADBEEF".into()),
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+        assert_eq!(bls12381_hex_to_bytes(" 00ff\n".into()), [0x00, 0xff]);
+        assert!(bls12381_hex_to_bytes("0x".into()).is_empty());
+    }
+
+    #[test]
+    fn hex_rejects_invalid() {
+        assert!(bls12381_hex_to_bytes("abc".into()).is_empty());
+        assert!(bls12381_hex_to_bytes("0xabc".into()).is_empty());
+        assert!(bls12381_hex_to_bytes("zz".into()).is_empty());
+        assert!(bls12381_hex_to_bytes("0x12 34".into()).is_empty());
+        assert!(bls12381_hex_to_bytes("0x0x12".into()).is_empty());
+    }
+
+    // ---- round trips ----
+
+    #[test]
+    fn pubkey_round_trip() {
+        let (_, pk) = keygen(b"test-seed-for-bls-uncompressed!!");
+        let compressed = pk.to_bytes().to_vec();
+        let uncompressed = bls12381_min_pk_pubkey_uncompress(compressed.clone());
+        assert_eq!(uncompressed.len(), PK_UNCOMPRESSED_LEN);
+        assert_eq!(uncompressed, pk.serialize().to_vec());
+        assert_eq!(bls12381_min_pk_pubkey_compress(uncompressed), compressed);
+    }
+
+    #[test]
+    fn pubkey_decompress_round_trips() {
+        let (_, pk) = keygen(b"test-seed-for-bls-decompress!!!!");
+        let compressed = pk.to_bytes().to_vec();
+        let uncompressed = pk.serialize().to_vec();
+
+        // 48 → 96 → 48 and 96 → 48 → 96.
+        assert_eq!(
+            bls12381_min_pk_pubkey_compress(bls12381_min_pk_pubkey_decompress(compressed.clone())),
+            compressed
+        );
+        assert_eq!(
+            bls12381_min_pk_pubkey_decompress(bls12381_min_pk_pubkey_compress(
+                uncompressed.clone()
+            )),
+            uncompressed
+        );
+
+        assert!(bls12381_min_pk_pubkey_decompress(vec![0x80; 50]).is_empty());
+        assert!(bls12381_min_pk_pubkey_compress(vec![0u8; 50]).is_empty());
+        // Off the curve: a valid-looking x with no matching y.
+        let mut off_curve = uncompressed;
+        off_curve[95] ^= 1;
+        assert!(bls12381_min_pk_pubkey_compress(off_curve).is_empty());
+    }
+
+    #[test]
+    fn sig_round_trip() {
+        let (sk, _) = keygen(b"test-seed-for-bls-uncompressed!!");
+        let compressed = sign_msg(&sk, b"round trip").to_bytes().to_vec();
+        let uncompressed = bls12381_min_pk_sig_uncompress(compressed.clone());
+        assert_eq!(uncompressed.len(), SIG_UNCOMPRESSED_LEN);
+        assert_eq!(bls12381_min_pk_sig_compress(uncompressed), compressed);
+    }
+
+    #[test]
+    fn conversions_reject_wrong_form() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-uncompressed!!");
+        let pk = pk.to_bytes().to_vec();
+        let sig = sign_msg(&sk, b"round trip").to_bytes().to_vec();
+
+        // Each function only accepts the form it converts from.
+        assert!(bls12381_min_pk_pubkey_compress(pk.clone()).is_empty());
+        assert!(bls12381_min_pk_sig_compress(sig.clone()).is_empty());
+        assert!(
+            bls12381_min_pk_pubkey_uncompress(bls12381_min_pk_pubkey_uncompress(pk)).is_empty()
+        );
+        assert!(bls12381_min_pk_sig_uncompress(bls12381_min_pk_sig_uncompress(sig)).is_empty());
+
+        assert!(bls12381_min_pk_pubkey_uncompress(vec![0u8; PK_LEN]).is_empty());
+        assert!(bls12381_min_pk_pubkey_compress(vec![1u8; PK_UNCOMPRESSED_LEN]).is_empty());
+    }
+
+    // ---- verify ----
+
+    #[test]
+    fn verify_uncompressed_inputs() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-uncompressed!!");
+        let msg = b"cached key".to_vec();
+        let sig = sign_msg(&sk, &msg);
+        let sig_u = sig.serialize().to_vec();
+        let pk_u = pk.serialize().to_vec();
+
+        assert!(bls12381_min_pk_verify_uncompressed(
+            sig_u.clone(),
+            pk_u.clone(),
+            msg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_uncompressed(
+            sig_u,
+            pk_u,
+            b"other".to_vec()
+        ));
+        // Compressed inputs go through the regular API instead.
+        assert!(!bls12381_min_pk_verify_uncompressed(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.clone()
+        ));
+        assert!(bls12381_min_pk_verify(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg
+        ));
+    }
+
+    #[test]
+    fn verify_any_encoding() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-verify-any!!!!");
+        let msg = b"mixed sources".to_vec();
+        let sig = sign_msg(&sk, &msg);
+        let sigs = [sig.to_bytes().to_vec(), sig.serialize().to_vec()];
+        let pks = [pk.to_bytes().to_vec(), pk.serialize().to_vec()];
+
+        for sig in &sigs {
+            for pk in &pks {
+                assert!(bls12381_min_pk_verify_any(
+                    sig.clone(),
+                    pk.clone(),
+                    msg.clone()
+                ));
+                assert!(!bls12381_min_pk_verify_any(
+                    sig.clone(),
+                    pk.clone(),
+                    b"other".to_vec()
+                ));
+            }
+        }
+
+        // A signature passed as the key, and lengths matching no encoding.
+        assert!(!bls12381_min_pk_verify_any(
+            sigs[0].clone(),
+            sigs[0].clone(),
+            msg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_any(
+            sigs[0].clone(),
+            vec![0u8; 50],
+            msg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_any(
+            vec![0u8; 100],
+            pks[0].clone(),
+            msg
+        ));
+    }
+
+    // ---- equality ----
+
+    #[test]
+    fn pubkeys_equal_across_encodings() {
+        let (_, pk) = keygen(b"test-seed-for-bls-pubkey-equal!!");
+        let (_, other) = keygen(b"test-seed-for-bls-pubkey-other!!");
+        let compressed = pk.to_bytes().to_vec();
+        let uncompressed = pk.serialize().to_vec();
+
+        assert!(bls12381_min_pk_pubkeys_equal(
+            compressed.clone(),
+            uncompressed.clone()
+        ));
+        assert!(bls12381_min_pk_pubkeys_equal(
+            uncompressed.clone(),
+            uncompressed.clone()
+        ));
+        assert!(!bls12381_min_pk_pubkeys_equal(
+            compressed.clone(),
+            other.serialize().to_vec()
+        ));
+        assert!(!bls12381_min_pk_pubkeys_equal(compressed, vec![0u8; 48]));
+        assert!(!bls12381_min_pk_pubkeys_equal(vec![0u8; 50], vec![0u8; 50]));
+    }
+
+    #[test]
+    fn sigs_equal_across_encodings() {
+        let (sk, _) = keygen(b"test-seed-for-bls-pubkey-equal!!");
+        let sig = sign_msg(&sk, b"same");
+        let other = sign_msg(&sk, b"different");
+
+        assert!(bls12381_min_pk_sigs_equal(
+            sig.to_bytes().to_vec(),
+            sig.serialize().to_vec()
+        ));
+        assert!(!bls12381_min_pk_sigs_equal(
+            sig.to_bytes().to_vec(),
+            other.serialize().to_vec()
+        ));
+        assert!(!bls12381_min_pk_sigs_equal(
+            sig.to_bytes().to_vec(),
+            vec![0u8; 96]
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_uncompressed_keys() {
+        let keys = committee("uncompressed", 4);
+        let msg = b"cached committee".to_vec();
+        let pks: Vec<Vec<u8>> = pks_of(&keys)
+            .into_iter()
+            .map(bls12381_min_pk_pubkey_uncompress)
+            .collect();
+        let agg = agg_sign(&keys, &[0, 1, 2, 3], &msg);
+
+        assert!(bls12381_min_pk_verify_aggregate_uncompressed(
+            pks.clone(),
+            msg.clone(),
+            agg.clone()
+        ));
+        assert!(bls12381_min_pk_verify_aggregate_uncompressed(
+            pks.clone(),
+            msg.clone(),
+            bls12381_min_pk_sig_uncompress(agg.clone())
+        ));
+        assert!(!bls12381_min_pk_verify_aggregate_uncompressed(
+            pks[..3].to_vec(),
+            msg.clone(),
+            agg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify_aggregate_uncompressed(
+            vec![],
+            msg,
+            agg
+        ));
+    }
+}
@@ -0,0 +1,950 @@
+// Secret key management: derivation of child and session keys from a
+// master key, and password-protected export for moving keys between
+// devices.
+//
+// Secret keys are 32-byte big-endian scalars. blst's `SecretKey` wipes
+// itself on drop. Key bytes arriving from Dart are held in a `SecretBytes`
+// guard, which wipes them once the call is done; other buffers holding key
+// material are wiped explicitly with `zeroize` before they are released.
+
+use blst::min_pk::{PublicKey, SecretKey};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use subtle::{Choice, ConstantTimeEq, ConstantTimeLess};
+use zeroize::Zeroize;
+
+use crate::api::bls::DST;
+use crate::bip39;
+use crate::hash::{pbkdf2_hmac_sha256, sha256};
+
+/// Secret key material received across the FFI boundary, wiped on drop.
+///
+/// flutter_rust_bridge hands each call its own copy of the bytes, so
+/// wrapping the argument makes sure that copy doesn't outlive the call.
+pub(crate) struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    pub(crate) fn new(bytes: Vec<u8>) -> SecretBytes {
+        SecretBytes(bytes)
+    }
+
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Parse the bytes as a secret key.
+    pub(crate) fn secret_key(&self) -> Option<SecretKey> {
+        SecretKey::from_bytes(&self.0).ok()
+    }
+}
+
+impl Zeroize for SecretBytes {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Generate a key pair deterministically from a seed.
+///
+/// * `seed` – input keying material, at least 32 bytes
+///
+/// Uses the IETF `KeyGen` procedure with an empty `key_info`, so the same
+/// seed always yields the same key pair. The seed buffer is wiped.
+///
+/// Returns `(secret_key_32, public_key_48)`, or two empty vectors when the
+/// seed is shorter than 32 bytes.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_keygen_from_seed(seed: Vec<u8>) -> (Vec<u8>, Vec<u8>) {
+    let seed = SecretBytes::new(seed);
+    match SecretKey::key_gen(seed.as_slice(), &[]) {
+        Ok(sk) => (sk.to_bytes().to_vec(), sk.sk_to_pk().to_bytes().to_vec()),
+        Err(_) => (vec![], vec![]),
+    }
+}
+
+/// Generate one key pair per seed, like [`bls12381_min_pk_keygen_from_seed`]
+/// in a single call.
+///
+/// * `seeds` – input keying material for each key pair, at least 32 bytes
+///
+/// A short seed doesn't abort the batch; it just leaves empty entries at
+/// its index. Every seed buffer is wiped.
+///
+/// Returns `(secret_keys_32, public_keys_48)`, index-aligned with `seeds`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_batch_keygen(seeds: Vec<Vec<u8>>) -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+    seeds
+        .into_iter()
+        .map(bls12381_min_pk_keygen_from_seed)
+        .unzip()
+}
+
+/// Generate a fresh random key pair.
+///
+/// The 32 bytes of input keying material come from the operating system's
+/// entropy source (via `getrandom`, which also covers Windows and the
+/// browser's `crypto.getRandomValues` on web), so nothing depends on the
+/// quality of a Dart RNG. They go through the same `KeyGen` as
+/// [`bls12381_min_pk_keygen_from_seed`] and are wiped afterwards.
+///
+/// Returns `(secret_key_32, public_key_48)`, or two empty vectors when the
+/// entropy source can't be read.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_generate_keypair() -> (Vec<u8>, Vec<u8>) {
+    let mut ikm = SecretBytes::new(vec![0u8; 32]);
+    if getrandom::getrandom(&mut ikm.0).is_err() {
+        return (vec![], vec![]);
+    }
+    match SecretKey::key_gen(ikm.as_slice(), &[]) {
+        Ok(sk) => (sk.to_bytes().to_vec(), sk.sk_to_pk().to_bytes().to_vec()),
+        Err(_) => (vec![], vec![]),
+    }
+}
+
+/// Derive the public key belonging to a secret key.
+///
+/// * `sk_bytes` – 32-byte secret key
+///
+/// The input buffer is wiped.
+///
+/// Returns the 48-byte compressed G1 public key, or an empty `Vec<u8>`
+/// when `sk_bytes` is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sk_to_pk(sk_bytes: Vec<u8>) -> Vec<u8> {
+    match SecretBytes::new(sk_bytes).secret_key() {
+        Some(sk) => sk.sk_to_pk().to_bytes().to_vec(),
+        None => vec![],
+    }
+}
+
+/// Order `r` of the BLS12-381 scalar field, big-endian.
+const GROUP_ORDER: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// Whether a 32-byte big-endian scalar lies in `1..r`, in constant time.
+fn scalar_in_range_ct(bytes: &[u8; 32]) -> bool {
+    // Big-endian comparison: the first byte that differs from `r` decides.
+    let mut less = Choice::from(0);
+    let mut equal = Choice::from(1);
+    for (x, r) in bytes.iter().zip(&GROUP_ORDER) {
+        less |= equal & x.ct_lt(r);
+        equal &= x.ct_eq(r);
+    }
+    (less & !bytes.ct_eq(&[0u8; 32])).into()
+}
+
+/// Import a secret key from an untrusted source without leaking, through
+/// timing, why it was rejected.
+///
+/// * `sk_bytes` – 32-byte big-endian secret key
+///
+/// The range check against the group order runs in constant time, and the
+/// key is only parsed once it passed. The input buffer is wiped.
+///
+/// Returns the canonical 32-byte secret key, or an empty `Vec<u8>` when
+/// `sk_bytes` is not 32 bytes or not a nonzero scalar below the group
+/// order.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_import_sk_ct(sk_bytes: Vec<u8>) -> Vec<u8> {
+    let sk_bytes = SecretBytes::new(sk_bytes);
+    let in_range = match <&[u8; 32]>::try_from(sk_bytes.as_slice()) {
+        Ok(bytes) => scalar_in_range_ct(bytes),
+        Err(_) => false,
+    };
+    if !in_range {
+        return vec![];
+    }
+    match sk_bytes.secret_key() {
+        Some(sk) => sk.to_bytes().to_vec(),
+        None => vec![],
+    }
+}
+
+/// Check whether raw bytes form a valid secret key.
+///
+/// * `sk_bytes` – candidate 32-byte big-endian secret key
+///
+/// A valid key is a nonzero scalar below the group order. The input buffer
+/// is wiped.
+///
+/// Returns `true` when `sk_bytes` can be used to sign, `false` otherwise.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_validate_secret_key(sk_bytes: Vec<u8>) -> bool {
+    SecretBytes::new(sk_bytes).secret_key().is_some()
+}
+
+/// Turn a BIP39 mnemonic phrase into its 64-byte wallet seed.
+///
+/// * `mnemonic`   – 12 to 24 lowercase words from the BIP39 English list
+/// * `passphrase` – optional extra passphrase, empty for none
+///
+/// The word list and checksum are validated before the seed is derived
+/// with PBKDF2-HMAC-SHA-512. Passing the seed to
+/// [`bls12381_derive_master_sk`] recovers the key tree backed up by the
+/// phrase. A non-ASCII passphrase must already be NFKD-normalized. Both
+/// strings are wiped.
+///
+/// Returns the 64-byte seed, or an empty `Vec<u8>` when the phrase has an
+/// unknown word, an unsupported length or a bad checksum.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_mnemonic_to_seed(mut mnemonic: String, mut passphrase: String) -> Vec<u8> {
+    let seed = match bip39::mnemonic_to_entropy(&mnemonic) {
+        Some(mut entropy) => {
+            entropy.zeroize();
+            bip39::mnemonic_seed(&mnemonic, &passphrase).to_vec()
+        }
+        None => vec![],
+    };
+    mnemonic.zeroize();
+    passphrase.zeroize();
+    seed
+}
+
+/// Derive the EIP-2333 master secret key from a seed.
+///
+/// * `seed` – input keying material, at least 32 bytes
+///
+/// The root of an EIP-2333 key tree, compatible with Ethereum consensus
+/// wallets; children come from [`bls12381_derive_child_sk`]. The seed
+/// buffer is wiped.
+///
+/// Returns the 32-byte master secret key, or an empty `Vec<u8>` when the
+/// seed is shorter than 32 bytes.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_derive_master_sk(seed: Vec<u8>) -> Vec<u8> {
+    match SecretKey::derive_master_eip2333(SecretBytes::new(seed).as_slice()) {
+        Ok(sk) => sk.to_bytes().to_vec(),
+        Err(_) => vec![],
+    }
+}
+
+/// Derive an EIP-2333 child secret key.
+///
+/// * `parent_sk` – 32-byte parent secret key
+/// * `index`     – child index
+///
+/// Applying this along a path such as `m/12381/3600/0/0` from
+/// [`bls12381_derive_master_sk`] gives the same keys as other EIP-2333
+/// implementations. The parent buffer is wiped.
+///
+/// Returns the 32-byte child secret key, or an empty `Vec<u8>` when
+/// `parent_sk` is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_derive_child_sk(parent_sk: Vec<u8>, index: u32) -> Vec<u8> {
+    match SecretBytes::new(parent_sk).secret_key() {
+        Some(parent) => parent.derive_child_eip2333(index).to_bytes().to_vec(),
+        None => vec![],
+    }
+}
+
+/// Derive the secret key for one storage shard from a node's master key.
+///
+/// * `master_sk`   – 32-byte master secret key
+/// * `shard_index` – index of the shard the key is bound to
+///
+/// Uses EIP-2333 child derivation with `shard_index` as the child index, so
+/// the same master key and index always yield the same shard key, and shard
+/// keys can't be used to recover the master key.
+///
+/// Returns the 32-byte shard secret key, or an empty `Vec<u8>` when
+/// `master_sk` is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_derive_shard_key(master_sk: Vec<u8>, shard_index: u32) -> Vec<u8> {
+    bls12381_derive_child_sk(master_sk, shard_index)
+}
+
+/// A short-lived key pair derived from a long-term master key for one
+/// session. The secret key never leaves the handle and is wiped on drop.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct SessionKeyPair {
+    sk: SecretKey,
+    pk: PublicKey,
+}
+
+impl SessionKeyPair {
+    /// The 48-byte compressed G1 session public key.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.pk.to_bytes().to_vec()
+    }
+
+    /// Sign `msg` with the session key, returning a 96-byte compressed G2
+    /// signature that verifies under [`SessionKeyPair::public_key`].
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn sign(&self, msg: Vec<u8>) -> Vec<u8> {
+        self.sk.sign(&msg, DST, &[]).to_bytes().to_vec()
+    }
+}
+
+/// Derive a session key pair from a master secret key.
+///
+/// * `master_sk`  – 32-byte master secret key
+/// * `session_id` – identifier of the session the key is bound to
+///
+/// The session secret key is the IETF `KeyGen` (HKDF-SHA-256) output with
+/// the master key as input keying material and `session_id` as `key_info`,
+/// so each session id yields an unrelated key.
+///
+/// Returns `None` when `master_sk` is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_session_keypair(master_sk: Vec<u8>, session_id: Vec<u8>) -> Option<SessionKeyPair> {
+    let master_sk = SecretBytes::new(master_sk);
+    master_sk.secret_key()?;
+    let sk = SecretKey::key_gen(master_sk.as_slice(), &session_id).ok()?;
+    let pk = sk.sk_to_pk();
+    Some(SessionKeyPair { sk, pk })
+}
+
+/// Version byte of the identity export format.
+const IDENTITY_VERSION: u8 = 2;
+/// PBKDF2 iterations used for new exports.
+const IDENTITY_ITERATIONS: u32 = 100_000;
+/// Largest iteration count accepted on import, to bound the work a blob
+/// can demand.
+const IDENTITY_MAX_ITERATIONS: u32 = 10_000_000;
+const IDENTITY_SALT_LEN: usize = 16;
+const IDENTITY_NONCE_LEN: usize = 12;
+/// Version, iteration count, salt and nonce.
+const IDENTITY_HEADER_LEN: usize = 1 + 4 + IDENTITY_SALT_LEN + IDENTITY_NONCE_LEN;
+/// Secret key plus a 4-byte public key checksum.
+const IDENTITY_PLAINTEXT_LEN: usize = 32 + 4;
+const IDENTITY_TAG_LEN: usize = 16;
+const IDENTITY_BLOB_LEN: usize = IDENTITY_HEADER_LEN + IDENTITY_PLAINTEXT_LEN + IDENTITY_TAG_LEN;
+
+/// First 4 bytes of SHA-256 over the compressed public key.
+fn pk_checksum(pk: &PublicKey) -> [u8; 4] {
+    let digest = sha256(&pk.to_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// The ChaCha20-Poly1305 cipher keyed by the stretched password.
+fn identity_cipher(password: &[u8], salt: &[u8], iterations: u32) -> ChaCha20Poly1305 {
+    let mut key = [0u8; 32];
+    pbkdf2_hmac_sha256(password, salt, iterations, &mut key);
+    let cipher = ChaCha20Poly1305::new(&key.into());
+    key.zeroize();
+    cipher
+}
+
+/// Export a signing identity as a password-encrypted blob.
+///
+/// * `sk_bytes` – 32-byte secret key
+/// * `password` – password protecting the export
+///
+/// Layout (version 2):
+///
+/// ```text
+/// version: u8 = 2 || iterations: u32_le || salt: 16 bytes || nonce: 12 bytes
+///   || ciphertext: 36 bytes || tag: 16 bytes
+/// ```
+///
+/// The password is stretched with PBKDF2-HMAC-SHA-256 over a random salt
+/// into a ChaCha20-Poly1305 key, which seals `sk || SHA-256(pk)[..4]` under
+/// a random nonce with the header as associated data. Salt and nonce come
+/// from the OS RNG, so every export of the same key is different and no
+/// password guesses can be precomputed for it.
+///
+/// Returns the 85-byte blob, or an empty `Vec<u8>` when `sk_bytes` is
+/// malformed or the OS entropy source fails.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_export_identity(sk_bytes: Vec<u8>, password: String) -> Vec<u8> {
+    export_identity(sk_bytes, &password, IDENTITY_ITERATIONS)
+}
+
+/// [`bls12381_export_identity`] with an explicit PBKDF2 iteration count.
+fn export_identity(sk_bytes: Vec<u8>, password: &str, iterations: u32) -> Vec<u8> {
+    let sk = match SecretBytes::new(sk_bytes).secret_key() {
+        Some(sk) => sk,
+        None => return vec![],
+    };
+    let pk = sk.sk_to_pk();
+
+    let mut salt_nonce = [0u8; IDENTITY_SALT_LEN + IDENTITY_NONCE_LEN];
+    if getrandom::getrandom(&mut salt_nonce).is_err() {
+        return vec![];
+    }
+    let (salt, nonce) = salt_nonce.split_at(IDENTITY_SALT_LEN);
+    let mut blob = Vec::with_capacity(IDENTITY_BLOB_LEN);
+    blob.push(IDENTITY_VERSION);
+    blob.extend_from_slice(&iterations.to_le_bytes());
+    blob.extend_from_slice(&salt_nonce);
+
+    let mut plaintext = sk.to_bytes().to_vec();
+    plaintext.extend_from_slice(&pk_checksum(&pk));
+    let sealed = identity_cipher(password.as_bytes(), salt, iterations).encrypt(
+        Nonce::from_slice(nonce),
+        Payload {
+            msg: &plaintext,
+            aad: &blob,
+        },
+    );
+    plaintext.zeroize();
+    match sealed {
+        Ok(sealed) => {
+            blob.extend_from_slice(&sealed);
+            blob
+        }
+        Err(_) => vec![],
+    }
+}
+
+/// A signing identity recovered from an export.
+pub struct IdentityImport {
+    /// 32-byte secret key.
+    pub secret_key: Vec<u8>,
+    /// 48-byte compressed G1 public key, checked against the export.
+    pub public_key: Vec<u8>,
+}
+
+/// Import a signing identity from [`bls12381_export_identity`].
+///
+/// * `blob`     – the exported bytes
+/// * `password` – the password used for the export
+///
+/// Returns `None` when the blob is malformed or has an unknown version, the
+/// password is wrong, the blob was tampered with, or the decrypted key
+/// does not match its public key checksum.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_import_identity(blob: Vec<u8>, password: String) -> Option<IdentityImport> {
+    if blob.len() != IDENTITY_BLOB_LEN || blob[0] != IDENTITY_VERSION {
+        return None;
+    }
+    let iterations = u32::from_le_bytes(blob[1..5].try_into().ok()?);
+    if iterations == 0 || iterations > IDENTITY_MAX_ITERATIONS {
+        return None;
+    }
+    let (header, sealed) = blob.split_at(IDENTITY_HEADER_LEN);
+    let (salt, nonce) = header[5..].split_at(IDENTITY_SALT_LEN);
+
+    // A wrong password and a tampered blob both fail authentication here.
+    let mut plaintext = identity_cipher(password.as_bytes(), salt, iterations)
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: sealed,
+                aad: header,
+            },
+        )
+        .ok()?;
+    let sk = SecretKey::from_bytes(&plaintext[..32]);
+    let checksum = [plaintext[32], plaintext[33], plaintext[34], plaintext[35]];
+    plaintext.zeroize();
+
+    let sk = sk.ok()?;
+    let pk = sk.sk_to_pk();
+    if pk_checksum(&pk) != checksum {
+        return None;
+    }
+    Some(IdentityImport {
+        secret_key: sk.to_bytes().to_vec(),
+        public_key: pk.to_bytes().to_vec(),
+    })
+}
+
+/// DER header of a wrapped secret key: a PKCS#8 `OneAsymmetricKey` laid
+/// out as in RFC 8410, with the BLS12-381 OID `1.3.6.1.4.1.44668.5.3.2.1`
+/// used by other BLS tooling as the algorithm.
+///
+/// ```text
+/// SEQUENCE (55) {
+///   INTEGER 0
+///   SEQUENCE (14) { OID 1.3.6.1.4.1.44668.5.3.2.1 }
+///   OCTET STRING (34) { OCTET STRING (32) { secret key } }
+/// }
+/// ```
+const SK_DER_PREFIX: [u8; 25] = [
+    0x30, 0x37, 0x02, 0x01, 0x00, 0x30, 0x0e, 0x06, 0x0c, 0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0xdc,
+    0x7c, 0x05, 0x03, 0x02, 0x01, 0x04, 0x22, 0x04, 0x20,
+];
+const SK_DER_LEN: usize = SK_DER_PREFIX.len() + 32;
+
+/// Wrap a secret key in a PKCS#8-style DER structure.
+///
+/// * `sk_bytes` – 32-byte secret key
+///
+/// The layout is fixed (see [`bls12381_min_pk_import_sk_der`]), so the
+/// result is always 57 bytes. The input buffer is wiped.
+///
+/// Returns the DER-encoded key, or an empty `Vec<u8>` when `sk_bytes` is
+/// malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_export_sk_der(sk_bytes: Vec<u8>) -> Vec<u8> {
+    let sk = match SecretBytes::new(sk_bytes).secret_key() {
+        Some(sk) => sk,
+        None => return vec![],
+    };
+    let mut der = Vec::with_capacity(SK_DER_LEN);
+    der.extend_from_slice(&SK_DER_PREFIX);
+    der.extend_from_slice(&sk.to_bytes());
+    der
+}
+
+/// Unwrap a secret key from [`bls12381_min_pk_export_sk_der`].
+///
+/// * `der` – DER-encoded key
+///
+/// Only the exact structure written on export is accepted: a different
+/// algorithm OID, optional PKCS#8 attributes or a public key field are all
+/// rejected. The input buffer is wiped.
+///
+/// Returns the 32-byte secret key, or an empty `Vec<u8>` when `der` is
+/// malformed or holds an invalid scalar.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_import_sk_der(der: Vec<u8>) -> Vec<u8> {
+    let der = SecretBytes::new(der);
+    let bytes = der.as_slice();
+    if bytes.len() != SK_DER_LEN || bytes[..SK_DER_PREFIX.len()] != SK_DER_PREFIX {
+        return vec![];
+    }
+    match SecretKey::from_bytes(&bytes[SK_DER_PREFIX.len()..]) {
+        Ok(sk) => sk.to_bytes().to_vec(),
+        Err(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bls::{bls12381_min_pk_sign, bls12381_min_pk_verify};
+    use crate::test_utils::keygen;
+
+    // ---- keygen ----
+
+    #[test]
+    fn keygen_from_seed_deterministic() {
+        let seed = b"test-seed-for-bls-keygen-export!".to_vec();
+        let (sk_a, pk_a) = bls12381_min_pk_keygen_from_seed(seed.clone());
+        let (sk_b, pk_b) = bls12381_min_pk_keygen_from_seed(seed.clone());
+
+        assert_eq!(sk_a.len(), 32);
+        assert_eq!(pk_a.len(), 48);
+        assert_eq!((sk_a.clone(), pk_a.clone()), (sk_b, pk_b));
+
+        let (sk, pk) = keygen(seed.as_slice().try_into().unwrap());
+        assert_eq!(sk_a, sk.to_bytes().to_vec());
+        assert_eq!(pk_a, pk.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn keygen_from_short_seed() {
+        assert_eq!(
+            bls12381_min_pk_keygen_from_seed(vec![7u8; 10]),
+            (vec![], vec![])
+        );
+    }
+
+    #[test]
+    fn batch_keygen_matches_single() {
+        let mut seeds: Vec<Vec<u8>> = (0..5u8).map(|i| vec![i + 1; 32]).collect();
+        seeds[2] = vec![9u8; 31];
+        let (sks, pks) = bls12381_min_pk_batch_keygen(seeds.clone());
+        assert_eq!((sks.len(), pks.len()), (5, 5));
+        assert_eq!(
+            bls12381_min_pk_batch_keygen(seeds.clone()),
+            (sks.clone(), pks.clone())
+        );
+
+        for (i, seed) in seeds.into_iter().enumerate() {
+            if i == 2 {
+                assert!(sks[i].is_empty() && pks[i].is_empty());
+            } else {
+                assert_eq!(
+                    (sks[i].clone(), pks[i].clone()),
+                    bls12381_min_pk_keygen_from_seed(seed)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generate_keypair_is_fresh_and_usable() {
+        let (sk_a, pk_a) = bls12381_min_pk_generate_keypair();
+        let (sk_b, pk_b) = bls12381_min_pk_generate_keypair();
+        assert_eq!(sk_a.len(), 32);
+        assert_eq!(pk_a.len(), 48);
+        assert_ne!(sk_a, sk_b);
+        assert_ne!(pk_a, pk_b);
+
+        for (sk, pk) in [(sk_a, pk_a), (sk_b, pk_b)] {
+            assert_eq!(bls12381_min_pk_sk_to_pk(sk.clone()), pk);
+            let sig = bls12381_min_pk_sign(sk, b"fresh".to_vec());
+            assert!(bls12381_min_pk_verify(sig, pk, b"fresh".to_vec()));
+        }
+    }
+
+    // ---- sk to pk ----
+
+    #[test]
+    fn sk_to_pk_matches_keygen() {
+        let (sk, pk) =
+            bls12381_min_pk_keygen_from_seed(b"test-seed-for-bls-sk-to-pk-check".to_vec());
+        assert_eq!(bls12381_min_pk_sk_to_pk(sk), pk);
+    }
+
+    #[test]
+    fn sk_to_pk_malformed() {
+        assert!(bls12381_min_pk_sk_to_pk(vec![1u8; 31]).is_empty());
+        assert!(bls12381_min_pk_sk_to_pk(vec![0u8; 32]).is_empty());
+    }
+
+    // ---- validate secret key ----
+
+    #[test]
+    fn validate_secret_key() {
+        let (sk, _) = keygen(b"test-seed-for-bls-validate-sk!!!");
+        assert!(bls12381_min_pk_validate_secret_key(sk.to_bytes().to_vec()));
+
+        assert!(!bls12381_min_pk_validate_secret_key(vec![0u8; 32]));
+        assert!(!bls12381_min_pk_validate_secret_key(vec![1u8; 31]));
+        // Above the group order r = 0x73ed...0001.
+        assert!(!bls12381_min_pk_validate_secret_key(vec![0xff; 32]));
+    }
+
+    // ---- constant-time import ----
+
+    #[test]
+    fn import_sk_ct_round_trip() {
+        let (sk, _) = keygen(b"test-seed-for-bls-import-ct!!!!!");
+        let bytes = sk.to_bytes().to_vec();
+        assert_eq!(bls12381_min_pk_import_sk_ct(bytes.clone()), bytes);
+
+        let mut r_minus_1 = GROUP_ORDER;
+        r_minus_1[31] -= 1;
+        assert_eq!(bls12381_min_pk_import_sk_ct(r_minus_1.to_vec()), r_minus_1);
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(bls12381_min_pk_import_sk_ct(one.to_vec()), one);
+    }
+
+    #[test]
+    fn import_sk_ct_rejects_out_of_range() {
+        let mut r_plus_1 = GROUP_ORDER;
+        r_plus_1[31] += 1;
+        for bad in [GROUP_ORDER, r_plus_1, [0xff; 32], [0u8; 32]] {
+            assert!(!scalar_in_range_ct(&bad));
+            assert!(bls12381_min_pk_import_sk_ct(bad.to_vec()).is_empty());
+            // Agrees with blst's own check.
+            assert!(!bls12381_min_pk_validate_secret_key(bad.to_vec()));
+        }
+        assert!(bls12381_min_pk_import_sk_ct(vec![1u8; 31]).is_empty());
+        assert!(bls12381_min_pk_import_sk_ct(vec![1u8; 33]).is_empty());
+    }
+
+    // ---- EIP-2333 ----
+
+    /// `(seed, master_sk, child_index, child_sk)` test cases from EIP-2333,
+    /// with the keys converted from decimal to big-endian hex.
+    const EIP2333_VECTORS: [(&str, &str, u32, &str); 4] = [
+        (
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070",
+            0,
+            "2d18bd6c14e6d15bf8b5085c9b74f3daae3b03cc2014770a599d8c1539e50f8e",
+        ),
+        (
+            "3141592653589793238462643383279502884197169399375105820974944592",
+            "41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7",
+            3141592653,
+            "384843fad5f3d777ea39de3e47a8f999ae91f89e42bffa993d91d9782d152a0f",
+        ),
+        (
+            "0099ff991111002299dd7744ee3355bbdd8844115566cc55663355668888cc00",
+            "3cfa341ab3910a7d00d933d8f7c4fe87c91798a0397421d6b19fd5b815132e80",
+            4294967295,
+            "40e86285582f35b28821340f6a53b448588efa575bc4d88c32ef8567b8d9479b",
+        ),
+        (
+            "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+            "2a0e28ffa5fbbe2f8e7aad4ed94f745d6bf755c51182e119bb1694fe61d3afca",
+            42,
+            "455c0dc9fccb3395825d92a60d2672d69416be1c2578a87a7a3d3ced11ebb88d",
+        ),
+    ];
+
+    #[test]
+    fn eip2333_spec_vectors() {
+        for (seed, master, index, child) in EIP2333_VECTORS {
+            let master_sk = bls12381_derive_master_sk(hex::decode(seed).unwrap());
+            assert_eq!(hex::encode(&master_sk), master);
+            assert_eq!(
+                hex::encode(bls12381_derive_child_sk(master_sk, index)),
+                child
+            );
+        }
+    }
+
+    #[test]
+    fn eip2333_deterministic_path() {
+        let seed = vec![7u8; 32];
+        let path = [12381u32, 3600, 0, 0];
+        let derive = || {
+            path.iter()
+                .fold(bls12381_derive_master_sk(seed.clone()), |sk, &i| {
+                    bls12381_derive_child_sk(sk, i)
+                })
+        };
+        let a = derive();
+        assert_eq!(a.len(), 32);
+        assert_eq!(a, derive());
+        assert_ne!(
+            a,
+            bls12381_derive_child_sk(bls12381_derive_master_sk(seed), 12381)
+        );
+    }
+
+    #[test]
+    fn eip2333_rejects_bad_input() {
+        assert!(bls12381_derive_master_sk(vec![1u8; 31]).is_empty());
+        assert!(bls12381_derive_child_sk(vec![0u8; 32], 0).is_empty());
+        assert!(bls12381_derive_child_sk(vec![1u8; 16], 0).is_empty());
+    }
+
+    // ---- BIP39 ----
+
+    #[test]
+    fn mnemonic_to_seed_known_vectors() {
+        // Trezor reference vectors, passphrase "TREZOR".
+        let cases = [
+            (
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553\
+                 1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            ),
+            (
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6f\
+                 a457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+            ),
+            (
+                "hamster diagram private dutch cause delay private meat slide toddler razor book \
+                 happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+                "64c87cde7e12ecf6704ab95bb1408bef047c22db4cc7491c4271d170a1b213d2\
+                 0b385bc1588d9c7b38f1b39d415665b8a9030c9ec653d75e65f847d8fc1fc440",
+            ),
+        ];
+        for (mnemonic, seed) in cases {
+            assert_eq!(
+                hex::encode(bls12381_mnemonic_to_seed(mnemonic.into(), "TREZOR".into())),
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn mnemonic_to_seed_rejects_bad_checksum() {
+        let phrase = "abandon ".repeat(12);
+        assert!(bls12381_mnemonic_to_seed(phrase, String::new()).is_empty());
+        assert!(bls12381_mnemonic_to_seed("not a mnemonic".into(), String::new()).is_empty());
+    }
+
+    #[test]
+    fn mnemonic_to_signing_key() {
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let seed = bls12381_mnemonic_to_seed(phrase.into(), String::new());
+        let sk = (0..2).fold(bls12381_derive_master_sk(seed.clone()), |sk, _| {
+            bls12381_derive_child_sk(sk, 0)
+        });
+        // The same phrase recovers the same key on another device.
+        let again = bls12381_derive_child_sk(
+            bls12381_derive_child_sk(bls12381_derive_master_sk(seed), 0),
+            0,
+        );
+        assert_eq!(sk, again);
+        let pk = bls12381_min_pk_sk_to_pk(sk.clone());
+        let sig = bls12381_min_pk_sign(sk, b"recovered".to_vec());
+        assert!(bls12381_min_pk_verify(sig, pk, b"recovered".to_vec()));
+    }
+
+    // ---- shard keys ----
+
+    #[test]
+    fn shard_key_deterministic() {
+        let (master, _) = keygen(b"test-seed-for-bls-master-shard!!");
+        let a = bls12381_derive_shard_key(master.to_bytes().to_vec(), 7);
+        let b = bls12381_derive_shard_key(master.to_bytes().to_vec(), 7);
+        assert_eq!(a.len(), 32);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn shard_keys_differ_per_index() {
+        let (master, _) = keygen(b"test-seed-for-bls-master-shard!!");
+        let a = bls12381_derive_shard_key(master.to_bytes().to_vec(), 0);
+        let b = bls12381_derive_shard_key(master.to_bytes().to_vec(), 1);
+        assert_ne!(a, b);
+        assert_ne!(a, master.to_bytes().to_vec());
+        // The derived key is itself a valid secret key.
+        assert!(SecretKey::from_bytes(&a).is_ok());
+    }
+
+    #[test]
+    fn shard_key_malformed_master() {
+        assert!(bls12381_derive_shard_key(vec![1u8; 16], 0).is_empty());
+        assert!(bls12381_derive_shard_key(vec![0u8; 32], 0).is_empty());
+    }
+
+    // ---- session keys ----
+
+    #[test]
+    fn session_signature_verifies() {
+        let (master, master_pk) = keygen(b"test-seed-for-bls-session-key!!!");
+        let session =
+            bls12381_session_keypair(master.to_bytes().to_vec(), b"s-1".to_vec()).unwrap();
+        let msg = b"session message".to_vec();
+        let sig = session.sign(msg.clone());
+
+        assert!(bls12381_min_pk_verify(
+            sig.clone(),
+            session.public_key(),
+            msg.clone()
+        ));
+        assert!(!bls12381_min_pk_verify(
+            sig,
+            master_pk.to_bytes().to_vec(),
+            msg
+        ));
+    }
+
+    #[test]
+    fn session_keys_differ_per_session() {
+        let (master, _) = keygen(b"test-seed-for-bls-session-key!!!");
+        let a = bls12381_session_keypair(master.to_bytes().to_vec(), b"s-1".to_vec()).unwrap();
+        let b = bls12381_session_keypair(master.to_bytes().to_vec(), b"s-2".to_vec()).unwrap();
+        let again = bls12381_session_keypair(master.to_bytes().to_vec(), b"s-1".to_vec()).unwrap();
+
+        assert_ne!(a.public_key(), b.public_key());
+        assert_eq!(a.public_key(), again.public_key());
+        assert_ne!(a.sign(b"m".to_vec()), b.sign(b"m".to_vec()));
+    }
+
+    #[test]
+    fn session_keypair_malformed_master() {
+        assert!(bls12381_session_keypair(vec![1u8; 16], b"s".to_vec()).is_none());
+        assert!(bls12381_session_keypair(vec![0u8; 32], b"s".to_vec()).is_none());
+    }
+
+    // ---- identity export ----
+
+    /// Iteration count that keeps the tests fast; the format is the same.
+    const TEST_ITERATIONS: u32 = 1_000;
+
+    #[test]
+    fn identity_round_trip() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-identity-move!");
+        let blob = bls12381_export_identity(sk.to_bytes().to_vec(), "hunter2".to_string());
+        assert_eq!(blob.len(), IDENTITY_BLOB_LEN);
+        assert_eq!(blob[0], IDENTITY_VERSION);
+        assert_eq!(blob[1..5], IDENTITY_ITERATIONS.to_le_bytes());
+        // The secret key does not appear in the clear.
+        assert!(!blob.windows(32).any(|w| w == sk.to_bytes()));
+
+        let imported = bls12381_import_identity(blob, "hunter2".to_string()).unwrap();
+        assert_eq!(imported.secret_key, sk.to_bytes().to_vec());
+        assert_eq!(imported.public_key, pk.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn identity_exports_use_fresh_salt() {
+        let (sk, _) = keygen(b"test-seed-for-bls-identity-move!");
+        let a = export_identity(sk.to_bytes().to_vec(), "pw", TEST_ITERATIONS);
+        let b = export_identity(sk.to_bytes().to_vec(), "pw", TEST_ITERATIONS);
+        assert_ne!(a[5..5 + IDENTITY_SALT_LEN], b[5..5 + IDENTITY_SALT_LEN]);
+        assert_ne!(a, b);
+        assert!(bls12381_import_identity(a, "pw".to_string()).is_some());
+        assert!(bls12381_import_identity(b, "pw".to_string()).is_some());
+    }
+
+    #[test]
+    fn identity_rejects_wrong_password() {
+        let (sk, _) = keygen(b"test-seed-for-bls-identity-move!");
+        let blob = export_identity(sk.to_bytes().to_vec(), "hunter2", TEST_ITERATIONS);
+        assert!(bls12381_import_identity(blob.clone(), "hunter2".to_string()).is_some());
+        assert!(bls12381_import_identity(blob, "hunter3".to_string()).is_none());
+    }
+
+    #[test]
+    fn identity_rejects_corruption() {
+        let (sk, _) = keygen(b"test-seed-for-bls-identity-move!");
+        let blob = export_identity(sk.to_bytes().to_vec(), "pw", TEST_ITERATIONS);
+
+        // Salt, ciphertext and tag are all authenticated.
+        for i in [10, 40, IDENTITY_BLOB_LEN - 1] {
+            let mut flipped = blob.clone();
+            flipped[i] ^= 0x01;
+            assert!(bls12381_import_identity(flipped, "pw".to_string()).is_none());
+        }
+
+        let mut version = blob.clone();
+        version[0] = 1;
+        assert!(bls12381_import_identity(version, "pw".to_string()).is_none());
+
+        let truncated = blob[..IDENTITY_BLOB_LEN - 1].to_vec();
+        assert!(bls12381_import_identity(truncated, "pw".to_string()).is_none());
+        assert!(bls12381_export_identity(vec![0u8; 32], "pw".to_string()).is_empty());
+    }
+
+    // ---- DER ----
+
+    #[test]
+    fn sk_der_round_trip() {
+        let (sk, _) = keygen(b"test-seed-for-bls-der-export!!!!");
+        let der = bls12381_min_pk_export_sk_der(sk.to_bytes().to_vec());
+        assert_eq!(der.len(), 57);
+        assert_eq!(
+            hex::encode(&der[..SK_DER_PREFIX.len()]),
+            "3037020100300e060c2b0601040182dc7c0503020104220420"
+        );
+        assert_eq!(der[SK_DER_PREFIX.len()..], sk.to_bytes());
+        assert_eq!(bls12381_min_pk_import_sk_der(der), sk.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn sk_der_rejects_malformed() {
+        let (sk, _) = keygen(b"test-seed-for-bls-der-export!!!!");
+        let der = bls12381_min_pk_export_sk_der(sk.to_bytes().to_vec());
+
+        assert!(bls12381_min_pk_import_sk_der(der[..der.len() - 1].to_vec()).is_empty());
+        assert!(bls12381_min_pk_import_sk_der(vec![]).is_empty());
+        // Another algorithm under the same layout.
+        let mut other = der.clone();
+        other[20] ^= 1;
+        assert!(bls12381_min_pk_import_sk_der(other).is_empty());
+        // A well-formed wrapper around the zero scalar.
+        let mut zero = der;
+        zero[SK_DER_PREFIX.len()..].fill(0);
+        assert!(bls12381_min_pk_import_sk_der(zero).is_empty());
+
+        assert!(bls12381_min_pk_export_sk_der(vec![0u8; 31]).is_empty());
+    }
+
+    // ---- secret bytes ----
+
+    #[test]
+    fn secret_bytes_wiped() {
+        assert!(std::mem::needs_drop::<SecretBytes>());
+
+        let (sk, _) = keygen(b"test-seed-for-bls-secret-bytes!!");
+        let mut guard = SecretBytes::new(sk.to_bytes().to_vec());
+        assert!(guard.secret_key().is_some());
+        let ptr = guard.as_slice().as_ptr();
+        let len = guard.as_slice().len();
+
+        // `drop` runs exactly this; calling it directly lets the test look at
+        // the buffer while it is still allocated.
+        guard.zeroize();
+        assert!(guard.as_slice().is_empty());
+        // SAFETY: wiping clears the Vec but keeps its allocation, which
+        // `guard` still owns, and every byte of it is initialized to zero.
+        let wiped = unsafe { std::slice::from_raw_parts(ptr, len) };
+        assert!(wiped.iter().all(|&b| b == 0));
+        drop(guard);
+    }
+}
@@ -0,0 +1,360 @@
+// BLS12-381 min_sig operations, the mirror image of `api::bls`.
+//
+// Here public keys live in G2 and signatures in G1, which suits protocols
+// that store many signatures and few keys. The API matches the min_pk one
+// function for function; only the groups, the encodings and the DST change.
+//
+// Key sizes:
+//   Public key: 96 bytes (compressed G2 point)
+//   Signature:  48 bytes (compressed G1 point)
+
+use blst::min_sig::{AggregateSignature, PublicKey, Signature};
+use blst::{blst_p1_affine, blst_p1_affine_is_inf, BLST_ERROR};
+
+/// Domain Separation Tag for BLS12-381 min_sig (G1 signatures), the IETF
+/// standard NUL scheme.
+pub(crate) const DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// Length of a compressed G2 public key.
+pub(crate) const PK_LEN: usize = 96;
+/// Length of a compressed G1 signature.
+pub(crate) const SIG_LEN: usize = 48;
+
+/// Whether `sig` is the point at infinity, the G1 counterpart of the
+/// min_pk check.
+fn is_identity_sig(sig: &Signature) -> bool {
+    // SAFETY: the pointer refers to a valid, initialized point.
+    unsafe { blst_p1_affine_is_inf(<&blst_p1_affine>::from(sig)) }
+}
+
+/// Verify a single BLS12-381 min_sig signature.
+///
+/// * `sig_bytes` – 48-byte compressed G1 signature
+/// * `pk_bytes`  – 96-byte compressed G2 public key
+/// * `msg`       – arbitrary-length message
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including malformed inputs and the identity signature).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_sig_verify(sig_bytes: Vec<u8>, pk_bytes: Vec<u8>, msg: Vec<u8>) -> bool {
+    if sig_bytes.len() != SIG_LEN || pk_bytes.len() != PK_LEN {
+        return false;
+    }
+    let pk = match PublicKey::from_bytes(&pk_bytes) {
+        Ok(pk) => pk,
+        Err(_) => return false,
+    };
+    let sig = match Signature::from_bytes(&sig_bytes) {
+        Ok(sig) if !is_identity_sig(&sig) => sig,
+        _ => return false,
+    };
+    // Same flags as min_pk: group-check the signature, no augmentation,
+    // validate the public key.
+    sig.verify(true, &msg, DST, &[], &pk, true) == BLST_ERROR::BLST_SUCCESS
+}
+
+/// Aggregate multiple BLS12-381 min_sig signatures into one.
+///
+/// * `sigs_bytes` – list of 48-byte compressed G1 signatures
+///
+/// Returns the 48-byte aggregate signature, or an empty `Vec<u8>` on error
+/// (e.g. empty list, malformed signature).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_sig_aggregate(sigs_bytes: Vec<Vec<u8>>) -> Vec<u8> {
+    if sigs_bytes.is_empty() || sigs_bytes.iter().any(|b| b.len() != SIG_LEN) {
+        return vec![];
+    }
+
+    let sigs: Result<Vec<Signature>, _> = sigs_bytes
+        .iter()
+        .map(|b| Signature::from_bytes(b))
+        .collect();
+
+    let sigs = match sigs {
+        Ok(s) => s,
+        Err(_) => return vec![],
+    };
+
+    let sig_refs: Vec<&Signature> = sigs.iter().collect();
+
+    match AggregateSignature::aggregate(&sig_refs, true) {
+        Ok(agg) => agg.to_signature().to_bytes().to_vec(),
+        Err(_) => vec![],
+    }
+}
+
+/// Verify an aggregate BLS12-381 min_sig signature where all signers signed
+/// the same message.
+///
+/// * `pks_bytes`     – list of 96-byte compressed G2 public keys
+/// * `msg`           – the shared message all signers signed
+/// * `agg_sig_bytes` – 48-byte compressed aggregate G1 signature
+///
+/// Returns `true` when the aggregate signature is valid, `false` otherwise
+/// (including the identity signature).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_sig_verify_aggregate(
+    pks_bytes: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    agg_sig_bytes: Vec<u8>,
+) -> bool {
+    if pks_bytes.is_empty()
+        || pks_bytes.iter().any(|b| b.len() != PK_LEN)
+        || agg_sig_bytes.len() != SIG_LEN
+    {
+        return false;
+    }
+
+    let pks: Option<Vec<PublicKey>> = pks_bytes
+        .iter()
+        .map(|b| PublicKey::from_bytes(b).ok())
+        .collect();
+    let pks = match pks {
+        Some(p) => p,
+        None => return false,
+    };
+    let pk_refs: Vec<&PublicKey> = pks.iter().collect();
+
+    let sig = match Signature::from_bytes(&agg_sig_bytes) {
+        Ok(s) if !is_identity_sig(&s) => s,
+        _ => return false,
+    };
+
+    sig.fast_aggregate_verify(true, &msg, DST, &pk_refs) == BLST_ERROR::BLST_SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::min_sig::{keygen, sign_msg};
+
+    // ---- verify ----
+
+    #[test]
+    fn verify_valid_signature() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-verification!!");
+        let msg = b"hello walrus";
+        let sig = sign_msg(&sk, msg);
+
+        assert!(bls12381_min_sig_verify(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            msg.to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verify_wrong_message() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-wrong-msg!!!!!");
+        let sig = sign_msg(&sk, b"correct message");
+
+        assert!(!bls12381_min_sig_verify(
+            sig.to_bytes().to_vec(),
+            pk.to_bytes().to_vec(),
+            b"wrong message".to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verify_wrong_key() {
+        let (sk1, _) = keygen(b"test-seed-for-bls-wrong-key-1!!!");
+        let (_, pk2) = keygen(b"test-seed-for-bls-wrong-key-2!!!");
+        let msg = b"hello walrus";
+        let sig = sign_msg(&sk1, msg);
+
+        assert!(!bls12381_min_sig_verify(
+            sig.to_bytes().to_vec(),
+            pk2.to_bytes().to_vec(),
+            msg.to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verify_empty_inputs() {
+        assert!(!bls12381_min_sig_verify(vec![], vec![], vec![]));
+    }
+
+    #[test]
+    fn verify_wrong_size_signature() {
+        assert!(!bls12381_min_sig_verify(
+            vec![0u8; 96], // Should be 48
+            vec![0u8; 96],
+            vec![1, 2, 3],
+        ));
+    }
+
+    #[test]
+    fn verify_wrong_size_pubkey() {
+        assert!(!bls12381_min_sig_verify(
+            vec![0u8; 48],
+            vec![0u8; 48], // Should be 96
+            vec![1, 2, 3],
+        ));
+    }
+
+    #[test]
+    fn verify_rejects_min_pk_signature() {
+        // A min_pk signature over the same message uses the other groups and
+        // the other DST, so it can never pass here.
+        let (sk, _) = keygen(b"test-seed-for-bls-verification!!");
+        let min_pk_sk = blst::min_pk::SecretKey::from_bytes(&sk.to_bytes()).unwrap();
+        let min_pk_sig = min_pk_sk.sign(b"hello walrus", crate::api::bls::DST, &[]);
+
+        assert!(!bls12381_min_sig_verify(
+            min_pk_sig.to_bytes().to_vec(),
+            min_pk_sk.sk_to_pk().to_bytes().to_vec(),
+            b"hello walrus".to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verify_rejects_identity_signature() {
+        let (_, pk) = keygen(b"test-seed-for-bls-verification!!");
+        let mut identity = vec![0u8; SIG_LEN];
+        identity[0] = 0xc0;
+        assert!(is_identity_sig(&Signature::from_bytes(&identity).unwrap()));
+
+        let pk = pk.to_bytes().to_vec();
+        assert!(!bls12381_min_sig_verify(
+            identity.clone(),
+            pk.clone(),
+            b"anything".to_vec()
+        ));
+        assert!(!bls12381_min_sig_verify_aggregate(
+            vec![pk],
+            b"anything".to_vec(),
+            identity
+        ));
+    }
+
+    // ---- aggregate ----
+
+    #[test]
+    fn aggregate_single() {
+        let (sk, _) = keygen(b"test-seed-for-bls-aggregate-1!!!");
+        let sig = sign_msg(&sk, b"aggregate me");
+
+        let agg = bls12381_min_sig_aggregate(vec![sig.to_bytes().to_vec()]);
+        assert_eq!(agg.len(), 48);
+        assert_eq!(agg, sig.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn aggregate_multiple() {
+        let (sk1, _) = keygen(b"test-seed-for-bls-agg-multi-1!!!");
+        let (sk2, _) = keygen(b"test-seed-for-bls-agg-multi-2!!!");
+        let (sk3, _) = keygen(b"test-seed-for-bls-agg-multi-3!!!");
+        let msg = b"shared message";
+
+        let sig1 = sign_msg(&sk1, msg);
+        let sig2 = sign_msg(&sk2, msg);
+        let sig3 = sign_msg(&sk3, msg);
+
+        let agg = bls12381_min_sig_aggregate(vec![
+            sig1.to_bytes().to_vec(),
+            sig2.to_bytes().to_vec(),
+            sig3.to_bytes().to_vec(),
+        ]);
+        assert_eq!(agg.len(), 48);
+        assert_ne!(agg, sig1.to_bytes().to_vec());
+    }
+
+    #[test]
+    fn aggregate_empty() {
+        assert!(bls12381_min_sig_aggregate(vec![]).is_empty());
+    }
+
+    #[test]
+    fn aggregate_malformed() {
+        assert!(bls12381_min_sig_aggregate(vec![vec![0u8; 10]]).is_empty());
+        // A min_pk-sized signature is rejected by length alone.
+        assert!(bls12381_min_sig_aggregate(vec![vec![0u8; 96]]).is_empty());
+    }
+
+    // ---- verify aggregate ----
+
+    #[test]
+    fn verify_aggregate_valid() {
+        let (sk1, pk1) = keygen(b"test-agg-verify-valid-key-1!!!!!");
+        let (sk2, pk2) = keygen(b"test-agg-verify-valid-key-2!!!!!");
+        let (sk3, pk3) = keygen(b"test-agg-verify-valid-key-3!!!!!");
+        let msg = b"certify this blob";
+
+        let agg = bls12381_min_sig_aggregate(vec![
+            sign_msg(&sk1, msg).to_bytes().to_vec(),
+            sign_msg(&sk2, msg).to_bytes().to_vec(),
+            sign_msg(&sk3, msg).to_bytes().to_vec(),
+        ]);
+
+        assert!(bls12381_min_sig_verify_aggregate(
+            vec![
+                pk1.to_bytes().to_vec(),
+                pk2.to_bytes().to_vec(),
+                pk3.to_bytes().to_vec(),
+            ],
+            msg.to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_wrong_message() {
+        let (sk1, pk1) = keygen(b"test-agg-verify-wrong-msg-key1!!");
+        let (sk2, pk2) = keygen(b"test-agg-verify-wrong-msg-key2!!");
+        let msg = b"correct message";
+
+        let agg = bls12381_min_sig_aggregate(vec![
+            sign_msg(&sk1, msg).to_bytes().to_vec(),
+            sign_msg(&sk2, msg).to_bytes().to_vec(),
+        ]);
+
+        assert!(!bls12381_min_sig_verify_aggregate(
+            vec![pk1.to_bytes().to_vec(), pk2.to_bytes().to_vec()],
+            b"wrong message".to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_missing_signer() {
+        let (sk1, pk1) = keygen(b"test-agg-verify-missing-key-1!!!");
+        let (sk2, pk2) = keygen(b"test-agg-verify-missing-key-2!!!");
+        let (_, pk3) = keygen(b"test-agg-verify-missing-key-3!!!");
+        let msg = b"only two signed";
+
+        let agg = bls12381_min_sig_aggregate(vec![
+            sign_msg(&sk1, msg).to_bytes().to_vec(),
+            sign_msg(&sk2, msg).to_bytes().to_vec(),
+        ]);
+
+        // Verify with 3 keys but only 2 signed → should fail.
+        assert!(!bls12381_min_sig_verify_aggregate(
+            vec![
+                pk1.to_bytes().to_vec(),
+                pk2.to_bytes().to_vec(),
+                pk3.to_bytes().to_vec(),
+            ],
+            msg.to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_aggregate_empty_keys() {
+        assert!(!bls12381_min_sig_verify_aggregate(
+            vec![],
+            b"msg".to_vec(),
+            vec![0u8; 48],
+        ));
+    }
+
+    // ---- end-to-end ----
+
+    #[test]
+    fn key_and_sig_sizes() {
+        let (sk, pk) = keygen(b"test-sizes-check-key-seed!!!!!!!");
+        let sig = sign_msg(&sk, b"size check");
+        assert_eq!(pk.to_bytes().len(), 96, "Public key should be 96 bytes");
+        assert_eq!(sig.to_bytes().len(), 48, "Signature should be 48 bytes");
+    }
+}
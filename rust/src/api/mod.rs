@@ -1,2 +1,17 @@
+pub mod aggregator;
+pub mod batch;
 pub mod bls;
+pub mod bundle;
+pub mod cache;
+pub mod commitment;
+pub mod committee;
+pub mod diagnostics;
+pub mod encoding;
+pub mod keys;
+pub mod min_sig;
+pub mod prehash;
+pub mod rlp;
 pub mod simple;
+pub mod sui;
+pub mod threshold;
+pub mod verifier;
@@ -0,0 +1,272 @@
+// Hash-then-sign variants: the BLS message is a digest of the content
+// rather than the content itself.
+
+use crate::api::bls::{sign_with_dst, verify_with_dst, DST};
+use crate::api::keys::SecretBytes;
+use crate::hash::{blake2b256, keccak256, sha256, sha512};
+
+/// Length of a digest accepted by the `_prehashed` functions.
+const DIGEST_LEN: usize = 32;
+
+// Hash selectors accepted by `bls12381_min_pk_verify_with_hash`.
+const HASH_SHA256: u8 = 0;
+const HASH_SHA512: u8 = 1;
+const HASH_BLAKE2B256: u8 = 2;
+const HASH_KECCAK256: u8 = 3;
+
+/// Digest of `content` under the hash selected by `hash_id`.
+fn digest(content: &[u8], hash_id: u8) -> Option<Vec<u8>> {
+    match hash_id {
+        HASH_SHA256 => Some(sha256(content).to_vec()),
+        HASH_SHA512 => Some(sha512(content).to_vec()),
+        HASH_BLAKE2B256 => Some(blake2b256(content).to_vec()),
+        HASH_KECCAK256 => Some(keccak256(content).to_vec()),
+        _ => None,
+    }
+}
+
+/// Sign the BLAKE2b-256 digest of `content`.
+///
+/// * `sk_bytes` – 32-byte secret key
+/// * `content`  – arbitrary-length content to hash and sign
+///
+/// Returns the 96-byte signature over the 32-byte digest, or an empty
+/// `Vec<u8>` for a malformed secret key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sign_blake2b(sk_bytes: Vec<u8>, content: Vec<u8>) -> Vec<u8> {
+    sign_with_dst(
+        SecretBytes::new(sk_bytes).as_slice(),
+        &blake2b256(&content),
+        DST,
+    )
+}
+
+/// Verify a signature over the BLAKE2b-256 digest of `content`.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `content`   – arbitrary-length content whose digest was signed
+///
+/// Returns `true` when the signature over the digest is valid, `false`
+/// otherwise (including malformed inputs).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_blake2b(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    content: Vec<u8>,
+) -> bool {
+    verify_with_dst(&sig_bytes, &pk_bytes, &blake2b256(&content), DST)
+}
+
+/// Sign a 32-byte digest the application already computed.
+///
+/// * `sk_bytes` – 32-byte secret key
+/// * `digest32` – 32-byte digest, signed as the message under the standard
+///   DST
+///
+/// Interoperates with signers that hash content themselves and sign the
+/// digest, with hash-to-curve applied to the digest bytes.
+///
+/// Returns the 96-byte signature, or an empty `Vec<u8>` when `digest32` is
+/// not 32 bytes or the secret key is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_sign_prehashed(sk_bytes: Vec<u8>, digest32: Vec<u8>) -> Vec<u8> {
+    let sk_bytes = SecretBytes::new(sk_bytes);
+    if digest32.len() != DIGEST_LEN {
+        return vec![];
+    }
+    sign_with_dst(sk_bytes.as_slice(), &digest32, DST)
+}
+
+/// Verify a signature over a 32-byte digest from
+/// [`bls12381_min_pk_sign_prehashed`].
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `digest32`  – 32-byte digest that was signed
+///
+/// Returns `true` when the signature is valid, `false` otherwise
+/// (including a digest that isn't 32 bytes and malformed inputs).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_prehashed(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    digest32: Vec<u8>,
+) -> bool {
+    digest32.len() == DIGEST_LEN && verify_with_dst(&sig_bytes, &pk_bytes, &digest32, DST)
+}
+
+/// Verify a signature over the digest of `content`, with the hash chosen at
+/// runtime.
+///
+/// * `sig_bytes` – 96-byte compressed G2 signature
+/// * `pk_bytes`  – 48-byte compressed G1 public key
+/// * `content`   – arbitrary-length content whose digest was signed
+/// * `hash_id`   – hash applied to `content` before verifying:
+///   - `0` – SHA-256 (32-byte digest)
+///   - `1` – SHA-512 (64-byte digest)
+///   - `2` – BLAKE2b-256 (32-byte digest), as Sui and Walrus use
+///   - `3` – Keccak-256 (32-byte digest), as Ethereum uses
+///
+/// The signature must be over the full digest bytes under the standard DST.
+///
+/// Returns `false` for an unknown `hash_id`, malformed inputs, or an
+/// invalid signature.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_with_hash(
+    sig_bytes: Vec<u8>,
+    pk_bytes: Vec<u8>,
+    content: Vec<u8>,
+    hash_id: u8,
+) -> bool {
+    match digest(&content, hash_id) {
+        Some(d) => verify_with_dst(&sig_bytes, &pk_bytes, &d, DST),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bls::bls12381_min_pk_verify;
+    use crate::test_utils::{keygen, sign_msg};
+
+    // ---- blake2b ----
+
+    #[test]
+    fn blake2b_matches_manual_digest() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-blake2b-sign!!");
+        let content = vec![0xabu8; 10_000];
+
+        let sig = bls12381_min_pk_sign_blake2b(sk.to_bytes().to_vec(), content.clone());
+        let manual = sign_msg(&sk, &blake2b256(&content)).to_bytes().to_vec();
+        assert_eq!(sig, manual);
+
+        assert!(bls12381_min_pk_verify_blake2b(
+            sig.clone(),
+            pk.to_bytes().to_vec(),
+            content,
+        ));
+        assert!(bls12381_min_pk_verify(
+            sig,
+            pk.to_bytes().to_vec(),
+            blake2b256(&vec![0xabu8; 10_000]).to_vec(),
+        ));
+    }
+
+    #[test]
+    fn blake2b_wrong_content() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-blake2b-wrong!");
+        let sig = bls12381_min_pk_sign_blake2b(sk.to_bytes().to_vec(), b"content".to_vec());
+        assert!(!bls12381_min_pk_verify_blake2b(
+            sig,
+            pk.to_bytes().to_vec(),
+            b"contents".to_vec(),
+        ));
+    }
+
+    #[test]
+    fn blake2b_sign_malformed_key() {
+        assert!(bls12381_min_pk_sign_blake2b(vec![0u8; 16], b"content".to_vec()).is_empty());
+    }
+
+    // ---- prehashed ----
+
+    #[test]
+    fn prehashed_round_trip() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-prehashed-sig!");
+        let digest = sha256(b"application content").to_vec();
+
+        let sig = bls12381_min_pk_sign_prehashed(sk.to_bytes().to_vec(), digest.clone());
+        assert_eq!(sig, sign_msg(&sk, &digest).to_bytes().to_vec());
+        assert!(bls12381_min_pk_verify_prehashed(
+            sig.clone(),
+            pk.to_bytes().to_vec(),
+            digest
+        ));
+        assert!(!bls12381_min_pk_verify_prehashed(
+            sig,
+            pk.to_bytes().to_vec(),
+            sha256(b"other content").to_vec()
+        ));
+    }
+
+    #[test]
+    fn prehashed_wrong_digest_length() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-prehashed-sig!");
+        let sk = sk.to_bytes().to_vec();
+        for len in [0, 31, 33, 64] {
+            let digest = vec![0x5d; len];
+            assert!(bls12381_min_pk_sign_prehashed(sk.clone(), digest.clone()).is_empty());
+            // Even a valid signature over these bytes is refused.
+            let sig = sign_with_dst(&sk, &digest, DST);
+            assert!(!bls12381_min_pk_verify_prehashed(
+                sig,
+                pk.to_bytes().to_vec(),
+                digest
+            ));
+        }
+    }
+
+    // ---- pluggable hash ----
+
+    #[test]
+    fn with_hash_sha256_and_keccak() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-pluggable-hash");
+        let content = b"hash me first";
+
+        for (hash_id, d) in [
+            (HASH_SHA256, sha256(content).to_vec()),
+            (HASH_KECCAK256, keccak256(content).to_vec()),
+        ] {
+            let sig = sign_msg(&sk, &d).to_bytes().to_vec();
+            assert!(bls12381_min_pk_verify_with_hash(
+                sig.clone(),
+                pk.to_bytes().to_vec(),
+                content.to_vec(),
+                hash_id,
+            ));
+            // The same signature under another hash fails.
+            assert!(!bls12381_min_pk_verify_with_hash(
+                sig,
+                pk.to_bytes().to_vec(),
+                content.to_vec(),
+                HASH_SHA512,
+            ));
+        }
+    }
+
+    #[test]
+    fn with_hash_sha512_and_blake2b() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-pluggable-512!");
+        let content = b"hash me first";
+
+        let sig = sign_msg(&sk, &sha512(content)).to_bytes().to_vec();
+        assert!(bls12381_min_pk_verify_with_hash(
+            sig,
+            pk.to_bytes().to_vec(),
+            content.to_vec(),
+            HASH_SHA512,
+        ));
+
+        let sig = bls12381_min_pk_sign_blake2b(sk.to_bytes().to_vec(), content.to_vec());
+        assert!(bls12381_min_pk_verify_with_hash(
+            sig,
+            pk.to_bytes().to_vec(),
+            content.to_vec(),
+            HASH_BLAKE2B256,
+        ));
+    }
+
+    #[test]
+    fn with_hash_unknown_id() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-pluggable-bad!");
+        let sig = sign_msg(&sk, &sha256(b"c")).to_bytes().to_vec();
+        assert!(!bls12381_min_pk_verify_with_hash(
+            sig,
+            pk.to_bytes().to_vec(),
+            b"c".to_vec(),
+            4,
+        ));
+    }
+}
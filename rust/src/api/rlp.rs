@@ -0,0 +1,255 @@
+// Signatures over RLP-encoded messages, for Ethereum-ecosystem interop.
+//
+// RLP has exactly one valid encoding per value, so a signature over the
+// encoded bytes is checked directly without re-encoding. The verifier only
+// insists that the bytes are one canonical RLP item.
+
+use crate::api::bls::bls12381_min_pk_verify;
+
+/// Offset of single-item string prefixes (`0x80..=0xb7`).
+const STRING_OFFSET: u8 = 0x80;
+/// Offset of list prefixes (`0xc0..=0xf7`).
+const LIST_OFFSET: u8 = 0xc0;
+/// Longest payload that fits the one-byte prefix form.
+const SHORT_MAX: usize = 55;
+
+/// Append an RLP length prefix for a payload of `len` bytes.
+fn push_prefix(out: &mut Vec<u8>, offset: u8, len: usize) {
+    if len <= SHORT_MAX {
+        out.push(offset + len as u8);
+    } else {
+        let be = len.to_be_bytes();
+        let skip = be.iter().take_while(|&&b| b == 0).count();
+        out.push(offset + SHORT_MAX as u8 + (be.len() - skip) as u8);
+        out.extend_from_slice(&be[skip..]);
+    }
+}
+
+/// RLP encoding of a byte string.
+fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    if bytes.len() == 1 && bytes[0] < STRING_OFFSET {
+        out.push(bytes[0]);
+    } else {
+        push_prefix(out, STRING_OFFSET, bytes.len());
+        out.extend_from_slice(bytes);
+    }
+}
+
+/// Header of the RLP item at the start of `buf`: the prefix length, the
+/// payload length and whether the item is a list, or `None` if the prefix
+/// is truncated or not in canonical form.
+fn header(buf: &[u8]) -> Option<(usize, usize, bool)> {
+    let prefix = *buf.first()?;
+    match prefix {
+        0x00..=0x7f => Some((0, 1, false)),
+        0x80..=0xb7 => Some((1, (prefix - STRING_OFFSET) as usize, false)),
+        0xc0..=0xf7 => Some((1, (prefix - LIST_OFFSET) as usize, true)),
+        _ => {
+            let is_list = prefix >= LIST_OFFSET;
+            let offset = if is_list { LIST_OFFSET } else { STRING_OFFSET };
+            let len_len = (prefix - offset) as usize - SHORT_MAX;
+            let len_bytes = buf.get(1..1 + len_len)?;
+            // No leading zeros, and the long form only for long payloads.
+            if len_bytes[0] == 0 || len_len > std::mem::size_of::<usize>() {
+                return None;
+            }
+            let len = len_bytes
+                .iter()
+                .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+            if len <= SHORT_MAX {
+                return None;
+            }
+            Some((1 + len_len, len, is_list))
+        }
+    }
+}
+
+/// Length of the canonical RLP item at the start of `buf`, or `None` if it
+/// is truncated or not in canonical form.
+///
+/// Nested lists are walked with an explicit stack instead of recursion, as
+/// each level costs the sender only a byte or two.
+fn item_len(buf: &[u8]) -> Option<usize> {
+    // End offsets of the lists enclosing `pos`, innermost last.
+    let mut open: Vec<usize> = Vec::new();
+    let mut pos = 0;
+    loop {
+        let item = &buf[pos..open.last().copied().unwrap_or(buf.len())];
+        let (header, payload, is_list) = header(item)?;
+        let total = header.checked_add(payload)?;
+        let body = item.get(header..total)?;
+        if is_list {
+            open.push(pos + total);
+            pos += header;
+        } else if header == 1 && payload == 1 && body[0] < STRING_OFFSET {
+            // A single low byte must be encoded as itself.
+            return None;
+        } else {
+            pos += total;
+        }
+        while open.last() == Some(&pos) {
+            open.pop();
+        }
+        if open.is_empty() {
+            return Some(pos);
+        }
+    }
+}
+
+/// Whether `bytes` is exactly one canonical RLP item.
+fn is_canonical_item(bytes: &[u8]) -> bool {
+    item_len(bytes) == Some(bytes.len())
+}
+
+/// RLP-encode a list of byte-string fields, ready for signing.
+///
+/// * `fields` – the list's fields, each encoded as an RLP byte string
+///
+/// Integers are expected already in RLP form: big-endian with no leading
+/// zero bytes, and zero as the empty string.
+///
+/// Returns the encoded list.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_rlp_encode_list(fields: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for field in &fields {
+        encode_bytes(&mut payload, field);
+    }
+    let mut out = Vec::with_capacity(9 + payload.len());
+    push_prefix(&mut out, LIST_OFFSET, payload.len());
+    out.extend_from_slice(&payload);
+    out
+}
+
+/// Verify a BLS12-381 min_pk signature over an RLP-encoded message.
+///
+/// * `sig`       – 96-byte compressed G2 signature
+/// * `pk`        – 48-byte compressed G1 public key
+/// * `rlp_bytes` – the signed message, one RLP item
+///
+/// The signature is checked over `rlp_bytes` as given.
+///
+/// Returns `false` when `rlp_bytes` is not exactly one canonical RLP item,
+/// or the signature does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_verify_rlp(sig: Vec<u8>, pk: Vec<u8>, rlp_bytes: Vec<u8>) -> bool {
+    is_canonical_item(&rlp_bytes) && bls12381_min_pk_verify(sig, pk, rlp_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{keygen, sign_msg};
+
+    /// `["cat", "dog"]`, the example from the Ethereum RLP spec.
+    const CAT_DOG: [u8; 9] = [0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g'];
+
+    // ---- encoding ----
+
+    #[test]
+    fn rlp_encode_spec_vectors() {
+        assert_eq!(
+            bls12381_rlp_encode_list(vec![b"cat".to_vec(), b"dog".to_vec()]),
+            CAT_DOG
+        );
+        assert_eq!(bls12381_rlp_encode_list(vec![]), vec![0xc0]);
+        // Empty string, a single low byte, and a single high byte.
+        assert_eq!(
+            bls12381_rlp_encode_list(vec![vec![], vec![0x0f], vec![0x80]]),
+            vec![0xc4, 0x80, 0x0f, 0x81, 0x80]
+        );
+
+        let long = bls12381_rlp_encode_list(vec![vec![b'a'; 56]]);
+        assert_eq!(&long[..3], &[0xf8, 58, 0xb8]);
+        assert_eq!(long[3], 56);
+        assert!(is_canonical_item(&long));
+    }
+
+    #[test]
+    fn rlp_rejects_non_canonical() {
+        assert!(is_canonical_item(&CAT_DOG));
+        // Trailing byte after the item.
+        assert!(!is_canonical_item(&[0x80, 0x00]));
+        // Truncated list payload.
+        assert!(!is_canonical_item(&CAT_DOG[..8]));
+        // A low single byte wrapped in a string prefix.
+        assert!(!is_canonical_item(&[0x81, 0x05]));
+        // Long form used for a short payload.
+        assert!(!is_canonical_item(&[0xb8, 0x01, 0x80]));
+        // Long-form length with a leading zero.
+        assert!(!is_canonical_item(&[0xb9, 0x00, 0x38]));
+    }
+
+    #[test]
+    fn rlp_deeply_nested_lists() {
+        // 100k lists nested around an empty list, built inside out.
+        let mut prefixes = Vec::new();
+        let mut len = 1;
+        for _ in 0..100_000 {
+            let mut prefix = Vec::new();
+            push_prefix(&mut prefix, LIST_OFFSET, len);
+            len += prefix.len();
+            prefixes.push(prefix);
+        }
+        let nested: Vec<u8> = prefixes
+            .into_iter()
+            .rev()
+            .flatten()
+            .chain([LIST_OFFSET])
+            .collect();
+        assert_eq!(nested.len(), len);
+
+        assert!(is_canonical_item(&nested));
+        // Missing the innermost list.
+        assert!(!is_canonical_item(&nested[..len - 1]));
+        assert!(!bls12381_min_pk_verify_rlp(
+            vec![0u8; 96],
+            vec![0u8; 48],
+            nested
+        ));
+    }
+
+    #[test]
+    fn rlp_nested_list_items() {
+        // `[[], [[]], [[], [[]]]]`, the set-theoretic three from the spec.
+        let three = [0xc7, 0xc0, 0xc1, 0xc0, 0xc3, 0xc0, 0xc1, 0xc0];
+        assert!(is_canonical_item(&three));
+        // An inner list claiming more bytes than its parent holds.
+        assert!(!is_canonical_item(&[0xc2, 0xc2, 0xc0, 0xc0]));
+        // A list payload shorter than its prefix claims.
+        assert!(!is_canonical_item(&[0xc2, 0xc0]));
+    }
+
+    // ---- verify ----
+
+    #[test]
+    fn verify_rlp_fixed_payload() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-rlp-verify!!!!");
+        let sig = sign_msg(&sk, &CAT_DOG).to_bytes().to_vec();
+        let pk = pk.to_bytes().to_vec();
+
+        assert!(bls12381_min_pk_verify_rlp(
+            sig.clone(),
+            pk.clone(),
+            CAT_DOG.to_vec()
+        ));
+
+        let mut other = CAT_DOG;
+        other[2] = b'b';
+        assert!(!bls12381_min_pk_verify_rlp(sig, pk, other.to_vec()));
+    }
+
+    #[test]
+    fn verify_rlp_rejects_malformed_encoding() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-rlp-verify!!!!");
+        let mut msg = CAT_DOG.to_vec();
+        msg.push(0);
+        // The signature is valid over the bytes, but they are not one item.
+        let sig = sign_msg(&sk, &msg).to_bytes().to_vec();
+        assert!(!bls12381_min_pk_verify_rlp(
+            sig,
+            pk.to_bytes().to_vec(),
+            msg
+        ));
+    }
+}
@@ -0,0 +1,417 @@
+// Sui-specific message framing on top of the BLS12-381 min_pk primitives.
+//
+// Sui never signs raw payloads: every signed message is prefixed with a
+// 3-byte intent `[scope, version, app_id]` so that a signature produced for
+// one purpose can't be replayed as another.
+
+use blst::min_pk::PublicKey;
+
+use crate::api::bls::{bls12381_min_pk_verify_aggregate, PK_LEN};
+use crate::api::committee::CommitteeHandle;
+
+/// Sui `IntentScope::PersonalMessage`, used for application-level payloads.
+pub(crate) const INTENT_SCOPE_PERSONAL_MESSAGE: u8 = 3;
+/// Sui `IntentVersion::V0`.
+pub(crate) const INTENT_VERSION_V0: u8 = 0;
+/// Sui `AppId::Sui`.
+pub(crate) const APP_ID_SUI: u8 = 0;
+
+/// Length of a Sui `ObjectID`.
+const OBJECT_ID_LEN: usize = 32;
+/// Length of a Sui `ObjectDigest`.
+const OBJECT_DIGEST_LEN: usize = 32;
+
+/// Minimal BCS reader for the fixed layouts decoded in this module.
+struct BcsReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> BcsReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        BcsReader { buf }
+    }
+
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.buf.len() < n {
+            return None;
+        }
+        let (head, tail) = self.buf.split_at(n);
+        self.buf = tail;
+        Some(head)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        let b: [u8; 8] = self.bytes(8)?.try_into().ok()?;
+        Some(u64::from_le_bytes(b))
+    }
+
+    /// ULEB128 length prefix, limited to 32 bits as BCS requires.
+    fn uleb128(&mut self) -> Option<usize> {
+        let mut value: u64 = 0;
+        for shift in (0..32).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return u32::try_from(value).ok().map(|v| v as usize);
+            }
+        }
+        None
+    }
+
+    /// A BCS `vector<u8>`: ULEB128 length followed by the bytes.
+    fn byte_vec(&mut self) -> Option<&'a [u8]> {
+        let len = self.uleb128()?;
+        self.bytes(len)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+}
+
+/// BCS-encode a `vector<u8>`: ULEB128 length followed by the bytes.
+pub(crate) fn bcs_byte_vec(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(5 + bytes.len());
+    let mut len = bytes.len();
+    while len >= 0x80 {
+        out.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    out.push(len as u8);
+    out.extend_from_slice(bytes);
+    out
+}
+
+/// Prefix `payload` with the 3-byte intent `[scope, version, app_id]`.
+pub(crate) fn intent_message(scope: u8, version: u8, app_id: u8, payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(3 + payload.len());
+    out.extend_from_slice(&[scope, version, app_id]);
+    out.extend_from_slice(payload);
+    out
+}
+
+/// Build the intent message Sui validators sign for a payload.
+///
+/// * `intent_scope` – Sui `IntentScope`, e.g. `3` for a personal message
+/// * `version`      – Sui `IntentVersion`, `0` today
+/// * `app_id`       – Sui `AppId`, e.g. `0` for Sui itself
+/// * `payload`      – the BCS-encoded value being signed
+///
+/// This is the BCS encoding of Sui's `IntentMessage`: the 3 intent bytes
+/// followed by `payload` as is. Sui's BLS12-381 authority signatures are
+/// over these bytes directly, so nothing is hashed here; the result goes
+/// straight to the verify functions. `payload` must already be BCS, e.g.
+/// with a length prefix for a byte vector.
+///
+/// Returns the `3 + payload.len()`-byte intent message.
+#[flutter_rust_bridge::frb(sync)]
+pub fn sui_intent_message(intent_scope: u8, version: u8, app_id: u8, payload: Vec<u8>) -> Vec<u8> {
+    intent_message(intent_scope, version, app_id, &payload)
+}
+
+/// Verify an aggregate signature over a Sui object reference.
+///
+/// * `pks`       – list of 48-byte compressed G1 public keys of the signers
+/// * `object_id` – 32-byte object ID
+/// * `version`   – object version (sequence number)
+/// * `digest`    – 32-byte object digest
+/// * `agg_sig`   – 96-byte compressed aggregate G2 signature
+///
+/// The reference is BCS-encoded as Sui's `ObjectRef` tuple
+/// `(ObjectID, SequenceNumber, ObjectDigest)`, where the digest is a byte
+/// vector with its own length prefix. Sui has no intent for signing an
+/// object reference, so this crate's own choice is to sign those bytes the
+/// way Sui frames a personal message, without the Blake2b pre-hash:
+///
+/// ```text
+/// [3, 0, 0] || uleb128(73) || object_id || u64_le(version) || uleb128(32) || digest
+/// ```
+///
+/// Returns `false` when `object_id` or `digest` is not 32 bytes, or the
+/// aggregate signature does not verify.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_verify_object_ref(
+    pks: Vec<Vec<u8>>,
+    object_id: Vec<u8>,
+    version: u64,
+    digest: Vec<u8>,
+    agg_sig: Vec<u8>,
+) -> bool {
+    match object_ref_message(&object_id, version, &digest) {
+        Some(msg) => bls12381_min_pk_verify_aggregate(pks, msg, agg_sig),
+        None => false,
+    }
+}
+
+/// Build the intent-wrapped message for an object reference, or `None` if a
+/// field has the wrong length.
+fn object_ref_message(object_id: &[u8], version: u64, digest: &[u8]) -> Option<Vec<u8>> {
+    if object_id.len() != OBJECT_ID_LEN || digest.len() != OBJECT_DIGEST_LEN {
+        return None;
+    }
+    let mut object_ref = Vec::with_capacity(OBJECT_ID_LEN + 8 + 1 + OBJECT_DIGEST_LEN);
+    object_ref.extend_from_slice(object_id);
+    object_ref.extend_from_slice(&version.to_le_bytes());
+    object_ref.extend_from_slice(&bcs_byte_vec(digest));
+    Some(intent_message(
+        INTENT_SCOPE_PERSONAL_MESSAGE,
+        INTENT_VERSION_V0,
+        APP_ID_SUI,
+        &bcs_byte_vec(&object_ref),
+    ))
+}
+
+/// Decode an on-chain committee from its BCS encoding into a weighted
+/// [`CommitteeHandle`].
+///
+/// * `bcs_bytes` – BCS-encoded committee
+///
+/// The expected layout is the Move struct
+///
+/// ```text
+/// struct Committee {
+///     epoch: u64,
+///     members: vector<Member>,
+/// }
+/// struct Member {
+///     public_key: vector<u8>,  // 48-byte compressed G1 key
+///     voting_power: u64,
+/// }
+/// ```
+///
+/// i.e. `u64_le(epoch) || uleb128(n) || n × (uleb128(48) || pk || u64_le(power))`.
+/// The epoch is read but not kept on the handle.
+///
+/// Returns `None` on truncated or trailing bytes, a key that is not 48
+/// bytes or does not parse, an empty committee, or a total voting power
+/// that overflows `u64`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_committee_from_sui_bcs(bcs_bytes: Vec<u8>) -> Option<CommitteeHandle> {
+    let mut r = BcsReader::new(&bcs_bytes);
+    let _epoch = r.u64()?;
+    let n = r.uleb128()?;
+
+    let mut pks = Vec::new();
+    let mut weights = Vec::new();
+    for _ in 0..n {
+        let pk = r.byte_vec()?;
+        if pk.len() != PK_LEN {
+            return None;
+        }
+        pks.push(PublicKey::from_bytes(pk).ok()?);
+        weights.push(r.u64()?);
+    }
+    if !r.is_empty() {
+        return None;
+    }
+    CommitteeHandle::from_parts(pks, weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{agg_sign, committee, pks_of};
+
+    /// BCS-encode a committee in the layout `bls12381_committee_from_sui_bcs`
+    /// expects.
+    fn committee_bcs(epoch: u64, members: &[(Vec<u8>, u64)]) -> Vec<u8> {
+        let mut out = epoch.to_le_bytes().to_vec();
+        out.push(members.len() as u8);
+        for (pk, power) in members {
+            out.push(pk.len() as u8);
+            out.extend_from_slice(pk);
+            out.extend_from_slice(&power.to_le_bytes());
+        }
+        out
+    }
+
+    const OBJECT_ID: [u8; 32] = [0x5a; 32];
+    const VERSION: u64 = 0x0102_0304;
+    const DIGEST: [u8; 32] = [0xd1; 32];
+
+    // ---- intent message ----
+
+    #[test]
+    fn intent_message_layout() {
+        // A personal message: BCS of the bytes "hello" is a ULEB128 length
+        // prefix followed by the bytes.
+        let payload = [&[5u8][..], b"hello"].concat();
+        assert_eq!(
+            hex::encode(sui_intent_message(
+                INTENT_SCOPE_PERSONAL_MESSAGE,
+                INTENT_VERSION_V0,
+                APP_ID_SUI,
+                payload
+            )),
+            "0300000568656c6c6f"
+        );
+        // Transaction data under the same version and app.
+        assert_eq!(sui_intent_message(0, 0, 0, vec![0xaa]), vec![0, 0, 0, 0xaa]);
+        assert_eq!(sui_intent_message(1, 2, 3, vec![]), vec![1, 2, 3]);
+    }
+
+    // ---- object ref ----
+
+    #[test]
+    fn object_ref_message_layout() {
+        let msg = object_ref_message(&OBJECT_ID, VERSION, &DIGEST).unwrap();
+        assert_eq!(msg.len(), 3 + 1 + 32 + 8 + 1 + 32);
+        assert_eq!(&msg[..3], &[3, 0, 0]);
+        // `PersonalMessage.message` length prefix.
+        assert_eq!(msg[3], 73);
+        assert_eq!(&msg[4..36], &OBJECT_ID);
+        assert_eq!(&msg[36..44], &[0x04, 0x03, 0x02, 0x01, 0, 0, 0, 0]);
+        // `ObjectDigest` length prefix.
+        assert_eq!(msg[44], 32);
+        assert_eq!(&msg[45..], &DIGEST);
+    }
+
+    #[test]
+    fn object_ref_matches_sui_bcs() {
+        use sui_sdk_types::{Address, Digest, Intent, IntentAppId, IntentScope, IntentVersion};
+
+        let object_ref = sui_sdk_types::ObjectReference::new(
+            Address::new(OBJECT_ID),
+            VERSION,
+            Digest::new(DIGEST),
+        );
+        let intent = Intent {
+            scope: IntentScope::PersonalMessage,
+            version: IntentVersion::V0,
+            app_id: IntentAppId::Sui,
+        };
+        let expected = [
+            intent.to_bytes().to_vec(),
+            bcs::to_bytes(&bcs::to_bytes(&object_ref).unwrap()).unwrap(),
+        ]
+        .concat();
+        assert_eq!(
+            object_ref_message(&OBJECT_ID, VERSION, &DIGEST).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn bcs_byte_vec_round_trip() {
+        assert_eq!(bcs_byte_vec(&[]), vec![0]);
+        assert_eq!(bcs_byte_vec(&[7; 127])[..1], [0x7f]);
+        assert_eq!(bcs_byte_vec(&[7; 128])[..2], [0x80, 0x01]);
+        assert_eq!(bcs_byte_vec(&[7; 300])[..2], [0xac, 0x02]);
+        for n in [0, 1, 127, 128, 16_384] {
+            let bytes = vec![0xab; n];
+            let encoded = bcs_byte_vec(&bytes);
+            let mut r = BcsReader::new(&encoded);
+            assert_eq!(r.byte_vec(), Some(&bytes[..]));
+            assert!(r.is_empty());
+        }
+    }
+
+    #[test]
+    fn verify_object_ref_valid() {
+        let keys = committee("object-ref", 3);
+        let msg = object_ref_message(&OBJECT_ID, VERSION, &DIGEST).unwrap();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+
+        assert!(bls12381_verify_object_ref(
+            pks_of(&keys),
+            OBJECT_ID.to_vec(),
+            VERSION,
+            DIGEST.to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_object_ref_wrong_version() {
+        let keys = committee("object-ref", 3);
+        let msg = object_ref_message(&OBJECT_ID, VERSION, &DIGEST).unwrap();
+        let agg = agg_sign(&keys, &[0, 1, 2], &msg);
+
+        assert!(!bls12381_verify_object_ref(
+            pks_of(&keys),
+            OBJECT_ID.to_vec(),
+            VERSION + 1,
+            DIGEST.to_vec(),
+            agg,
+        ));
+    }
+
+    #[test]
+    fn verify_object_ref_bad_lengths() {
+        let keys = committee("object-ref", 1);
+        assert!(!bls12381_verify_object_ref(
+            pks_of(&keys),
+            vec![0u8; 31],
+            VERSION,
+            DIGEST.to_vec(),
+            vec![0u8; 96],
+        ));
+        assert!(!bls12381_verify_object_ref(
+            pks_of(&keys),
+            OBJECT_ID.to_vec(),
+            VERSION,
+            vec![0u8; 33],
+            vec![0u8; 96],
+        ));
+    }
+
+    // ---- committee BCS ----
+
+    #[test]
+    fn uleb128_multi_byte() {
+        let mut r = BcsReader::new(&[0x80, 0x01, 0x7f]);
+        assert_eq!(r.uleb128(), Some(128));
+        assert_eq!(r.uleb128(), Some(127));
+        assert!(r.is_empty());
+        assert_eq!(BcsReader::new(&[0x80]).uleb128(), None);
+    }
+
+    #[test]
+    fn committee_from_sui_bcs_weighted_quorum() {
+        let keys = committee("sui-bcs", 4);
+        let pks = pks_of(&keys);
+        let blob = committee_bcs(
+            42,
+            &[
+                (pks[0].clone(), 5_000),
+                (pks[1].clone(), 2_500),
+                (pks[2].clone(), 1_500),
+                (pks[3].clone(), 1_000),
+            ],
+        );
+        // 8 (epoch) + 1 (count) + 4 * (1 + 48 + 8)
+        assert_eq!(blob.len(), 8 + 1 + 4 * 57);
+
+        let handle = bls12381_committee_from_sui_bcs(blob).unwrap();
+        assert_eq!(handle.size(), 4);
+        assert_eq!(handle.total_weight(), 10_000);
+
+        let msg = b"weighted certificate".to_vec();
+
+        // Members 0 and 1 hold 7500/10000 > 2/3.
+        let heavy = agg_sign(&keys, &[0, 1], &msg);
+        assert!(handle.verify_quorum(vec![0b0011], msg.clone(), heavy));
+
+        // Members 1, 2 and 3 are a majority by count but only 5000/10000.
+        let light = agg_sign(&keys, &[1, 2, 3], &msg);
+        assert!(!handle.verify_quorum(vec![0b1110], msg, light));
+    }
+
+    #[test]
+    fn committee_from_sui_bcs_malformed() {
+        let pks = pks_of(&committee("sui-bcs", 1));
+        let good = committee_bcs(1, &[(pks[0].clone(), 1)]);
+        assert!(bls12381_committee_from_sui_bcs(good.clone()).is_some());
+
+        // Truncated.
+        assert!(bls12381_committee_from_sui_bcs(good[..good.len() - 1].to_vec()).is_none());
+        // Trailing bytes.
+        let mut trailing = good.clone();
+        trailing.push(0);
+        assert!(bls12381_committee_from_sui_bcs(trailing).is_none());
+        // Wrong key length.
+        assert!(bls12381_committee_from_sui_bcs(committee_bcs(1, &[(vec![0u8; 47], 1)])).is_none());
+        // Empty committee.
+        assert!(bls12381_committee_from_sui_bcs(committee_bcs(1, &[])).is_none());
+    }
+}
@@ -0,0 +1,252 @@
+// Threshold signatures: recombining partial signatures made with Shamir
+// shares of a group secret key.
+//
+// Share holder `i` owns `f(i)` for a secret polynomial `f` with `f(0)` the
+// group key, and signs with it as with any secret key. Any `t` partial
+// signatures determine the group signature by Lagrange interpolation at 0:
+//
+//   sig = Σ λ_i · sig_i,   λ_i = Π_{j ≠ i} x_j / (x_j - x_i)   (mod r)
+//
+// blst only exposes scalar-field and raw point arithmetic through its C
+// bindings, so the few calls needed here are wrapped in small safe helpers.
+
+use blst::min_pk::Signature;
+use blst::{
+    blst_fr, blst_fr_from_uint64, blst_fr_inverse, blst_fr_mul, blst_fr_sub, blst_p2,
+    blst_p2_add_or_double, blst_p2_affine, blst_p2_from_affine, blst_p2_mult, blst_p2_to_affine,
+    blst_scalar, blst_scalar_from_fr,
+};
+
+use crate::api::bls::parse_valid_sig;
+
+/// Bit length of the scalar field order.
+const SCALAR_BITS: usize = 255;
+
+fn fr_from_u64(v: u64) -> blst_fr {
+    let mut out = blst_fr::default();
+    let limbs = [v, 0, 0, 0];
+    // SAFETY: `limbs` holds the four 64-bit limbs the function reads.
+    unsafe { blst_fr_from_uint64(&mut out, limbs.as_ptr()) };
+    out
+}
+
+fn fr_mul(a: &blst_fr, b: &blst_fr) -> blst_fr {
+    let mut out = blst_fr::default();
+    // SAFETY: all pointers refer to valid, initialized field elements.
+    unsafe { blst_fr_mul(&mut out, a, b) };
+    out
+}
+
+fn fr_sub(a: &blst_fr, b: &blst_fr) -> blst_fr {
+    let mut out = blst_fr::default();
+    // SAFETY: all pointers refer to valid, initialized field elements.
+    unsafe { blst_fr_sub(&mut out, a, b) };
+    out
+}
+
+/// Inverse of a nonzero field element.
+fn fr_inverse(a: &blst_fr) -> blst_fr {
+    let mut out = blst_fr::default();
+    // SAFETY: both pointers refer to valid, initialized field elements.
+    unsafe { blst_fr_inverse(&mut out, a) };
+    out
+}
+
+fn scalar_from_fr(a: &blst_fr) -> blst_scalar {
+    let mut out = blst_scalar::default();
+    // SAFETY: both pointers refer to valid, initialized values.
+    unsafe { blst_scalar_from_fr(&mut out, a) };
+    out
+}
+
+/// `k · point`, in projective form.
+fn p2_mult(point: &blst_p2_affine, k: &blst_scalar) -> blst_p2 {
+    let mut proj = blst_p2::default();
+    let mut out = blst_p2::default();
+    // SAFETY: all pointers refer to valid, initialized values, and `k.b`
+    // holds the 32 little-endian bytes covering `SCALAR_BITS`.
+    unsafe {
+        blst_p2_from_affine(&mut proj, point);
+        blst_p2_mult(&mut out, &proj, k.b.as_ptr(), SCALAR_BITS);
+    }
+    out
+}
+
+/// Lagrange coefficient at 0 of the share at `xs[i]`.
+fn lagrange_at_zero(xs: &[blst_fr], i: usize) -> blst_fr {
+    let mut num = fr_from_u64(1);
+    let mut den = fr_from_u64(1);
+    for (j, xj) in xs.iter().enumerate() {
+        if j != i {
+            num = fr_mul(&num, xj);
+            den = fr_mul(&den, &fr_sub(xj, &xs[i]));
+        }
+    }
+    fr_mul(&num, &fr_inverse(&den))
+}
+
+/// Recombine partial signatures into the group signature.
+///
+/// * `indices`      – nonzero share index of each signer, i.e. the point
+///   its key share was evaluated at
+/// * `partial_sigs` – 96-byte compressed G2 signatures by those shares over
+///   the same message, in the same order
+///
+/// With at least the threshold number of shares, the result verifies under
+/// the group public key like an ordinary signature. With fewer, it is just
+/// some other signature, so verify the result rather than trusting it.
+///
+/// Returns the 96-byte reconstructed signature, or an empty `Vec<u8>` when
+/// the lists differ in length or are empty, an index is zero or repeated,
+/// or a partial signature is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bls12381_min_pk_threshold_combine(indices: Vec<u32>, partial_sigs: Vec<Vec<u8>>) -> Vec<u8> {
+    if indices.is_empty() || indices.len() != partial_sigs.len() {
+        return vec![];
+    }
+    let mut sorted = indices.clone();
+    sorted.sort_unstable();
+    if sorted[0] == 0 || sorted.windows(2).any(|w| w[0] == w[1]) {
+        return vec![];
+    }
+    let sigs: Option<Vec<Signature>> = partial_sigs.iter().map(|b| parse_valid_sig(b)).collect();
+    let sigs = match sigs {
+        Some(s) => s,
+        None => return vec![],
+    };
+
+    let xs: Vec<blst_fr> = indices.iter().map(|&i| fr_from_u64(i as u64)).collect();
+    let mut acc: Option<blst_p2> = None;
+    for (i, sig) in sigs.into_iter().enumerate() {
+        let lambda = scalar_from_fr(&lagrange_at_zero(&xs, i));
+        let term = p2_mult(&blst_p2_affine::from(sig), &lambda);
+        acc = Some(match acc {
+            None => term,
+            Some(prev) => {
+                let mut sum = blst_p2::default();
+                // SAFETY: all pointers refer to valid, initialized points.
+                unsafe { blst_p2_add_or_double(&mut sum, &prev, &term) };
+                sum
+            }
+        });
+    }
+
+    // `indices` is non-empty, so the loop ran at least once.
+    let acc = acc.expect("at least one share");
+    let mut affine = blst_p2_affine::default();
+    // SAFETY: both pointers refer to valid, initialized points.
+    unsafe { blst_p2_to_affine(&mut affine, &acc) };
+    Signature::from(affine).to_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bls::bls12381_min_pk_verify;
+    use crate::test_utils::{committee, sign_msg};
+    use blst::min_pk::{PublicKey, SecretKey};
+    use blst::{
+        blst_bendian_from_scalar, blst_fr_add, blst_fr_from_scalar, blst_scalar_from_bendian,
+    };
+
+    const MSG: &[u8] = b"threshold certificate";
+
+    fn fr_from_sk(sk: &SecretKey) -> blst_fr {
+        let mut scalar = blst_scalar::default();
+        let mut out = blst_fr::default();
+        // SAFETY: `to_bytes` yields the 32 big-endian bytes read here.
+        unsafe {
+            blst_scalar_from_bendian(&mut scalar, sk.to_bytes().as_ptr());
+            blst_fr_from_scalar(&mut out, &scalar);
+        }
+        out
+    }
+
+    fn sk_from_fr(a: &blst_fr) -> SecretKey {
+        let mut bytes = [0u8; 32];
+        // SAFETY: `bytes` has room for the 32-byte big-endian scalar.
+        unsafe { blst_bendian_from_scalar(bytes.as_mut_ptr(), &scalar_from_fr(a)) };
+        SecretKey::from_bytes(&bytes).unwrap()
+    }
+
+    /// Shamir-share a fresh group key with threshold `t` among `n` holders;
+    /// returns the group public key and the shares for indices `1..=n`.
+    fn deal(t: usize, n: u32) -> (PublicKey, Vec<SecretKey>) {
+        let coeffs: Vec<blst_fr> = committee("threshold", t)
+            .iter()
+            .map(|(sk, _)| fr_from_sk(sk))
+            .collect();
+        let group_pk = sk_from_fr(&coeffs[0]).sk_to_pk();
+        let shares = (1..=n)
+            .map(|i| {
+                // Horner evaluation of f(i).
+                let x = fr_from_u64(i as u64);
+                let mut y = blst_fr::default();
+                for c in coeffs.iter().rev() {
+                    let mut sum = blst_fr::default();
+                    // SAFETY: all pointers refer to valid field elements.
+                    unsafe { blst_fr_add(&mut sum, &fr_mul(&y, &x), c) };
+                    y = sum;
+                }
+                sk_from_fr(&y)
+            })
+            .collect();
+        (group_pk, shares)
+    }
+
+    fn partials(shares: &[SecretKey], indices: &[u32]) -> Vec<Vec<u8>> {
+        indices
+            .iter()
+            .map(|&i| sign_msg(&shares[i as usize - 1], MSG).to_bytes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn threshold_combine_verifies_under_group_key() {
+        let (group_pk, shares) = deal(3, 5);
+        let group_pk = group_pk.to_bytes().to_vec();
+
+        let a = bls12381_min_pk_threshold_combine(vec![1, 3, 5], partials(&shares, &[1, 3, 5]));
+        assert_eq!(a.len(), 96);
+        assert!(bls12381_min_pk_verify(
+            a.clone(),
+            group_pk.clone(),
+            MSG.to_vec()
+        ));
+
+        // Any other threshold subset, in any order, gives the same signature.
+        let b = bls12381_min_pk_threshold_combine(vec![4, 2, 1], partials(&shares, &[4, 2, 1]));
+        assert_eq!(a, b);
+
+        // More shares than needed still interpolate the same polynomial.
+        let all = [1, 2, 3, 4, 5];
+        let c = bls12381_min_pk_threshold_combine(all.to_vec(), partials(&shares, &all));
+        assert_eq!(a, c);
+    }
+
+    #[test]
+    fn threshold_combine_below_threshold_fails_verification() {
+        let (group_pk, shares) = deal(3, 5);
+        let sig = bls12381_min_pk_threshold_combine(vec![1, 2], partials(&shares, &[1, 2]));
+        assert!(!bls12381_min_pk_verify(
+            sig,
+            group_pk.to_bytes().to_vec(),
+            MSG.to_vec()
+        ));
+    }
+
+    #[test]
+    fn threshold_combine_rejects_bad_input() {
+        let (_, shares) = deal(2, 3);
+        let sigs = partials(&shares, &[1, 2]);
+
+        assert!(bls12381_min_pk_threshold_combine(vec![1], sigs.clone()).is_empty());
+        assert!(bls12381_min_pk_threshold_combine(vec![1, 1], sigs.clone()).is_empty());
+        assert!(bls12381_min_pk_threshold_combine(vec![0, 2], sigs.clone()).is_empty());
+        assert!(bls12381_min_pk_threshold_combine(vec![], vec![]).is_empty());
+        assert!(
+            bls12381_min_pk_threshold_combine(vec![1, 2], vec![sigs[0].clone(), vec![0; 96]])
+                .is_empty()
+        );
+    }
+}
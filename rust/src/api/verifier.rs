@@ -0,0 +1,96 @@
+// A single storage node's public key, decoded and validated once for
+// checking many of its signatures. `bls12381_min_pk_verify` decompresses
+// and subgroup-checks the key on every call, which dominates when the key
+// never changes.
+
+use blst::min_pk::PublicKey;
+use blst::BLST_ERROR;
+
+use crate::api::bls::{parse_valid_sig, DST, PK_LEN};
+
+/// A validated BLS12-381 min_pk public key, ready to verify signatures.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct VerifierKey {
+    pk: PublicKey,
+}
+
+impl VerifierKey {
+    /// Decode and validate a 48-byte compressed G1 public key once.
+    ///
+    /// Returns `None` when the key is malformed, outside G1 or the identity.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(pk_bytes: Vec<u8>) -> Option<VerifierKey> {
+        if pk_bytes.len() != PK_LEN {
+            return None;
+        }
+        let pk = PublicKey::key_validate(&pk_bytes).ok()?;
+        Some(VerifierKey { pk })
+    }
+
+    /// The 48-byte compressed public key.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.pk.to_bytes().to_vec()
+    }
+
+    /// Verify a signature by this key.
+    ///
+    /// * `msg`       – arbitrary-length message
+    /// * `sig_bytes` – 96-byte compressed G2 signature
+    ///
+    /// Returns `true` when the signature is valid, `false` otherwise
+    /// (including a malformed signature).
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn verify(&self, msg: Vec<u8>, sig_bytes: Vec<u8>) -> bool {
+        match parse_valid_sig(&sig_bytes) {
+            // Both points are already validated.
+            Some(sig) => {
+                sig.verify(false, &msg, DST, &[], &self.pk, false) == BLST_ERROR::BLST_SUCCESS
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::bls::bls12381_min_pk_verify;
+    use crate::test_utils::{keygen, sign_msg};
+
+    #[test]
+    fn verifier_key_matches_verify() {
+        let (sk, pk) = keygen(b"test-seed-for-bls-verifier-key!!");
+        let pk = pk.to_bytes().to_vec();
+        let (other_sk, _) = keygen(b"test-seed-for-bls-verifier-othr!");
+        let key = VerifierKey::new(pk.clone()).unwrap();
+        assert_eq!(key.public_key(), pk);
+
+        for i in 0..10u8 {
+            let msg = format!("blob cert {i}").into_bytes();
+            let sig = sign_msg(&sk, &msg).to_bytes().to_vec();
+            let forged = sign_msg(&other_sk, &msg).to_bytes().to_vec();
+            assert!(key.verify(msg.clone(), sig.clone()));
+            for (m, s) in [
+                (msg.clone(), sig.clone()),
+                (b"other".to_vec(), sig),
+                (msg, forged),
+            ] {
+                assert_eq!(
+                    key.verify(m.clone(), s.clone()),
+                    bls12381_min_pk_verify(s, pk.clone(), m)
+                );
+            }
+        }
+        assert!(!key.verify(b"blob cert 0".to_vec(), vec![0u8; 96]));
+    }
+
+    #[test]
+    fn verifier_key_invalid_key() {
+        assert!(VerifierKey::new(vec![]).is_none());
+        assert!(VerifierKey::new(vec![0u8; PK_LEN]).is_none());
+        let mut identity = vec![0u8; PK_LEN];
+        identity[0] = 0xc0;
+        assert!(VerifierKey::new(identity).is_none());
+    }
+}
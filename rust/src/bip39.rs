@@ -0,0 +1,121 @@
+// BIP39 mnemonic phrases: decoding a phrase back to its entropy and
+// stretching it into a wallet seed.
+// https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+//
+// Each word is an 11-bit index into the English wordlist. The phrase
+// encodes `ENT` bits of entropy followed by the first `ENT / 32` bits of
+// SHA-256 of that entropy, for 12, 15, 18, 21 or 24 words.
+
+use zeroize::Zeroize;
+
+use crate::hash::{pbkdf2_hmac_sha512, sha256};
+
+/// The BIP39 English wordlist, one word per line in index order.
+const ENGLISH: &str = include_str!("bip39_english.txt");
+
+/// PBKDF2 iteration count fixed by BIP39.
+const SEED_ITERATIONS: u32 = 2048;
+
+/// Decode a mnemonic phrase to its entropy, checking the checksum.
+///
+/// Words are separated by any whitespace and must be lowercase English
+/// list words. Returns `None` for an unknown word, an unsupported word
+/// count or a checksum mismatch.
+pub(crate) fn mnemonic_to_entropy(mnemonic: &str) -> Option<Vec<u8>> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+        return None;
+    }
+
+    let mut bits = Vec::with_capacity(words.len() * 11);
+    for word in &words {
+        let index = ENGLISH.lines().position(|w| w == *word)?;
+        bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+    }
+
+    let checksum_bits = bits.len() / 33;
+    let entropy_bits = bits.len() - checksum_bits;
+    let mut entropy: Vec<u8> = bits[..entropy_bits]
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0u8, |acc, &b| (acc << 1) | b as u8))
+        .collect();
+    let hash = sha256(&entropy);
+    let ok = (0..checksum_bits)
+        .all(|i| bits[entropy_bits + i] == ((hash[i / 8] >> (7 - i % 8)) & 1 == 1));
+    bits.zeroize();
+    if !ok {
+        entropy.zeroize();
+        return None;
+    }
+    Some(entropy)
+}
+
+/// The 64-byte BIP39 seed of a phrase, without validating it.
+///
+/// The phrase is re-joined with single spaces. BIP39 calls for NFKD
+/// normalization of both strings; this is the identity on ASCII, which
+/// covers the English wordlist, but a non-ASCII passphrase must already be
+/// normalized by the caller.
+pub(crate) fn mnemonic_seed(mnemonic: &str, passphrase: &str) -> [u8; 64] {
+    let mut password = mnemonic.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut salt = format!("mnemonic{passphrase}");
+    let mut seed = [0u8; 64];
+    pbkdf2_hmac_sha512(
+        password.as_bytes(),
+        salt.as_bytes(),
+        SEED_ITERATIONS,
+        &mut seed,
+    );
+    password.zeroize();
+    salt.zeroize();
+    seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wordlist_shape() {
+        let words: Vec<&str> = ENGLISH.lines().collect();
+        assert_eq!(words.len(), 2048);
+        assert!(words.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(words[0], "abandon");
+        assert_eq!(words[2047], "zoo");
+    }
+
+    #[test]
+    fn entropy_known_vectors() {
+        let cases = [
+            (
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "00000000000000000000000000000000",
+            ),
+            (
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            ),
+            (
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+                "80808080808080808080808080808080",
+            ),
+            (
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+                "ffffffffffffffffffffffffffffffff",
+            ),
+        ];
+        for (mnemonic, entropy) in cases {
+            assert_eq!(hex::encode(mnemonic_to_entropy(mnemonic).unwrap()), entropy);
+        }
+    }
+
+    #[test]
+    fn entropy_rejects_bad_phrases() {
+        // Checksum mismatch: the last word should be "about".
+        assert!(mnemonic_to_entropy(&"abandon ".repeat(12)).is_none());
+        // Unknown word.
+        assert!(mnemonic_to_entropy(&format!("{}bitcoin", "abandon ".repeat(11))).is_none());
+        // Unsupported length.
+        assert!(mnemonic_to_entropy(&format!("{}about", "abandon ".repeat(10))).is_none());
+    }
+}
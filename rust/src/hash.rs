@@ -0,0 +1,182 @@
+// Hash primitives used by the `api` modules.
+//
+// SHA-256 comes from blst, which already links its own implementation for
+// hash-to-curve, so it costs nothing extra. BLAKE2b, SHA-512 and Keccak
+// come from the RustCrypto `blake2`, `sha2` and `sha3` crates, and
+// PBKDF2-HMAC from its `pbkdf2` crate for password-based key derivation.
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use pbkdf2::pbkdf2_hmac;
+use sha2::{Sha256, Sha512};
+use sha3::Keccak256;
+
+/// SHA-256 of `msg`.
+pub(crate) fn sha256(msg: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    // SAFETY: `out` has room for the 32-byte digest and `msg` is valid for
+    // `msg.len()` bytes of reads.
+    unsafe { blst::blst_sha256(out.as_mut_ptr(), msg.as_ptr(), msg.len()) };
+    out
+}
+
+/// BLAKE2b-256 of `msg`, the digest Sui and Walrus use.
+pub(crate) fn blake2b256(msg: &[u8]) -> [u8; 32] {
+    Blake2b::<U32>::digest(msg).into()
+}
+
+/// SHA-512 of `msg`.
+pub(crate) fn sha512(msg: &[u8]) -> [u8; 64] {
+    Sha512::digest(msg).into()
+}
+
+/// Keccak-256 of `msg`, as used by Ethereum (not NIST SHA3-256).
+pub(crate) fn keccak256(msg: &[u8]) -> [u8; 32] {
+    Keccak256::digest(msg).into()
+}
+
+/// PBKDF2-HMAC-SHA-256 (RFC 8018), filling `out` with derived key bytes.
+pub(crate) fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    pbkdf2_hmac::<Sha256>(password, salt, iterations, out)
+}
+
+/// PBKDF2-HMAC-SHA-512 (RFC 8018), filling `out` with derived key bytes.
+pub(crate) fn pbkdf2_hmac_sha512(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    pbkdf2_hmac::<Sha512>(password, salt, iterations, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_known_vectors() {
+        assert_eq!(
+            hex::encode(sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex::encode(sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn blake2b_known_vectors() {
+        assert_eq!(
+            hex::encode(blake2b256(b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+        assert_eq!(
+            hex::encode(blake2b256(b"abc")),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+    }
+
+    #[test]
+    fn blake2b_block_boundaries() {
+        // Lengths around the 128-byte block size, checked against Python's
+        // `hashlib.blake2b(b"a" * n, digest_size=32)`.
+        let data = [b'a'; 257];
+        let cases = [
+            (
+                127,
+                "59e2f1aba240f20aa591016f5ef429990bc9c2131dcd0d30f0ffd75ed18f317d",
+            ),
+            (
+                128,
+                "ae2aa48507885c4c950fb809b2076f959cde9f8ea6da260d9a3587df33dac450",
+            ),
+            (
+                129,
+                "2f64744a6de0d2c0b56e64cf6e29a5aaa255010d415d51c75ccc82f73dccd865",
+            ),
+            (
+                256,
+                "eae4d3a7627549b383179dc18049964f91a6fed14c9f3fb26705eda3eeda5558",
+            ),
+            (
+                257,
+                "496943de3c8b1458635ee30c15bb26cede6957abdd28f0a860cdaf967116c747",
+            ),
+        ];
+        for (n, expected) in cases {
+            assert_eq!(hex::encode(blake2b256(&data[..n])), expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn sha512_known_vectors() {
+        assert_eq!(
+            hex::encode(sha512(b"")),
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+             47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        );
+        assert_eq!(
+            hex::encode(sha512(b"abc")),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(
+            hex::encode(sha512(&[b'a'; 200])),
+            "4b11459c33f52a22ee8236782714c150a3b2c60994e9acee17fe68947a3e6789\
+             f31e7668394592da7bef827cddca88c4e6f86e4df7ed1ae6cba71f3e98faee9f"
+        );
+    }
+
+    #[test]
+    fn keccak256_known_vectors() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_hmac_sha256_known_vectors() {
+        let mut out = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 1, &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+        );
+        pbkdf2_hmac_sha256(b"password", b"salt", 4096, &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+        );
+        // Output longer than one block.
+        let mut long = [0u8; 40];
+        pbkdf2_hmac_sha256(
+            b"passwordPASSWORDpassword",
+            b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+            4096,
+            &mut long,
+        );
+        assert_eq!(
+            hex::encode(long),
+            "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_hmac_sha512_known_vectors() {
+        let mut out = [0u8; 64];
+        pbkdf2_hmac_sha512(b"password", b"salt", 1, &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252\
+             c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce"
+        );
+        pbkdf2_hmac_sha512(b"password", b"salt", 2, &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "e1d9c16aa681708a45f5c7c4e215ceb66e011a2e9f0040713f18aefdb866d53c\
+             f76cab2868a39b9f7840edce4fef5a82be67335c77a6068e04112754f27ccf4e"
+        );
+    }
+}
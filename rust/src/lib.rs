@@ -1,2 +1,7 @@
 pub mod api;
+mod bip39;
 mod frb_generated;
+mod hash;
+mod roaring;
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,277 @@
+// Decoder for the portable Roaring bitmap serialization format, as written
+// by CRoaring, Java `RoaringBitmap` and the `roaring` crate. Only decoding
+// is needed here, so it's a small reader over the spec:
+// https://github.com/RoaringBitmap/RoaringFormatSpec
+//
+// Values are u32s split into a 16-bit container key and 16-bit low bits.
+// Each container is an array of sorted u16s, a 65536-bit bitmap, or (in the
+// run-enabled format) a list of `(start, length - 1)` runs.
+
+/// Cookie of the format without run containers.
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+/// Low 16 bits of the cookie of the run-enabled format.
+const SERIAL_COOKIE: u32 = 12347;
+/// Run-enabled bitmaps with fewer containers than this omit offsets.
+const NO_OFFSET_THRESHOLD: usize = 4;
+/// Array containers hold at most this many values; larger ones are bitmaps.
+const ARRAY_MAX: usize = 4096;
+/// Size of a bitmap container in bytes.
+const BITMAP_BYTES: usize = 8192;
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let out = self.buf.get(self.pos..self.pos.checked_add(n)?)?;
+        self.pos += n;
+        Some(out)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.bytes(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+}
+
+/// Decode a serialized Roaring bitmap into its values, in ascending order.
+///
+/// Every value must be below `bound`. Decoding stops at the first one that
+/// isn't, and the values are strictly ascending, so the output never holds
+/// more than `bound` values: a run container expands to up to 65536 values
+/// from 6 bytes, and without the bound a sub-megabyte input could demand
+/// 16 GiB.
+///
+/// Returns `None` on truncated or trailing bytes, an unknown cookie, keys
+/// or values out of order, a value not below `bound`, a cardinality that
+/// doesn't match the container contents, or offsets that don't match the
+/// layout.
+pub(crate) fn decode(buf: &[u8], bound: usize) -> Option<Vec<u32>> {
+    let mut r = Reader { buf, pos: 0 };
+    let cookie = r.u32()?;
+    let (size, run_flags) = if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (r.u32()? as usize, None)
+    } else if cookie & 0xffff == SERIAL_COOKIE {
+        let size = (cookie >> 16) as usize + 1;
+        (size, Some(r.bytes(size.div_ceil(8))?))
+    } else {
+        return None;
+    };
+    let is_run = |i: usize| run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+
+    let mut headers = Vec::with_capacity(size.min(buf.len() / 4));
+    for _ in 0..size {
+        let key = r.u16()?;
+        let cardinality = r.u16()? as usize + 1;
+        headers.push((key, cardinality));
+    }
+    if headers.windows(2).any(|w| w[0].0 >= w[1].0) {
+        return None;
+    }
+    let offsets = if run_flags.is_none() || size >= NO_OFFSET_THRESHOLD {
+        (0..size).map(|_| r.u32()).collect::<Option<Vec<u32>>>()?
+    } else {
+        vec![]
+    };
+
+    let mut out = Vec::new();
+    for (i, &(key, cardinality)) in headers.iter().enumerate() {
+        if offsets.get(i).is_some_and(|&off| off as usize != r.pos) {
+            return None;
+        }
+        let high = (key as u32) << 16;
+        let start = out.len();
+        if is_run(i) {
+            let runs = r.u16()?;
+            let mut next = 0u32;
+            for _ in 0..runs {
+                let first = r.u16()? as u32;
+                let last = first + r.u16()? as u32;
+                if first < next || last > 0xffff || (high | last) as usize >= bound {
+                    return None;
+                }
+                out.extend((first..=last).map(|low| high | low));
+                next = last + 1;
+            }
+        } else if cardinality <= ARRAY_MAX {
+            let values = r.bytes(2 * cardinality)?;
+            for pair in values.chunks_exact(2) {
+                let value = high | u16::from_le_bytes([pair[0], pair[1]]) as u32;
+                if value as usize >= bound {
+                    return None;
+                }
+                out.push(value);
+            }
+            if out[start..].windows(2).any(|w| w[0] >= w[1]) {
+                return None;
+            }
+        } else {
+            let words = r.bytes(BITMAP_BYTES)?;
+            for (w, word) in words.chunks_exact(8).enumerate() {
+                let mut bits = u64::from_le_bytes(word.try_into().ok()?);
+                while bits != 0 {
+                    let value = high | (w as u32 * 64 + bits.trailing_zeros());
+                    if value as usize >= bound {
+                        return None;
+                    }
+                    out.push(value);
+                    bits &= bits - 1;
+                }
+            }
+        }
+        if out.len() - start != cardinality {
+            return None;
+        }
+    }
+    (r.pos == buf.len()).then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `{1, 2, 3}` in the format without run containers.
+    const ONE_TWO_THREE: [u8; 22] = [
+        0x3a, 0x30, 0, 0, // cookie 12346
+        1, 0, 0, 0, // one container
+        0, 0, 2, 0, // key 0, cardinality 3
+        16, 0, 0, 0, // container offset
+        1, 0, 2, 0, 3, 0,
+    ];
+
+    /// Serialize values in the no-run format, choosing array or bitmap
+    /// containers by cardinality as writers do.
+    fn encode_no_run(values: &[u32]) -> Vec<u8> {
+        let mut containers: Vec<(u16, Vec<u16>)> = Vec::new();
+        for &v in values {
+            let (key, low) = ((v >> 16) as u16, v as u16);
+            match containers.last_mut() {
+                Some((k, lows)) if *k == key => lows.push(low),
+                _ => containers.push((key, vec![low])),
+            }
+        }
+        let mut out = SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes().to_vec();
+        out.extend_from_slice(&(containers.len() as u32).to_le_bytes());
+        for (key, lows) in &containers {
+            out.extend_from_slice(&key.to_le_bytes());
+            out.extend_from_slice(&(lows.len() as u16 - 1).to_le_bytes());
+        }
+        let mut offset = out.len() + 4 * containers.len();
+        let mut bodies = Vec::new();
+        for (_, lows) in &containers {
+            out.extend_from_slice(&(offset as u32).to_le_bytes());
+            if lows.len() <= ARRAY_MAX {
+                for low in lows {
+                    bodies.extend_from_slice(&low.to_le_bytes());
+                }
+                offset += 2 * lows.len();
+            } else {
+                let mut words = [0u64; 1024];
+                for &low in lows {
+                    words[low as usize / 64] |= 1 << (low % 64);
+                }
+                for w in words {
+                    bodies.extend_from_slice(&w.to_le_bytes());
+                }
+                offset += BITMAP_BYTES;
+            }
+        }
+        out.extend_from_slice(&bodies);
+        out
+    }
+
+    #[test]
+    fn decode_array_container() {
+        assert_eq!(decode(&ONE_TWO_THREE, 4), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn decode_run_container() {
+        // `0..=99` as a single run, no offset header (fewer than 4 containers).
+        let bytes = [
+            0x3b, 0x30, 0, 0,   // cookie 12347, one container
+            0b1, // container 0 is a run container
+            0, 0, 99, 0, // key 0, cardinality 100
+            1, 0, // one run
+            0, 0, 99, 0, // start 0, length 100
+        ];
+        assert_eq!(decode(&bytes, 100), Some((0..100).collect()));
+    }
+
+    #[test]
+    fn decode_bitmap_and_multiple_containers() {
+        let mut values: Vec<u32> = (0..5000).map(|i| i * 3).collect();
+        values.extend([70_000, 1 << 20]);
+        assert_eq!(decode(&encode_no_run(&values), 1 << 20 | 1), Some(values));
+    }
+
+    #[test]
+    fn decode_rejects_malformed() {
+        assert!(decode(&[], usize::MAX).is_none());
+        assert!(decode(&ONE_TWO_THREE[..21], usize::MAX).is_none());
+
+        let mut trailing = ONE_TWO_THREE.to_vec();
+        trailing.push(0);
+        assert!(decode(&trailing, usize::MAX).is_none());
+
+        let mut cookie = ONE_TWO_THREE;
+        cookie[0] = 0x3c;
+        assert!(decode(&cookie, usize::MAX).is_none());
+
+        // Values out of order.
+        let mut unsorted = ONE_TWO_THREE;
+        unsorted[16..].copy_from_slice(&[2, 0, 1, 0, 3, 0]);
+        assert!(decode(&unsorted, usize::MAX).is_none());
+
+        // Wrong offset.
+        let mut offset = ONE_TWO_THREE;
+        offset[12] = 17;
+        assert!(decode(&offset, usize::MAX).is_none());
+    }
+
+    #[test]
+    fn decode_rejects_values_past_bound() {
+        assert!(decode(&ONE_TWO_THREE, 3).is_none());
+        let values: Vec<u32> = (0..5000).map(|i| i * 3).collect();
+        assert!(decode(&encode_no_run(&values), 14_997).is_none());
+        assert!(decode(&encode_no_run(&values), 14_998).is_some());
+    }
+
+    #[test]
+    fn decode_rejects_oversized_runs() {
+        // 65536 containers, each one full run: 2^32 values from under 1 MB.
+        let size = 1usize << 16;
+        let mut bytes = (SERIAL_COOKIE | ((size as u32 - 1) << 16))
+            .to_le_bytes()
+            .to_vec();
+        bytes.extend(std::iter::repeat_n(0xff, size / 8));
+        for key in 0..size {
+            bytes.extend_from_slice(&(key as u16).to_le_bytes());
+            bytes.extend_from_slice(&0xffffu16.to_le_bytes());
+        }
+        let first = bytes.len() + 4 * size;
+        for i in 0..size {
+            bytes.extend_from_slice(&((first + 6 * i) as u32).to_le_bytes());
+        }
+        for _ in 0..size {
+            bytes.extend_from_slice(&[1, 0, 0, 0, 0xff, 0xff]);
+        }
+        assert!(bytes.len() < 1 << 20);
+        assert!(decode(&bytes, 1000).is_none());
+
+        // A run that merely overshoots the bound is rejected too.
+        let overshoot = [
+            0x3b, 0x30, 0, 0,   // cookie 12347, one container
+            0b1, // container 0 is a run container
+            0, 0, 99, 0, // key 0, cardinality 100
+            1, 0, // one run
+            0, 0, 99, 0, // start 0, length 100
+        ];
+        assert!(decode(&overshoot, 99).is_none());
+    }
+}
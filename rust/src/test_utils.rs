@@ -0,0 +1,63 @@
+// Shared helpers for unit tests across the `api` modules.
+
+use blst::min_pk::{PublicKey, SecretKey, Signature};
+
+use crate::api::bls::DST;
+
+/// Generate a key pair from a 32-byte seed.
+pub fn keygen(seed: &[u8; 32]) -> (SecretKey, PublicKey) {
+    let sk = SecretKey::key_gen(seed, &[]).unwrap();
+    let pk = sk.sk_to_pk();
+    (sk, pk)
+}
+
+/// Generate `n` deterministic key pairs, derived from `label` and the index.
+pub fn committee(label: &str, n: usize) -> Vec<(SecretKey, PublicKey)> {
+    (0..n)
+        .map(|i| {
+            let mut seed = [b'!'; 32];
+            let tag = format!("{label}-{i}");
+            let len = tag.len().min(32);
+            seed[..len].copy_from_slice(&tag.as_bytes()[..len]);
+            keygen(&seed)
+        })
+        .collect()
+}
+
+/// Sign a message using the hardcoded DST.
+pub fn sign_msg(sk: &SecretKey, msg: &[u8]) -> Signature {
+    sk.sign(msg, DST, &[])
+}
+
+/// Compressed public keys of a committee.
+pub fn pks_of(keys: &[(SecretKey, PublicKey)]) -> Vec<Vec<u8>> {
+    keys.iter().map(|(_, pk)| pk.to_bytes().to_vec()).collect()
+}
+
+/// Aggregate signature over `msg` from the committee members at `indices`.
+pub fn agg_sign(keys: &[(SecretKey, PublicKey)], indices: &[usize], msg: &[u8]) -> Vec<u8> {
+    let sigs: Vec<Vec<u8>> = indices
+        .iter()
+        .map(|&i| sign_msg(&keys[i].0, msg).to_bytes().to_vec())
+        .collect();
+    crate::api::bls::bls12381_min_pk_aggregate(sigs)
+}
+
+/// The same helpers for the min_sig scheme.
+pub mod min_sig {
+    use blst::min_sig::{PublicKey, SecretKey, Signature};
+
+    use crate::api::min_sig::DST;
+
+    /// Generate a key pair from a 32-byte seed.
+    pub fn keygen(seed: &[u8; 32]) -> (SecretKey, PublicKey) {
+        let sk = SecretKey::key_gen(seed, &[]).unwrap();
+        let pk = sk.sk_to_pk();
+        (sk, pk)
+    }
+
+    /// Sign a message using the min_sig DST.
+    pub fn sign_msg(sk: &SecretKey, msg: &[u8]) -> Signature {
+        sk.sign(msg, DST, &[])
+    }
+}